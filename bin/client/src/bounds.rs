@@ -0,0 +1,30 @@
+//! Optional bounds on the inputs accepted by the fault proof program.
+
+/// Optional bounds on the inputs accepted by the fault proof program. Every bound is disabled by
+/// default, in which case the program accepts any inputs it is able to prove.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ProgramBounds {
+    /// The maximum age of the safe head relative to the claimed L2 block, in seconds. The
+    /// corresponding distance in L2 blocks is derived from the block time of the chain.
+    ///
+    /// Only applied by the single-chain program.
+    pub max_safe_head_age: Option<u64>,
+    /// The maximum number of L1 blocks walked back from the L1 head to reach the starting origin
+    /// of the derivation pipeline.
+    pub max_l1_walk_back: Option<u64>,
+}
+
+impl ProgramBounds {
+    /// Sets the maximum age of the safe head relative to the claimed L2 block, in seconds.
+    pub const fn with_max_safe_head_age(mut self, max_safe_head_age: u64) -> Self {
+        self.max_safe_head_age = Some(max_safe_head_age);
+        self
+    }
+
+    /// Sets the maximum number of L1 blocks walked back from the L1 head to reach the starting
+    /// origin of the derivation pipeline.
+    pub const fn with_max_l1_walk_back(mut self, max_l1_walk_back: u64) -> Self {
+        self.max_l1_walk_back = Some(max_l1_walk_back);
+        self
+    }
+}
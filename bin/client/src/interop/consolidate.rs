@@ -55,12 +55,8 @@ where
 
     // Transition to the Super Root at the next timestamp.
     //
-    // TODO: This won't work if we replace blocks, `transition` doesn't allow replacement of pending
-    // progress just yet.
-    let post = boot
-        .agreed_pre_state
-        .transition(None)
-        .ok_or(FaultProofProgramError::StateTransitionFailed)?;
+    // TODO: This won't work if we replace blocks, the pending progress is committed to as-is.
+    let post = PreState::SuperRoot(transition_state.clone().into_super_root()?);
     let post_commitment = post.hash();
 
     // Ensure that the post-state matches the claimed post-state.
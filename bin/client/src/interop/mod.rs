@@ -1,14 +1,18 @@
 //! Multi-chain, interoperable fault proof program entrypoint.
 
+use crate::bounds::ProgramBounds;
 use alloc::sync::Arc;
 use alloy_primitives::B256;
 use consolidate::consolidate_dependencies;
 use core::fmt::Debug;
 use kona_driver::DriverError;
 use kona_executor::{ExecutorError, KonaHandleRegister};
-use kona_preimage::{HintWriterClient, PreimageOracleClient};
+use kona_preimage::{HintWriterClient, PreimageKey, PreimageOracleClient};
 use kona_proof::{errors::OracleProviderError, l2::OracleL2ChainProvider, CachingOracle};
-use kona_proof_interop::{BootInfo, PreState, INVALID_TRANSITION_HASH, TRANSITION_STATE_MAX_STEPS};
+use kona_proof_interop::{
+    boot::{L1_HEAD_KEY, L2_ROLLUP_CONFIG_KEY},
+    BootInfo, PreState, TransitionStateError, INVALID_TRANSITION_HASH, TRANSITION_STATE_MAX_STEPS,
+};
 use thiserror::Error;
 use tracing::{error, info};
 use transition::sub_transition;
@@ -35,10 +39,13 @@ pub enum FaultProofProgramError {
     /// State transition failed.
     #[error("Critical state transition failure")]
     StateTransitionFailed,
+    /// The agreed transition state could not be finalized into a super root.
+    #[error(transparent)]
+    TransitionState(#[from] TransitionStateError),
 }
 
 /// Executes the interop fault proof program with the given [PreimageOracleClient] and
-/// [HintWriterClient].
+/// [HintWriterClient], rejecting inputs that exceed the given [ProgramBounds].
 #[inline]
 pub async fn run<P, H>(
     oracle_client: P,
@@ -49,6 +56,7 @@ pub async fn run<P, H>(
             OracleL2ChainProvider<CachingOracle<P, H>>,
         >,
     >,
+    bounds: ProgramBounds,
 ) -> Result<(), FaultProofProgramError>
 where
     P: PreimageOracleClient + Send + Sync + Debug + Clone,
@@ -58,6 +66,9 @@ where
 
     // Instantiate the oracle and bootstrap the program from local inputs.
     let oracle = Arc::new(CachingOracle::new(ORACLE_LRU_SIZE, oracle_client, hint_client));
+    [L1_HEAD_KEY, L2_ROLLUP_CONFIG_KEY]
+        .into_iter()
+        .for_each(|key| oracle.pin(PreimageKey::new_local(key.to())));
     let boot = match BootInfo::load(oracle.as_ref()).await {
         Ok(boot) => boot,
         Err(e) => {
@@ -79,13 +90,13 @@ where
     match boot.agreed_pre_state {
         PreState::SuperRoot(_) => {
             // If the pre-state is a super root, the first sub-problem is always selected.
-            sub_transition(oracle, handle_register, boot).await
+            sub_transition(oracle, handle_register, boot, bounds).await
         }
         PreState::TransitionState(ref transition_state) => {
             // If the pre-state is a transition state, the sub-problem is selected based on the
             // current step.
             if transition_state.step < TRANSITION_STATE_MAX_STEPS {
-                sub_transition(oracle, handle_register, boot).await
+                sub_transition(oracle, handle_register, boot, bounds).await
             } else {
                 consolidate_dependencies(oracle, boot).await
             }
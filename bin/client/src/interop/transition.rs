@@ -1,27 +1,26 @@
 //! Single chain sub-transition phase of the interop proof.
 
 use super::FaultProofProgramError;
-use crate::interop::util::fetch_l2_safe_head_hash;
+use crate::{bounds::ProgramBounds, interop::util::fetch_l2_safe_head_hash};
 use alloc::sync::Arc;
-use alloy_consensus::Sealed;
 use alloy_primitives::B256;
 use core::{cmp::Ordering, fmt::Debug};
 use kona_derive::errors::{PipelineError, PipelineErrorKind};
-use kona_driver::{Driver, DriverError};
-use kona_executor::{KonaHandleRegister, TrieDBProvider};
+use kona_driver::{block_for_timestamp, AdvanceOutcome, Driver, DriverError};
+use kona_executor::KonaHandleRegister;
 use kona_preimage::{HintWriterClient, PreimageOracleClient};
 use kona_proof::{
     executor::KonaExecutor,
     l1::{OracleBlobProvider, OracleL1ChainProvider, OraclePipeline},
     l2::OracleL2ChainProvider,
-    sync::new_pipeline_cursor,
+    sync::{fetch_safe_head, new_pipeline_cursor},
     CachingOracle,
 };
 use kona_proof_interop::{BootInfo, OptimisticBlock, PreState, INVALID_TRANSITION_HASH};
 use tracing::{error, info, warn};
 
 /// Executes a sub-transition of the interop proof with the given [PreimageOracleClient] and
-/// [HintWriterClient].
+/// [HintWriterClient], rejecting inputs that exceed the given [ProgramBounds].
 pub(crate) async fn sub_transition<P, H>(
     oracle: Arc<CachingOracle<P, H>>,
     handle_register: Option<
@@ -31,6 +30,7 @@ pub(crate) async fn sub_transition<P, H>(
         >,
     >,
     boot: BootInfo,
+    bounds: ProgramBounds,
 ) -> Result<(), FaultProofProgramError>
 where
     P: PreimageOracleClient + Send + Sync + Debug + Clone,
@@ -48,15 +48,19 @@ where
         }
     }
 
-    // Fetch the L2 block hash of the current safe head.
-    let safe_head_hash = fetch_l2_safe_head_hash(oracle.as_ref(), &boot.agreed_pre_state).await?;
-
     // Determine the active L2 chain ID and the fetch rollup configuration.
     let rollup_config = boot
         .active_rollup_config()
         .map(Arc::new)
         .ok_or(FaultProofProgramError::StateTransitionFailed)?;
 
+    // Fetch the L2 block hash of the current safe head. If the agreed pre-state is at the chain's
+    // genesis, the safe head is the genesis block of the rollup config.
+    let safe_head_hash = match boot.genesis_safe_head_hash() {
+        Some(genesis_hash) => genesis_hash,
+        None => fetch_l2_safe_head_hash(oracle.as_ref(), &boot.agreed_pre_state).await?,
+    };
+
     // Instantiate the L1 EL + CL provider and the L2 EL provider.
     let mut l1_provider = OracleL1ChainProvider::new(boot.l1_head, oracle.clone());
     let mut l2_provider =
@@ -66,24 +70,23 @@ where
     // Set the active L2 chain ID for the L2 provider.
     l2_provider.set_chain_id(boot.agreed_pre_state.active_l2_chain_id());
 
-    // Fetch the safe head's block header.
-    let safe_head = l2_provider
-        .header_by_hash(safe_head_hash)
-        .map(|header| Sealed::new_unchecked(header, safe_head_hash))?;
+    // Fetch the safe head's block header and block info.
+    let safe_head = fetch_safe_head(&mut l2_provider, safe_head_hash).await?;
 
     // Translate the claimed timestamp to an L2 block number.
-    let claimed_l2_block_number = rollup_config.genesis.l2.number +
-        ((boot.claimed_l2_timestamp - rollup_config.genesis.l2_time) / rollup_config.block_time);
+    let claimed_l2_block_number =
+        block_for_timestamp(rollup_config.as_ref(), boot.claimed_l2_timestamp)
+            .ok_or(FaultProofProgramError::StateTransitionFailed)?;
 
     // If the claimed L2 block number is less than the safe head of the L2 chain, the claim is
     // invalid.
-    match claimed_l2_block_number.cmp(&safe_head.number) {
+    match claimed_l2_block_number.cmp(&safe_head.header.number) {
         Ordering::Less => {
             error!(
                 target: "interop_client",
                 "Claimed L2 block number {claimed} is less than the safe head {safe}",
                 claimed = claimed_l2_block_number,
-                safe = safe_head.number
+                safe = safe_head.header.number
             );
             return Err(FaultProofProgramError::InvalidClaim(
                 boot.agreed_pre_state_commitment,
@@ -101,9 +104,13 @@ where
     }
 
     // Create a new derivation driver with the given boot information and oracle.
-    let cursor =
-        new_pipeline_cursor(rollup_config.as_ref(), safe_head, &mut l1_provider, &mut l2_provider)
-            .await?;
+    let cursor = new_pipeline_cursor(
+        rollup_config.as_ref(),
+        safe_head,
+        &mut l1_provider,
+        bounds.max_l1_walk_back,
+    )
+    .await?;
     l2_provider.set_cursor(cursor.clone());
 
     let pipeline = OraclePipeline::new(
@@ -126,7 +133,7 @@ where
     // Run the derivation pipeline until we are able to produce the output root of the claimed
     // L2 block.
     match driver.advance_to_target(rollup_config.as_ref(), Some(claimed_l2_block_number)).await {
-        Ok((safe_head, output_root)) => {
+        Ok(AdvanceOutcome { safe_head, output_root, .. }) => {
             let optimistic_block = OptimisticBlock::new(safe_head.block_info.hash, output_root);
             transition_and_check(
                 boot.agreed_pre_state,
@@ -8,6 +8,7 @@
 extern crate alloc;
 
 use alloc::string::String;
+use kona_client::bounds::ProgramBounds;
 use kona_preimage::{HintWriter, OracleReader};
 use kona_std_fpvm::{FileChannel, FileDescriptor};
 use kona_std_fpvm_proc::client_entry;
@@ -43,5 +44,6 @@ fn main() -> Result<(), String> {
         ORACLE_READER,
         HINT_WRITER,
         Some(precompiles::fpvm_handle_register),
+        ProgramBounds::default(),
     ))
 }
@@ -7,5 +7,6 @@
 
 extern crate alloc;
 
+pub mod bounds;
 pub mod interop;
 pub mod single;
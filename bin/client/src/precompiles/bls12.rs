@@ -6,12 +6,11 @@
 //!
 //! [revm implementation]: https://github.com/bluealloy/revm/blob/main/crates/precompile/src/bls12_381/pairing.rs
 
+use super::parse_precompile_result;
 use crate::{HINT_WRITER, ORACLE_READER};
 use alloc::{string::ToString, vec::Vec};
 use alloy_primitives::{address, keccak256, Address, Bytes};
-use kona_preimage::{
-    errors::PreimageOracleError, PreimageKey, PreimageKeyType, PreimageOracleClient,
-};
+use kona_preimage::{PreimageKey, PreimageKeyType, PreimageOracleClient};
 use kona_proof::{errors::OracleProviderError, HintType};
 use revm::{
     precompile::{Error as PrecompileError, Precompile, PrecompileResult, PrecompileWithAddress},
@@ -68,25 +67,14 @@ fn fpvm_bls12_pairing(input: &Bytes, gas_limit: u64) -> PrecompileResult {
             .await
             .map_err(OracleProviderError::Preimage)?;
 
-        // Ensure we've received valid result data.
-        if result_data.is_empty() {
-            return Err(OracleProviderError::Preimage(PreimageOracleError::Other(
-                "Invalid result data".to_string(),
-            )));
-        }
-
-        // Ensure we've not received an error from the host.
-        if result_data[0] == 0 {
-            return Err(OracleProviderError::Preimage(PreimageOracleError::Other(
-                "Error executing ecrecover precompile in host".to_string(),
-            )));
-        }
-
-        // Return the result data.
-        Ok(result_data[1..].to_vec())
+        Ok::<_, OracleProviderError>(result_data)
     })
     .map_err(|e| PrecompileError::Other(e.to_string()))?;
 
+    // Ensure the host executed the precompile successfully, and returned a well-formed output.
+    let result_data = parse_precompile_result(&result_data, &[32])
+        .map_err(|e| PrecompileError::Other(e.to_string()))?;
+
     Ok(PrecompileOutput::new(required_gas, result_data.into()))
 }
 
@@ -1,11 +1,10 @@
 //! Contains the accelerated version of the KZG point evaluation precompile.
 
+use super::parse_precompile_result;
 use crate::{HINT_WRITER, ORACLE_READER};
 use alloc::{string::ToString, vec::Vec};
 use alloy_primitives::{keccak256, Address, Bytes};
-use kona_preimage::{
-    errors::PreimageOracleError, PreimageKey, PreimageKeyType, PreimageOracleClient,
-};
+use kona_preimage::{PreimageKey, PreimageKeyType, PreimageOracleClient};
 use kona_proof::{errors::OracleProviderError, HintType};
 use revm::{
     precompile::{u64_to_address, Error as PrecompileError, PrecompileWithAddress},
@@ -44,24 +43,13 @@ fn fpvm_kzg_point_eval(input: &Bytes, gas_limit: u64) -> PrecompileResult {
             .await
             .map_err(OracleProviderError::Preimage)?;
 
-        // Ensure we've received valid result data.
-        if result_data.is_empty() {
-            return Err(OracleProviderError::Preimage(PreimageOracleError::Other(
-                "Invalid result data".to_string(),
-            )));
-        }
-
-        // Ensure we've not received an error from the host.
-        if result_data[0] == 0 {
-            return Err(OracleProviderError::Preimage(PreimageOracleError::Other(
-                "Error executing ecrecover precompile in host".to_string(),
-            )));
-        }
-
-        // Return the result data.
-        Ok(result_data[1..].to_vec())
+        Ok::<_, OracleProviderError>(result_data)
     })
     .map_err(|e| PrecompileError::Other(e.to_string()))?;
 
+    // Ensure the host executed the precompile successfully, and returned a well-formed output.
+    let result_data = parse_precompile_result(&result_data, &[64])
+        .map_err(|e| PrecompileError::Other(e.to_string()))?;
+
     Ok(PrecompileOutput::new(GAS_COST, result_data.into()))
 }
@@ -2,7 +2,7 @@
 //!
 //! [KonaHandleRegister]: kona_executor::KonaHandleRegister
 
-use alloc::sync::Arc;
+use alloc::{sync::Arc, vec::Vec};
 use kona_executor::{TrieDB, TrieDBProvider};
 use kona_mpt::TrieHinter;
 use revm::{
@@ -10,6 +10,7 @@ use revm::{
     primitives::{spec_to_generic, SpecId},
     State,
 };
+use thiserror::Error;
 
 mod bls12;
 mod bn128_pair;
@@ -51,3 +52,88 @@ pub(crate) fn fpvm_handle_register<F, H>(
         ctx_precompiles
     });
 }
+
+/// An error returned when the host's response to an accelerated precompile call is invalid.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub(crate) enum PrecompileAccelError {
+    /// The host reported a failure executing the precompile.
+    #[error("Host reported a failure executing the precompile")]
+    HostFailure,
+    /// The response is empty, or does not begin with a known status byte.
+    #[error("Malformed precompile response from host")]
+    MalformedResponse,
+    /// The precompile output has an unexpected length.
+    #[error("Unexpected precompile output length: {0}")]
+    LengthMismatch(usize),
+}
+
+/// Parses the host's response to an accelerated precompile call.
+///
+/// A successful response is the `0x01` status byte followed by the precompile output, while a
+/// failed execution is reported as a lone `0x00` status byte.
+///
+/// ## Takes
+/// - `response`: The raw response from the host.
+/// - `expected_lengths`: The output lengths the precompile may produce.
+///
+/// ## Returns
+/// - `Ok(output)`: The precompile output.
+/// - `Err(_)`: The host reported a failure, or the response is malformed.
+pub(crate) fn parse_precompile_result(
+    response: &[u8],
+    expected_lengths: &[usize],
+) -> Result<Vec<u8>, PrecompileAccelError> {
+    match response.split_first() {
+        Some((0x00, [])) => Err(PrecompileAccelError::HostFailure),
+        Some((0x01, output)) if expected_lengths.contains(&output.len()) => Ok(output.to_vec()),
+        Some((0x01, output)) => Err(PrecompileAccelError::LengthMismatch(output.len())),
+        _ => Err(PrecompileAccelError::MalformedResponse),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_parse_precompile_result_success() {
+        let mut response = vec![0x01];
+        response.extend_from_slice(&[0xAA; 32]);
+        assert_eq!(parse_precompile_result(&response, &[32]), Ok(vec![0xAA; 32]));
+        assert_eq!(parse_precompile_result(&[0x01], &[0, 32]), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_parse_precompile_result_host_failure() {
+        assert_eq!(parse_precompile_result(&[0x00], &[32]), Err(PrecompileAccelError::HostFailure));
+    }
+
+    #[test]
+    fn test_parse_precompile_result_malformed() {
+        assert_eq!(
+            parse_precompile_result(&[], &[32]),
+            Err(PrecompileAccelError::MalformedResponse)
+        );
+        assert_eq!(
+            parse_precompile_result(&[0x02, 0xAA], &[1]),
+            Err(PrecompileAccelError::MalformedResponse)
+        );
+        assert_eq!(
+            parse_precompile_result(&[0x00, 0xAA], &[1]),
+            Err(PrecompileAccelError::MalformedResponse)
+        );
+    }
+
+    #[test]
+    fn test_parse_precompile_result_length_mismatch() {
+        assert_eq!(
+            parse_precompile_result(&[0x01; 32], &[32]),
+            Err(PrecompileAccelError::LengthMismatch(31))
+        );
+        assert_eq!(
+            parse_precompile_result(&[0x01], &[64]),
+            Err(PrecompileAccelError::LengthMismatch(0))
+        );
+    }
+}
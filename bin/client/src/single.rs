@@ -1,18 +1,21 @@
 //! Single-chain fault proof program entrypoint.
 
+use crate::bounds::ProgramBounds;
 use alloc::sync::Arc;
-use alloy_consensus::Sealed;
 use alloy_primitives::B256;
 use core::fmt::Debug;
-use kona_driver::{Driver, DriverError};
-use kona_executor::{ExecutorError, KonaHandleRegister, TrieDBProvider};
+use kona_driver::{AdvanceOutcome, Driver, DriverError};
+use kona_executor::{ExecutorError, KonaHandleRegister};
 use kona_preimage::{CommsClient, HintWriterClient, PreimageKey, PreimageOracleClient};
 use kona_proof::{
+    boot::{L1_HEAD_KEY, L2_CHAIN_ID_KEY, L2_ROLLUP_CONFIG_KEY},
     errors::OracleProviderError,
     executor::KonaExecutor,
     l1::{OracleBlobProvider, OracleL1ChainProvider, OraclePipeline},
     l2::OracleL2ChainProvider,
-    sync::new_pipeline_cursor,
+    sync::{
+        check_safe_head_distance, fetch_safe_head, max_safe_head_distance, new_pipeline_cursor,
+    },
     BootInfo, CachingOracle, HintType,
 };
 use thiserror::Error;
@@ -32,7 +35,8 @@ pub enum FaultProofProgramError {
     Driver(#[from] DriverError<ExecutorError>),
 }
 
-/// Executes the fault proof program with the given [PreimageOracleClient] and [HintWriterClient].
+/// Executes the fault proof program with the given [PreimageOracleClient] and [HintWriterClient],
+/// rejecting inputs that exceed the given [ProgramBounds].
 #[inline]
 pub async fn run<P, H>(
     oracle_client: P,
@@ -43,6 +47,7 @@ pub async fn run<P, H>(
             OracleL2ChainProvider<CachingOracle<P, H>>,
         >,
     >,
+    bounds: ProgramBounds,
 ) -> Result<(), FaultProofProgramError>
 where
     P: PreimageOracleClient + Send + Sync + Debug + Clone,
@@ -55,6 +60,9 @@ where
     ////////////////////////////////////////////////////////////////
 
     let oracle = Arc::new(CachingOracle::new(ORACLE_LRU_SIZE, oracle_client, hint_client));
+    [L1_HEAD_KEY, L2_CHAIN_ID_KEY, L2_ROLLUP_CONFIG_KEY]
+        .into_iter()
+        .for_each(|key| oracle.pin(PreimageKey::new_local(key.to())));
     let boot = BootInfo::load(oracle.as_ref()).await?;
     let rollup_config = Arc::new(boot.rollup_config);
     let safe_head_hash = fetch_safe_head_hash(oracle.as_ref(), boot.agreed_l2_output_root).await?;
@@ -64,19 +72,17 @@ where
         OracleL2ChainProvider::new(safe_head_hash, rollup_config.clone(), oracle.clone());
     let beacon = OracleBlobProvider::new(oracle.clone());
 
-    // Fetch the safe head's block header.
-    let safe_head = l2_provider
-        .header_by_hash(safe_head_hash)
-        .map(|header| Sealed::new_unchecked(header, safe_head_hash))?;
+    // Fetch the safe head's block header and block info.
+    let safe_head = fetch_safe_head(&mut l2_provider, safe_head_hash).await?;
 
     // If the claimed L2 block number is less than the safe head of the L2 chain, the claim is
     // invalid.
-    if boot.claimed_l2_block_number < safe_head.number {
+    if boot.claimed_l2_block_number < safe_head.header.number {
         error!(
             target: "client",
             "Claimed L2 block number {claimed} is less than the safe head {safe}",
             claimed = boot.claimed_l2_block_number,
-            safe = safe_head.number
+            safe = safe_head.header.number
         );
         return Err(FaultProofProgramError::InvalidClaim(
             boot.agreed_l2_output_root,
@@ -84,6 +90,13 @@ where
         ));
     }
 
+    // If configured, reject a safe head that trails the claimed L2 block implausibly far, rather
+    // than deriving and executing the entire gap.
+    if let Some(max_age) = bounds.max_safe_head_age {
+        let max_distance = max_safe_head_distance(rollup_config.as_ref(), max_age);
+        check_safe_head_distance(&safe_head, boot.claimed_l2_block_number, max_distance)?;
+    }
+
     // In the case where the agreed upon L2 output root is the same as the claimed L2 output root,
     // trace extension is detected and we can skip the derivation and execution steps.
     if boot.agreed_l2_output_root == boot.claimed_l2_output_root {
@@ -99,9 +112,13 @@ where
     ////////////////////////////////////////////////////////////////
 
     // Create a new derivation driver with the given boot information and oracle.
-    let cursor =
-        new_pipeline_cursor(rollup_config.as_ref(), safe_head, &mut l1_provider, &mut l2_provider)
-            .await?;
+    let cursor = new_pipeline_cursor(
+        rollup_config.as_ref(),
+        safe_head,
+        &mut l1_provider,
+        bounds.max_l1_walk_back,
+    )
+    .await?;
     l2_provider.set_cursor(cursor.clone());
 
     let pipeline = OraclePipeline::new(
@@ -123,7 +140,7 @@ where
 
     // Run the derivation pipeline until we are able to produce the output root of the claimed
     // L2 block.
-    let (safe_head, output_root) = driver
+    let AdvanceOutcome { safe_head, output_root, .. } = driver
         .advance_to_target(rollup_config.as_ref(), Some(boot.claimed_l2_block_number))
         .await?;
 
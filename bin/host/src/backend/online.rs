@@ -1,15 +1,19 @@
 //! Contains the [OnlineHostBackend] definition.
 
 use crate::SharedKeyValueStore;
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use kona_preimage::{
     errors::{PreimageOracleError, PreimageOracleResult},
     HintRouter, PreimageFetcher, PreimageKey,
 };
-use std::{hash::Hash, str::FromStr, sync::Arc};
-use tokio::sync::RwLock;
-use tracing::{error, trace, warn};
+use std::{hash::Hash, path::Path, str::FromStr, sync::Arc};
+use tokio::{
+    fs::File,
+    io::{AsyncBufRead, AsyncBufReadExt, BufReader},
+    sync::RwLock,
+};
+use tracing::{error, info, trace, warn};
 
 /// The [OnlineHostBackendCfg] trait is used to define the type configuration for the
 /// [OnlineHostBackend].
@@ -77,6 +81,50 @@ where
     }
 }
 
+impl<C, H> OnlineHostBackend<C, H>
+where
+    C: OnlineHostBackendCfg,
+    H: HintHandler<Cfg = C>,
+{
+    /// Replays a recorded sequence of newline-delimited hints from the file at the given path,
+    /// fetching the data for each hint into the key-value store. See
+    /// [OnlineHostBackend::replay_hints].
+    pub async fn replay_hints_file(&self, path: &Path) -> Result<usize> {
+        let file = File::open(path)
+            .await
+            .map_err(|e| anyhow!("Failed to open hint file {}: {e}", path.display()))?;
+        self.replay_hints(BufReader::new(file)).await
+    }
+
+    /// Replays a recorded sequence of newline-delimited hints, fetching the data for each hint
+    /// into the key-value store. Empty lines are skipped.
+    ///
+    /// Returns the number of hints that were replayed. Unlike hints routed from the client, a
+    /// hint that fails to parse or fetch aborts the replay.
+    pub async fn replay_hints<R>(&self, reader: R) -> Result<usize>
+    where
+        R: AsyncBufRead + Unpin,
+    {
+        let mut lines = reader.lines();
+        let mut replayed = 0;
+        while let Some(line) = lines.next_line().await? {
+            let hint = line.trim();
+            if hint.is_empty() {
+                continue;
+            }
+
+            trace!(target: "host-backend", "Replaying hint: {hint}");
+            let parsed_hint =
+                hint.parse::<C::Hint>().map_err(|_| anyhow!("Failed to parse hint: {hint}"))?;
+            H::fetch_hint(parsed_hint, &self.cfg, &self.providers, self.kv.clone()).await?;
+            replayed += 1;
+        }
+
+        info!(target: "host-backend", "Replayed {replayed} hints");
+        Ok(replayed)
+    }
+}
+
 #[async_trait]
 impl<C, H> HintRouter for OnlineHostBackend<C, H>
 where
@@ -131,3 +179,54 @@ where
         preimage.ok_or(PreimageOracleError::KeyNotFound)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::MemoryKeyValueStore;
+    use alloy_primitives::keccak256;
+    use std::io::Write;
+
+    /// A configuration whose hints are raw strings, stored in the key-value store under their
+    /// keccak256 hash.
+    #[derive(Debug)]
+    struct MockCfg;
+
+    impl OnlineHostBackendCfg for MockCfg {
+        type Hint = String;
+        type Providers = ();
+    }
+
+    #[derive(Debug)]
+    struct MockHintHandler;
+
+    #[async_trait]
+    impl HintHandler for MockHintHandler {
+        type Cfg = MockCfg;
+
+        async fn fetch_hint(
+            hint: String,
+            _: &MockCfg,
+            _: &(),
+            kv: SharedKeyValueStore,
+        ) -> Result<()> {
+            kv.write().await.set(keccak256(&hint), hint.into_bytes())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replay_hints_file() {
+        let mut hint_file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(hint_file, "l1-block-header 0x01").unwrap();
+        writeln!(hint_file, "l2-block-header 0x02").unwrap();
+
+        let kv: SharedKeyValueStore = Arc::new(RwLock::new(MemoryKeyValueStore::new()));
+        let backend = OnlineHostBackend::new(MockCfg, kv.clone(), (), MockHintHandler);
+        assert_eq!(backend.replay_hints_file(hint_file.path()).await.unwrap(), 2);
+
+        let kv = kv.read().await;
+        for hint in ["l1-block-header 0x01", "l2-block-header 0x02"] {
+            assert_eq!(kv.get(keccak256(hint)), Some(hint.as_bytes().to_vec()));
+        }
+    }
+}
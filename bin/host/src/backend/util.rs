@@ -1,13 +1,156 @@
 //! Utilities for the preimage server backend.
 
 use crate::KeyValueStore;
-use alloy_consensus::EMPTY_ROOT_HASH;
-use alloy_primitives::keccak256;
-use alloy_rlp::EMPTY_STRING_CODE;
-use anyhow::Result;
+use alloy_consensus::{Header, EMPTY_ROOT_HASH};
+use alloy_eips::{eip2718::Encodable2718, eip4844::BlobTransactionSidecarItem};
+use alloy_primitives::{address, keccak256, Address, Bytes, B256};
+use alloy_provider::{network::Network, Provider, RootProvider};
+use alloy_rlp::{Decodable, EMPTY_STRING_CODE};
+use alloy_rpc_types::TransactionReceipt;
+use anyhow::{anyhow, ensure, Result};
+use futures::{stream, StreamExt, TryStreamExt};
+use kona_executor::OutputRootComponents;
 use kona_preimage::{PreimageKey, PreimageKeyType};
+use op_alloy_network::Optimism;
 use tokio::sync::RwLock;
 
+/// The address of the L2 to L1 message passer predeploy.
+const L2_TO_L1_MESSAGE_PASSER_ADDRESS: Address =
+    address!("4200000000000000000000000000000000000016");
+
+/// The maximum number of headers fetched for a single ancestor headers hint.
+const MAX_ANCESTOR_HEADERS: u64 = 8192;
+
+/// The maximum number of concurrent requests made when fetching ancestor headers.
+const ANCESTOR_HEADERS_CONCURRENCY: usize = 16;
+
+/// Fetches the EIP-2718 encoded receipts for the block with the given hash.
+///
+/// The raw receipts are first requested via `debug_getRawReceipts`. If the endpoint is unavailable,
+/// the JSON receipts are fetched via `eth_getBlockReceipts` and re-encoded with `encode`.
+pub(crate) async fn fetch_raw_receipts<N, F>(
+    provider: &RootProvider<N>,
+    hash: B256,
+    encode: F,
+) -> Result<Vec<Bytes>>
+where
+    N: Network,
+    F: Fn(N::ReceiptResponse) -> Bytes,
+{
+    let raw_err = match provider.client().request("debug_getRawReceipts", [hash]).await {
+        Ok(raw_receipts) => return Ok(raw_receipts),
+        Err(e) => e,
+    };
+
+    let receipts = provider
+        .get_block_receipts(hash.into())
+        .await
+        .map_err(|e| {
+            anyhow!(
+                "Failed to fetch receipts for block {hash}: debug_getRawReceipts: {raw_err}, \
+                 eth_getBlockReceipts: {e}"
+            )
+        })?
+        .ok_or_else(|| anyhow!("Receipts not found for block {hash}"))?;
+
+    Ok(receipts.into_iter().map(encode).collect())
+}
+
+/// Reconstructs the 128-byte output root preimage of the L2 block with the given number from its
+/// header (`debug_getRawHeader`) and the account proof of the L2 to L1 message passer, and stores
+/// it in the [KeyValueStore] keyed by its keccak256 hash, which must equal `output_root`.
+pub(crate) async fn store_output_root<KV: KeyValueStore + ?Sized>(
+    kv: &RwLock<KV>,
+    provider: &RootProvider<Optimism>,
+    block_number: u64,
+    output_root: B256,
+) -> Result<()> {
+    // Fetch the header for the L2 block.
+    let raw_header: Bytes = provider
+        .client()
+        .request("debug_getRawHeader", [format!("0x{block_number:x}")])
+        .await
+        .map_err(|e| anyhow!("Failed to fetch header RLP: {e}"))?;
+    let header = Header::decode(&mut raw_header.as_ref())?;
+
+    // Fetch the storage root of the L2 to L1 message passer for the L2 block.
+    let l2_to_l1_message_passer = provider
+        .get_proof(L2_TO_L1_MESSAGE_PASSER_ADDRESS, Default::default())
+        .block_id(block_number.into())
+        .await?;
+
+    let raw_output = OutputRootComponents {
+        state_root: header.state_root,
+        storage_root: l2_to_l1_message_passer.storage_hash,
+        block_hash: header.hash_slow(),
+    }
+    .encode();
+
+    let computed = keccak256(raw_output);
+    ensure!(
+        computed == output_root,
+        "Output root does not match L2 head. Expected: {output_root}, got: {computed}"
+    );
+
+    kv.write().await.set(PreimageKey::new_keccak256(*computed).into(), raw_output.into())
+}
+
+/// Fetches the raw headers of the ancestors of the L2 block with the given hash, down to the block
+/// with the given number, and stores them in the [KeyValueStore] keyed by their keccak256 hashes.
+///
+/// The ancestors are fetched by number concurrently, and then checked to form the chain of parent
+/// hashes leading to the head, so that a reorg between the requests cannot store a foreign header.
+pub(crate) async fn store_ancestor_headers<KV: KeyValueStore + ?Sized>(
+    kv: &RwLock<KV>,
+    provider: &RootProvider<Optimism>,
+    head_hash: B256,
+    block_number: u64,
+) -> Result<()> {
+    let raw_head: Bytes = provider.client().request("debug_getRawHeader", [head_hash]).await?;
+    let head = Header::decode(&mut raw_head.as_ref())?;
+
+    let oldest = block_number.min(head.number);
+    ensure!(
+        head.number - oldest < MAX_ANCESTOR_HEADERS,
+        "Ancestor header walk exceeds {MAX_ANCESTOR_HEADERS} headers"
+    );
+
+    let ancestors: Vec<Bytes> = stream::iter(oldest..head.number)
+        .map(|number| provider.client().request("debug_getRawHeader", [format!("0x{number:x}")]))
+        .buffered(ANCESTOR_HEADERS_CONCURRENCY)
+        .try_collect()
+        .await
+        .map_err(|e| anyhow!("Failed to fetch ancestor header: {e}"))?;
+
+    let mut parent_hash = head.parent_hash;
+    for raw_header in ancestors.iter().rev() {
+        let hash = keccak256(raw_header.as_ref());
+        ensure!(
+            hash == parent_hash,
+            "Ancestor header does not match the parent hash. Expected: {parent_hash}, got: {hash}"
+        );
+        parent_hash = Header::decode(&mut raw_header.as_ref())?.parent_hash;
+    }
+
+    let mut kv_lock = kv.write().await;
+    for raw_header in std::iter::once(raw_head).chain(ancestors) {
+        let hash = keccak256(raw_header.as_ref());
+        kv_lock.set(PreimageKey::new_keccak256(*hash).into(), raw_header.into())?;
+    }
+
+    Ok(())
+}
+
+/// Re-encodes an L1 JSON RPC receipt into its EIP-2718 consensus encoding.
+pub(crate) fn encode_receipt(receipt: TransactionReceipt) -> Bytes {
+    receipt.into_primitives_receipt().inner.encoded_2718().into()
+}
+
+/// Re-encodes an L2 JSON RPC receipt into its EIP-2718 consensus encoding.
+pub(crate) fn encode_op_receipt(receipt: <Optimism as Network>::ReceiptResponse) -> Bytes {
+    receipt.inner.inner.map_logs(|log| log.inner).encoded_2718().into()
+}
+
 /// Constructs a merkle patricia trie from the ordered list passed and stores all encoded
 /// intermediate nodes of the trie in the [KeyValueStore].
 pub(crate) async fn store_ordered_trie<KV: KeyValueStore + ?Sized, T: AsRef<[u8]>>(
@@ -39,3 +182,338 @@ pub(crate) async fn store_ordered_trie<KV: KeyValueStore + ?Sized, T: AsRef<[u8]
 
     Ok(())
 }
+
+/// Stores the preimages of a blob in the [KeyValueStore], keyed by the blob's versioned hash.
+///
+/// The KZG commitment is stored under the versioned hash. Each of the first
+/// `field_elements_per_blob` field elements is stored under the keccak256 hash of
+/// `abi.encodePacked(sidecar.KZGCommitment, uint256(i))`, followed by the KZG proof at index
+/// `field_elements_per_blob`.
+pub(crate) async fn store_blob<KV: KeyValueStore + ?Sized>(
+    kv: &RwLock<KV>,
+    blob_hash: B256,
+    sidecar: &BlobTransactionSidecarItem,
+    field_elements_per_blob: u64,
+) -> Result<()> {
+    // Bound the count by the blob's capacity, rather than shifting the count, which could wrap.
+    ensure!(
+        field_elements_per_blob <= (sidecar.blob.len() >> 5) as u64,
+        "Blob holds fewer than {field_elements_per_blob} field elements"
+    );
+
+    let mut kv_write_lock = kv.write().await;
+
+    // Set the preimage for the blob commitment.
+    kv_write_lock.set(
+        PreimageKey::new(*blob_hash, PreimageKeyType::Sha256).into(),
+        sidecar.kzg_commitment.to_vec(),
+    )?;
+
+    // Write all the field elements to the key-value store.
+    let mut blob_key = [0u8; 80];
+    blob_key[..48].copy_from_slice(sidecar.kzg_commitment.as_ref());
+    for i in 0..field_elements_per_blob {
+        blob_key[72..].copy_from_slice(i.to_be_bytes().as_ref());
+        let blob_key_hash = keccak256(blob_key.as_ref());
+
+        kv_write_lock.set(PreimageKey::new_keccak256(*blob_key_hash).into(), blob_key.into())?;
+        kv_write_lock.set(
+            PreimageKey::new(*blob_key_hash, PreimageKeyType::Blob).into(),
+            sidecar.blob[(i as usize) << 5..(i as usize + 1) << 5].to_vec(),
+        )?;
+    }
+
+    // Write the KZG Proof as the last element.
+    blob_key[72..].copy_from_slice(field_elements_per_blob.to_be_bytes().as_ref());
+    let blob_key_hash = keccak256(blob_key.as_ref());
+
+    kv_write_lock.set(PreimageKey::new_keccak256(*blob_key_hash).into(), blob_key.into())?;
+    kv_write_lock.set(
+        PreimageKey::new(*blob_key_hash, PreimageKeyType::Blob).into(),
+        sidecar.kzg_proof.to_vec(),
+    )?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::kv::MemoryKeyValueStore;
+    use alloy_consensus::{Receipt, ReceiptEnvelope};
+    use alloy_eips::eip4844::{Blob, Bytes48};
+    use alloy_rlp::Encodable;
+    use kona_providers_alloy::test_utils::MockTransport;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_fetch_raw_receipts_json_fallback() {
+        let transport = MockTransport::new();
+        let provider = RootProvider::new(transport.client());
+
+        // The raw-debug endpoint is unavailable; only JSON receipts are served.
+        transport.push_failure_msg("the method debug_getRawReceipts does not exist");
+        transport.push_success(&json!([
+            {
+                "type": "0x2",
+                "status": "0x1",
+                "cumulativeGasUsed": "0x5208",
+                "logs": [],
+                "logsBloom": format!("0x{}", "00".repeat(256)),
+                "transactionHash": B256::with_last_byte(1),
+                "transactionIndex": "0x0",
+                "blockHash": B256::with_last_byte(0xFF),
+                "blockNumber": "0x1",
+                "gasUsed": "0x5208",
+                "effectiveGasPrice": "0x1",
+                "from": "0x0000000000000000000000000000000000000001",
+                "to": "0x0000000000000000000000000000000000000002",
+                "contractAddress": null
+            },
+            {
+                "type": "0x0",
+                "status": "0x0",
+                "cumulativeGasUsed": "0xa410",
+                "logs": [],
+                "logsBloom": format!("0x{}", "00".repeat(256)),
+                "transactionHash": B256::with_last_byte(2),
+                "transactionIndex": "0x1",
+                "blockHash": B256::with_last_byte(0xFF),
+                "blockNumber": "0x1",
+                "gasUsed": "0x5208",
+                "effectiveGasPrice": "0x1",
+                "from": "0x0000000000000000000000000000000000000001",
+                "to": "0x0000000000000000000000000000000000000002",
+                "contractAddress": null
+            }
+        ]));
+
+        let receipts = fetch_raw_receipts(&provider, B256::with_last_byte(0xFF), encode_receipt)
+            .await
+            .unwrap();
+
+        let expected = [
+            ReceiptEnvelope::Eip1559(
+                Receipt { status: true.into(), cumulative_gas_used: 21_000, logs: vec![] }
+                    .with_bloom(),
+            ),
+            ReceiptEnvelope::Legacy(
+                Receipt { status: false.into(), cumulative_gas_used: 42_000, logs: vec![] }
+                    .with_bloom(),
+            ),
+        ]
+        .iter()
+        .map(|r| Bytes::from(r.encoded_2718()))
+        .collect::<Vec<_>>();
+        assert_eq!(receipts, expected);
+
+        let kv = RwLock::new(MemoryKeyValueStore::new());
+        store_ordered_trie(&kv, receipts.as_slice()).await.unwrap();
+
+        let mut hb = kona_mpt::ordered_trie_with_encoder(expected.as_slice(), |node, buf| {
+            buf.put_slice(node.as_ref());
+        });
+        let root = hb.root();
+        let root_key = PreimageKey::new(*root, PreimageKeyType::Keccak256);
+        assert!(kv.read().await.get(root_key.into()).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_raw_receipts_no_endpoint() {
+        let transport = MockTransport::new();
+        let provider = RootProvider::new(transport.client());
+
+        transport.push_failure_msg("the method debug_getRawReceipts does not exist");
+        transport.push_failure_msg("the method eth_getBlockReceipts does not exist");
+
+        let err = fetch_raw_receipts(&provider, B256::ZERO, encode_receipt).await.unwrap_err();
+        assert!(err.to_string().contains("debug_getRawReceipts"));
+        assert!(err.to_string().contains("eth_getBlockReceipts"));
+    }
+
+    #[tokio::test]
+    async fn test_store_blob_field_elements() {
+        const FIELD_ELEMENTS: u64 = 4;
+
+        let mut blob = Blob::default();
+        (0..FIELD_ELEMENTS as usize).for_each(|i| blob[(i << 5) + 31] = i as u8 + 1);
+        let sidecar = BlobTransactionSidecarItem {
+            index: 0,
+            blob: Box::new(blob),
+            kzg_commitment: Bytes48::repeat_byte(0x01),
+            kzg_proof: Bytes48::repeat_byte(0x02),
+        };
+        let blob_hash = B256::repeat_byte(0xFF);
+
+        let kv = RwLock::new(MemoryKeyValueStore::new());
+        store_blob(&kv, blob_hash, &sidecar, FIELD_ELEMENTS).await.unwrap();
+
+        // The commitment, plus a key preimage and a value for each field element and the proof.
+        let kv = kv.read().await;
+        assert_eq!(kv.keys().len(), 1 + 2 * (FIELD_ELEMENTS as usize + 1));
+
+        let mut blob_key = [0u8; 80];
+        blob_key[..48].copy_from_slice(sidecar.kzg_commitment.as_ref());
+        for i in 0..FIELD_ELEMENTS {
+            blob_key[72..].copy_from_slice(i.to_be_bytes().as_ref());
+            let key = PreimageKey::new(*keccak256(blob_key.as_ref()), PreimageKeyType::Blob);
+            assert_eq!(kv.get(key.into()).unwrap(), blob[(i as usize) << 5..(i as usize + 1) << 5]);
+        }
+
+        blob_key[72..].copy_from_slice(FIELD_ELEMENTS.to_be_bytes().as_ref());
+        let key = PreimageKey::new(*keccak256(blob_key.as_ref()), PreimageKeyType::Blob);
+        assert_eq!(kv.get(key.into()).unwrap(), sidecar.kzg_proof.to_vec());
+    }
+
+    #[tokio::test]
+    async fn test_store_blob_too_many_field_elements() {
+        let sidecar = BlobTransactionSidecarItem::default();
+        let kv = RwLock::new(MemoryKeyValueStore::new());
+
+        let field_elements = (sidecar.blob.len() as u64 >> 5) + 1;
+        assert!(store_blob(&kv, B256::ZERO, &sidecar, field_elements).await.is_err());
+        assert!(kv.read().await.keys().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_store_blob_field_elements_overflow() {
+        let sidecar = BlobTransactionSidecarItem::default();
+        let kv = RwLock::new(MemoryKeyValueStore::new());
+
+        // Counts whose byte length wraps around to fit within the blob are rejected as well.
+        for field_elements in [1 << 59, (1 << 59) + 1, u64::MAX] {
+            assert!(store_blob(&kv, B256::ZERO, &sidecar, field_elements).await.is_err());
+        }
+        assert!(kv.read().await.keys().is_empty());
+
+        // The full capacity of the blob is accepted.
+        let field_elements = sidecar.blob.len() as u64 >> 5;
+        store_blob(&kv, B256::ZERO, &sidecar, field_elements).await.unwrap();
+        assert_eq!(kv.read().await.keys().len(), 1 + 2 * (field_elements as usize + 1));
+    }
+
+    /// Queues the responses used to reconstruct the output of an L2 block, and returns the
+    /// expected output root preimage and its hash.
+    fn mock_output_reconstruction(transport: &MockTransport) -> ([u8; 128], B256) {
+        let header =
+            Header { number: 10, state_root: B256::repeat_byte(0x01), ..Default::default() };
+        let storage_root = B256::repeat_byte(0x02);
+
+        let mut raw_header = Vec::new();
+        header.encode(&mut raw_header);
+        transport.push_success(&Bytes::from(raw_header));
+        transport.push_success(&json!({
+            "address": L2_TO_L1_MESSAGE_PASSER_ADDRESS,
+            "balance": "0x0",
+            "codeHash": B256::ZERO,
+            "nonce": "0x0",
+            "storageHash": storage_root,
+            "accountProof": [],
+            "storageProof": []
+        }));
+
+        let mut raw_output = [0u8; 128];
+        raw_output[32..64].copy_from_slice(header.state_root.as_ref());
+        raw_output[64..96].copy_from_slice(storage_root.as_ref());
+        raw_output[96..128].copy_from_slice(header.hash_slow().as_ref());
+        (raw_output, keccak256(raw_output))
+    }
+
+    #[tokio::test]
+    async fn test_store_output_root_reconstructed() {
+        let transport = MockTransport::new();
+        let provider = RootProvider::<Optimism>::new(transport.client());
+        let (raw_output, output_root) = mock_output_reconstruction(&transport);
+
+        let kv = RwLock::new(MemoryKeyValueStore::new());
+        store_output_root(&kv, &provider, 10, output_root).await.unwrap();
+        assert_eq!(transport.methods(), ["debug_getRawHeader", "eth_getProof"]);
+
+        let kv = kv.read().await;
+        assert_eq!(kv.keys().len(), 1);
+        assert_eq!(
+            kv.get(PreimageKey::new_keccak256(*output_root).into()).unwrap(),
+            raw_output.to_vec()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_store_output_root_mismatch() {
+        let transport = MockTransport::new();
+        let provider = RootProvider::<Optimism>::new(transport.client());
+        mock_output_reconstruction(&transport);
+
+        let kv = RwLock::new(MemoryKeyValueStore::new());
+        let err = store_output_root(&kv, &provider, 10, B256::ZERO).await.unwrap_err();
+        assert!(err.to_string().contains("Output root does not match"));
+        assert!(kv.read().await.keys().is_empty());
+    }
+
+    /// Encodes the given header, returning its raw RLP and hash.
+    fn raw_header(header: &Header) -> (Bytes, B256) {
+        let mut raw_header = Vec::new();
+        header.encode(&mut raw_header);
+        let hash = keccak256(&raw_header);
+        (raw_header.into(), hash)
+    }
+
+    #[tokio::test]
+    async fn test_store_ancestor_headers() {
+        let transport = MockTransport::new();
+        let provider = RootProvider::<Optimism>::new(transport.client());
+
+        let (raw_parent, parent_hash) = raw_header(&Header { number: 9, ..Default::default() });
+        let (raw_head, head_hash) =
+            raw_header(&Header { number: 10, parent_hash, ..Default::default() });
+        transport.push_success(&raw_head);
+        transport.push_success(&raw_parent);
+
+        let kv = RwLock::new(MemoryKeyValueStore::new());
+        store_ancestor_headers(&kv, &provider, head_hash, 9).await.unwrap();
+        assert_eq!(transport.methods(), ["debug_getRawHeader", "debug_getRawHeader"]);
+
+        let kv = kv.read().await;
+        assert_eq!(kv.keys().len(), 2);
+        assert_eq!(
+            kv.get(PreimageKey::new_keccak256(*head_hash).into()).unwrap(),
+            raw_head.to_vec()
+        );
+        assert_eq!(
+            kv.get(PreimageKey::new_keccak256(*parent_hash).into()).unwrap(),
+            raw_parent.to_vec()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_store_ancestor_headers_not_parent() {
+        let transport = MockTransport::new();
+        let provider = RootProvider::<Optimism>::new(transport.client());
+
+        let (raw_other, _) = raw_header(&Header { number: 9, ..Default::default() });
+        let parent_hash = B256::repeat_byte(0xFF);
+        let (raw_head, head_hash) =
+            raw_header(&Header { number: 10, parent_hash, ..Default::default() });
+        transport.push_success(&raw_head);
+        transport.push_success(&raw_other);
+
+        let kv = RwLock::new(MemoryKeyValueStore::new());
+        let err = store_ancestor_headers(&kv, &provider, head_hash, 9).await.unwrap_err();
+        assert!(err.to_string().contains("Ancestor header does not match the parent hash"));
+        assert!(kv.read().await.keys().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_store_ancestor_headers_too_many() {
+        let transport = MockTransport::new();
+        let provider = RootProvider::<Optimism>::new(transport.client());
+
+        let (raw_head, head_hash) =
+            raw_header(&Header { number: MAX_ANCESTOR_HEADERS, ..Default::default() });
+        transport.push_success(&raw_head);
+
+        let kv = RwLock::new(MemoryKeyValueStore::new());
+        let err = store_ancestor_headers(&kv, &provider, head_hash, 0).await.unwrap_err();
+        assert!(err.to_string().contains("Ancestor header walk exceeds"));
+        assert_eq!(transport.methods(), ["debug_getRawHeader"]);
+    }
+}
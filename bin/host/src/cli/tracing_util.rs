@@ -1,22 +1,76 @@
 //! Contains utilities for initializing the tracing subscriber.
 
 use anyhow::{anyhow, Result};
-use tracing::Level;
+use tracing::{level_filters::LevelFilter, Level};
+use tracing_subscriber::EnvFilter;
 
 /// Initializes the tracing subscriber
 ///
+/// If the `RUST_LOG` environment variable is set, its directives (e.g.
+/// `kona_derive=debug,hyper=warn`) are used to filter events. Otherwise, the global max level is
+/// derived from the `verbosity_level`.
+///
 /// # Arguments
 /// * `verbosity_level` - The verbosity level (0-2)
 ///
 /// # Returns
 /// * `Result<()>` - Ok if successful, Err otherwise.
 pub fn init_tracing_subscriber(verbosity_level: u8) -> Result<()> {
-    let subscriber = tracing_subscriber::fmt()
-        .with_max_level(match verbosity_level {
-            0 => Level::INFO,
-            1 => Level::DEBUG,
-            _ => Level::TRACE,
-        })
-        .finish();
+    let directives = std::env::var(EnvFilter::DEFAULT_ENV).ok();
+    let filter = build_env_filter(directives.as_deref(), verbosity_level)?;
+    let subscriber = tracing_subscriber::fmt().with_env_filter(filter).finish();
     tracing::subscriber::set_global_default(subscriber).map_err(|e| anyhow!(e))
 }
+
+/// Builds the [EnvFilter] for the tracing subscriber.
+///
+/// # Arguments
+/// * `directives` - The `RUST_LOG`-style filter directives, if present.
+/// * `verbosity_level` - The verbosity level (0-2), used if no directives are present.
+///
+/// # Returns
+/// * `Result<EnvFilter>` - Ok if the directives parsed successfully, Err otherwise.
+fn build_env_filter(directives: Option<&str>, verbosity_level: u8) -> Result<EnvFilter> {
+    match directives.filter(|d| !d.trim().is_empty()) {
+        Some(directives) => EnvFilter::try_new(directives).map_err(|e| anyhow!(e)),
+        None => {
+            let level = match verbosity_level {
+                0 => Level::INFO,
+                1 => Level::DEBUG,
+                _ => Level::TRACE,
+            };
+            Ok(EnvFilter::default().add_directive(LevelFilter::from_level(level).into()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_env_filter_from_directives() {
+        let filter = build_env_filter(Some("kona_derive=debug,hyper=warn"), 0).unwrap();
+        let rendered = filter.to_string();
+        assert!(rendered.contains("kona_derive=debug"));
+        assert!(rendered.contains("hyper=warn"));
+        assert_eq!(filter.max_level_hint(), Some(LevelFilter::DEBUG));
+    }
+
+    #[test]
+    fn test_env_filter_from_verbosity() {
+        let filter = build_env_filter(None, 0).unwrap();
+        assert_eq!(filter.max_level_hint(), Some(LevelFilter::INFO));
+
+        let filter = build_env_filter(Some(""), 1).unwrap();
+        assert_eq!(filter.max_level_hint(), Some(LevelFilter::DEBUG));
+
+        let filter = build_env_filter(None, 2).unwrap();
+        assert_eq!(filter.max_level_hint(), Some(LevelFilter::TRACE));
+    }
+
+    #[test]
+    fn test_env_filter_invalid_directives() {
+        assert!(build_env_filter(Some("kona_derive=notalevel"), 0).is_err());
+    }
+}
@@ -0,0 +1,130 @@
+//! Blob sidecar sources for the host.
+
+use alloy_eips::eip4844::{
+    kzg_to_versioned_hash, Blob, BlobTransactionSidecar, BlobTransactionSidecarItem,
+    IndexedBlobHash, BYTES_PER_BLOB,
+};
+use kona_derive::errors::BlobProviderError;
+use kona_providers_alloy::{OnlineBeaconClient, OnlineBlobProvider};
+use maili_protocol::BlockInfo;
+use std::path::PathBuf;
+
+/// A blob sidecar source that reads blobs from a local directory, rather than a beacon node.
+///
+/// Each blob is stored raw in a file named `<versioned_hash>.blob`. The KZG commitment and proof
+/// of the blob are computed when it is loaded, and the commitment is checked against the
+/// versioned hash.
+#[derive(Debug, Clone)]
+pub struct FileBlobProvider {
+    /// The directory containing the blob files.
+    pub dir: PathBuf,
+}
+
+impl FileBlobProvider {
+    /// Creates a new [FileBlobProvider] reading blobs from the given directory.
+    pub const fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// Returns the path of the file holding the blob with the given versioned hash.
+    pub fn blob_path(&self, blob_hash: &IndexedBlobHash) -> PathBuf {
+        self.dir.join(format!("{}.blob", blob_hash.hash))
+    }
+
+    /// Loads the blob sidecar for the given blob hash from its file.
+    pub fn load_sidecar(
+        &self,
+        blob_hash: &IndexedBlobHash,
+    ) -> Result<BlobTransactionSidecarItem, BlobProviderError> {
+        let path = self.blob_path(blob_hash);
+        let raw = std::fs::read(&path).map_err(|e| {
+            BlobProviderError::Backend(format!("Failed to read blob file {}: {e}", path.display()))
+        })?;
+        if raw.len() != BYTES_PER_BLOB {
+            return Err(BlobProviderError::Backend(format!(
+                "Invalid blob file length {}, expected {BYTES_PER_BLOB}",
+                raw.len()
+            )));
+        }
+
+        let sidecar = BlobTransactionSidecar::try_from_blobs(vec![Blob::from_slice(&raw)])
+            .map_err(|e| BlobProviderError::Backend(e.to_string()))?;
+        let kzg_commitment = sidecar.commitments[0];
+        if kzg_to_versioned_hash(kzg_commitment.as_slice()) != blob_hash.hash {
+            return Err(BlobProviderError::Backend(format!(
+                "Blob file {} does not match versioned hash {}",
+                path.display(),
+                blob_hash.hash
+            )));
+        }
+
+        Ok(BlobTransactionSidecarItem {
+            index: blob_hash.index,
+            blob: Box::new(sidecar.blobs[0]),
+            kzg_commitment,
+            kzg_proof: sidecar.proofs[0],
+        })
+    }
+
+    /// Loads the blob sidecars for the given blob hashes, in order.
+    pub async fn fetch_filtered_sidecars(
+        &self,
+        _: &BlockInfo,
+        blob_hashes: &[IndexedBlobHash],
+    ) -> Result<Vec<BlobTransactionSidecarItem>, BlobProviderError> {
+        blob_hashes.iter().map(|blob_hash| self.load_sidecar(blob_hash)).collect()
+    }
+}
+
+/// The source of blob sidecars used by the host to serve blob hints.
+#[derive(Debug, Clone)]
+pub enum HostBlobProvider {
+    /// Blobs are fetched from an L1 beacon node.
+    Online(OnlineBlobProvider<OnlineBeaconClient>),
+    /// Blobs are read from a local directory.
+    File(FileBlobProvider),
+}
+
+impl HostBlobProvider {
+    /// Fetches the blob sidecars for the given block reference and blob hashes.
+    pub async fn fetch_filtered_sidecars(
+        &self,
+        block_ref: &BlockInfo,
+        blob_hashes: &[IndexedBlobHash],
+    ) -> Result<Vec<BlobTransactionSidecarItem>, BlobProviderError> {
+        match self {
+            Self::Online(provider) => {
+                provider.fetch_filtered_sidecars(block_ref, blob_hashes).await
+            }
+            Self::File(provider) => provider.fetch_filtered_sidecars(block_ref, blob_hashes).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloy_primitives::B256;
+
+    #[tokio::test]
+    async fn test_file_blob_provider_missing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let provider = FileBlobProvider::new(dir.path().to_path_buf());
+
+        let blob_hash = IndexedBlobHash { index: 0, hash: B256::repeat_byte(0x01) };
+        assert!(provider
+            .fetch_filtered_sidecars(&BlockInfo::default(), &[blob_hash])
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_file_blob_provider_hash_mismatch() {
+        let dir = tempfile::tempdir().unwrap();
+        let provider = FileBlobProvider::new(dir.path().to_path_buf());
+
+        let blob_hash = IndexedBlobHash { index: 0, hash: B256::repeat_byte(0x01) };
+        std::fs::write(provider.blob_path(&blob_hash), Blob::default().as_slice()).unwrap();
+        assert!(provider.load_sidecar(&blob_hash).is_err());
+    }
+}
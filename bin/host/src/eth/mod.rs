@@ -3,14 +3,66 @@
 use alloy_provider::{Network, RootProvider};
 use alloy_rpc_client::RpcClient;
 use alloy_transport_http::Http;
-use reqwest::Client;
+use anyhow::{Context, Result};
+use reqwest::{Client, Url};
+use std::time::Duration;
+
+mod blobs;
+pub use blobs::{FileBlobProvider, HostBlobProvider};
 
 mod precompiles;
 pub(crate) use precompiles::execute;
+pub use precompiles::{blob_kzg_settings, load_kzg_settings};
+
+mod trie_db;
+pub use trie_db::RpcTrieDBProvider;
+
+/// Returns an HTTP provider for the given URL, or an error if the URL is invalid.
+///
+/// If a `timeout` is provided, each request made by the provider will fail with a timeout error if
+/// a response is not received within it. Otherwise, requests never time out.
+pub fn http_provider<N: Network>(url: &str, timeout: Option<Duration>) -> Result<RootProvider<N>> {
+    let url: Url = url.parse().with_context(|| format!("Invalid RPC URL: {url}"))?;
+    let mut client = Client::builder();
+    if let Some(timeout) = timeout {
+        client = client.timeout(timeout);
+    }
+    let http = Http::with_client(client.build()?, url);
+    Ok(RootProvider::new(RpcClient::new(http, true)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloy_provider::{network::Ethereum, Provider};
+    use tokio::net::TcpListener;
+
+    #[tokio::test]
+    async fn test_http_provider_timeout() {
+        // A server that accepts connections, but never responds.
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let mut conns = Vec::new();
+            loop {
+                let (conn, _) = listener.accept().await.unwrap();
+                conns.push(conn);
+            }
+        });
+
+        let provider =
+            http_provider::<Ethereum>(&format!("http://{addr}"), Some(Duration::from_millis(100)))
+                .unwrap();
+        let res = tokio::time::timeout(Duration::from_secs(5), provider.get_block_number())
+            .await
+            .expect("Request hung instead of timing out");
+        assert!(res.is_err());
+
+        server.abort();
+    }
 
-/// Returns an HTTP provider for the given URL.
-pub fn http_provider<N: Network>(url: &str) -> RootProvider<N> {
-    let url = url.parse().unwrap();
-    let http = Http::<Client>::new(url);
-    RootProvider::new(RpcClient::new(http, true))
+    #[test]
+    fn test_http_provider_invalid_url() {
+        assert!(http_provider::<Ethereum>("not a url", None).is_err());
+    }
 }
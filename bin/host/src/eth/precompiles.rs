@@ -1,11 +1,16 @@
 //! Accelerated precompile runner for the host program.
 
+use alloy_eips::eip4844::env_settings::EnvKzgSettings as BlobKzgSettings;
 use alloy_primitives::{Address, Bytes};
 use anyhow::{anyhow, Result};
 use revm::{
     precompile::{self, PrecompileWithAddress},
-    primitives::{Env, Precompile},
+    primitives::{
+        kzg::{EnvKzgSettings, KzgSettings},
+        Env, Precompile,
+    },
 };
+use std::{path::Path, sync::Arc};
 
 /// List of precompiles that are accelerated by the host program.
 pub(crate) const ACCELERATED_PRECOMPILES: &[PrecompileWithAddress] = &[
@@ -15,8 +20,35 @@ pub(crate) const ACCELERATED_PRECOMPILES: &[PrecompileWithAddress] = &[
     precompile::kzg_point_evaluation::POINT_EVALUATION, // KZG point evaluation
 ];
 
-/// Executes an accelerated precompile on [revm].
-pub(crate) fn execute<T: Into<Bytes>>(address: Address, input: T) -> Result<Vec<u8>> {
+/// Loads the KZG settings used by the point evaluation precompile.
+///
+/// If a trusted setup file is given, the settings are loaded from it. Otherwise, the Ethereum
+/// mainnet trusted setup is used.
+pub fn load_kzg_settings(trusted_setup: Option<&Path>) -> Result<EnvKzgSettings> {
+    let Some(path) = trusted_setup else {
+        return Ok(EnvKzgSettings::Default);
+    };
+    let settings = KzgSettings::load_trusted_setup_file(path)
+        .map_err(|e| anyhow!("Failed to load KZG trusted setup from {}: {e}", path.display()))?;
+    Ok(EnvKzgSettings::Custom(Arc::new(settings)))
+}
+
+/// Returns the KZG settings used to verify blobs fetched from the beacon node, backed by the same
+/// trusted setup as the given point evaluation precompile settings.
+pub fn blob_kzg_settings(kzg_settings: &EnvKzgSettings) -> BlobKzgSettings {
+    match kzg_settings {
+        EnvKzgSettings::Default => BlobKzgSettings::Default,
+        EnvKzgSettings::Custom(settings) => BlobKzgSettings::Custom(settings.clone()),
+    }
+}
+
+/// Executes an accelerated precompile on [revm], using the given KZG settings for the point
+/// evaluation precompile.
+pub(crate) fn execute<T: Into<Bytes>>(
+    address: Address,
+    input: T,
+    kzg_settings: &EnvKzgSettings,
+) -> Result<Vec<u8>> {
     if let Some(precompile) =
         ACCELERATED_PRECOMPILES.iter().find(|precompile| precompile.0 == address)
     {
@@ -29,8 +61,11 @@ pub(crate) fn execute<T: Into<Bytes>>(address: Address, input: T) -> Result<Vec<
                 Ok(output.bytes.into())
             }
             Precompile::Env(env_precompile) => {
-                // Use default environment for KZG point evaluation.
-                let output = env_precompile(&input.into(), u64::MAX, &Env::default())
+                // Use the default environment with the given KZG settings for KZG point
+                // evaluation.
+                let mut env = Env::default();
+                env.cfg.kzg_settings = kzg_settings.clone();
+                let output = env_precompile(&input.into(), u64::MAX, &env)
                     .map_err(|e| anyhow!("Failed precompile execution: {e}"))?;
 
                 Ok(output.bytes.into())
@@ -41,3 +76,60 @@ pub(crate) fn execute<T: Into<Bytes>>(address: Address, input: T) -> Result<Vec<
         anyhow::bail!("Precompile not accelerated");
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloy_eips::eip4844::{
+        kzg_to_versioned_hash,
+        trusted_setup_points::{G1_POINTS, G2_POINTS},
+    };
+    use alloy_primitives::hex;
+
+    /// Returns the input of a valid point evaluation under the Ethereum mainnet trusted setup.
+    ///
+    /// Test vector: `verify_kzg_proof_case_correct_proof_31ebd010e6098750` from `c-kzg-4844`.
+    fn point_evaluation_input() -> Vec<u8> {
+        let commitment = hex!("8f59a8d2a1a625a17f3fea0fe5eb8c896db3764f3185481bc22f91b4aaffcca25f26936857bc3a7c2539ea8ec3a952b7");
+        let z = hex!("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000000");
+        let y = hex!("1522a4a7f34e1ea350ae07c29c96c7e79655aa926122e95fe69fcbd932ca49e9");
+        let proof = hex!("a62ad71d14c5719385c0686f1871430475bf3a00f0aa3f7b8dd99a9abc2160744faf0070725e00b60ad9a026a15b1a8c");
+        [kzg_to_versioned_hash(&commitment).as_slice(), &z, &y, &commitment, &proof].concat()
+    }
+
+    /// Returns a custom trusted setup that differs from the Ethereum mainnet trusted setup in the
+    /// `[τ]₂` point, under which mainnet KZG proofs no longer verify.
+    fn custom_kzg_settings() -> EnvKzgSettings {
+        let mut g2_points = G2_POINTS.0;
+        g2_points.swap(1, 2);
+        let settings = KzgSettings::load_trusted_setup(&G1_POINTS.0, &g2_points).unwrap();
+        EnvKzgSettings::Custom(Arc::new(settings))
+    }
+
+    #[test]
+    fn test_point_evaluation_custom_kzg_settings() {
+        let address = precompile::kzg_point_evaluation::POINT_EVALUATION.0;
+        let input = point_evaluation_input();
+
+        let output = execute(address, input.clone(), &EnvKzgSettings::Default).unwrap();
+        assert_eq!(output, precompile::kzg_point_evaluation::RETURN_VALUE.to_vec());
+
+        // The mainnet proof is rejected under the custom trusted setup.
+        assert!(execute(address, input, &custom_kzg_settings()).is_err());
+    }
+
+    #[test]
+    fn test_blob_kzg_settings_shares_trusted_setup() {
+        assert_eq!(blob_kzg_settings(&EnvKzgSettings::Default), BlobKzgSettings::Default);
+
+        let EnvKzgSettings::Custom(settings) = custom_kzg_settings() else {
+            panic!("Expected custom KZG settings");
+        };
+        let BlobKzgSettings::Custom(blob_settings) =
+            blob_kzg_settings(&EnvKzgSettings::Custom(settings.clone()))
+        else {
+            panic!("Expected custom blob KZG settings");
+        };
+        assert!(Arc::ptr_eq(&settings, &blob_settings));
+    }
+}
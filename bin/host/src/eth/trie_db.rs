@@ -0,0 +1,233 @@
+//! Contains the [RpcTrieDBProvider], which serves [TrieDBProvider] requests from an execution
+//! layer RPC.
+
+use alloy_consensus::Header;
+use alloy_primitives::{keccak256, Bytes, B256};
+use alloy_provider::{Network, RootProvider};
+use alloy_rlp::Decodable;
+use alloy_transport::TransportError;
+use anyhow::{anyhow, Result};
+use kona_executor::TrieDBProvider;
+use kona_mpt::{TrieNode, TrieProvider};
+use tokio::runtime::Handle;
+
+/// A [TrieDBProvider] that fetches trie nodes, bytecode, and headers directly from an archive
+/// node's `debug_dbGet` and `debug_getRawHeader` endpoints, bypassing the preimage oracle.
+///
+/// As the node is not trusted, every preimage is checked against the hash it was requested by.
+///
+/// This allows the [StatelessL2BlockExecutor] to re-execute blocks against a live node without the
+/// host/client split. Requests block on the current tokio runtime, which must be multi-threaded.
+///
+/// [StatelessL2BlockExecutor]: kona_executor::StatelessL2BlockExecutor
+#[derive(Debug, Clone)]
+pub struct RpcTrieDBProvider<N: Network> {
+    /// The RPC provider for the L2 execution layer.
+    provider: RootProvider<N>,
+}
+
+impl<N: Network> RpcTrieDBProvider<N> {
+    /// Creates a new [RpcTrieDBProvider] backed by the given RPC provider.
+    pub const fn new(provider: RootProvider<N>) -> Self {
+        Self { provider }
+    }
+
+    /// Fetches the database entry at the given key with `debug_dbGet`.
+    fn db_get(&self, key: Bytes) -> Result<Bytes> {
+        self.request_db_entry(&key)
+            .map_err(|e| anyhow!("Failed to fetch database entry {key}: {e}"))
+    }
+
+    /// Sends a `debug_dbGet` request for the given key, returning the raw RPC error on failure.
+    fn request_db_entry(&self, key: &Bytes) -> Result<Bytes, TransportError> {
+        tokio::task::block_in_place(move || {
+            Handle::current().block_on(async {
+                self.provider
+                    .client()
+                    .request::<&[Bytes; 1], Bytes>("debug_dbGet", &[key.clone()])
+                    .await
+            })
+        })
+    }
+}
+
+impl<N: Network> TrieProvider for RpcTrieDBProvider<N> {
+    type Error = anyhow::Error;
+
+    fn trie_node_by_hash(&self, key: B256) -> Result<TrieNode> {
+        let preimage = self.db_get(key.into())?;
+        verify_preimage(key, &preimage)?;
+        TrieNode::decode(&mut preimage.as_ref()).map_err(Into::into)
+    }
+}
+
+impl<N: Network> TrieDBProvider for RpcTrieDBProvider<N> {
+    fn bytecode_by_hash(&self, hash: B256) -> Result<Bytes> {
+        // geth hashdb scheme code hash key prefix
+        const CODE_PREFIX: u8 = b'c';
+
+        // Attempt to fetch the code with the geth hashdb scheme prefix first, falling back to the
+        // unprefixed code hash only if the node reports that the prefixed key does not exist.
+        let code_key = Bytes::from([&[CODE_PREFIX], hash.as_slice()].concat());
+        let code = match self.request_db_entry(&code_key) {
+            Ok(code) => code,
+            Err(e) if is_not_found(&e) => self.db_get(hash.into())?,
+            Err(e) => return Err(anyhow!("Failed to fetch code {hash}: {e}")),
+        };
+        verify_preimage(hash, &code)?;
+        Ok(code)
+    }
+
+    fn header_by_hash(&self, hash: B256) -> Result<Header> {
+        let raw_header: Bytes = tokio::task::block_in_place(move || {
+            Handle::current().block_on(async {
+                self.provider
+                    .client()
+                    .request::<&[B256; 1], Bytes>("debug_getRawHeader", &[hash])
+                    .await
+                    .map_err(|e| anyhow!("Failed to fetch header {hash}: {e}"))
+            })
+        })?;
+        verify_preimage(hash, &raw_header)?;
+        Header::decode(&mut raw_header.as_ref()).map_err(Into::into)
+    }
+}
+
+/// Verifies that the preimage returned by the node hashes to the key it was requested by.
+fn verify_preimage(hash: B256, preimage: &[u8]) -> Result<()> {
+    let actual = keccak256(preimage);
+    if actual != hash {
+        anyhow::bail!("Preimage mismatch: requested {hash}, received preimage of {actual}");
+    }
+    Ok(())
+}
+
+/// Returns whether the error is a `debug_dbGet` response for a key that is not in the database.
+fn is_not_found(error: &TransportError) -> bool {
+    error.as_error_resp().is_some_and(|payload| payload.message.contains("not found"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloy_consensus::{Sealable, Sealed};
+    use alloy_eips::eip2718::Encodable2718;
+    use alloy_primitives::{Address, TxKind};
+    use alloy_provider::network::Ethereum;
+    use alloy_rlp::Encodable;
+    use alloy_rpc_types_engine::PayloadAttributes;
+    use alloy_trie::TrieAccount;
+    use kona_executor::StatelessL2BlockExecutor;
+    use kona_mpt::{Nibbles, NoopTrieHinter, NoopTrieProvider};
+    use kona_providers_alloy::test_utils::MockTransport;
+    use maili_genesis::RollupConfig;
+    use op_alloy_consensus::{OpTxEnvelope, TxDeposit};
+    use op_alloy_rpc_types_engine::OpPayloadAttributes;
+
+    fn mocked_provider() -> (MockTransport, RpcTrieDBProvider<Ethereum>) {
+        let asserter = MockTransport::new();
+        let provider = RpcTrieDBProvider::new(RootProvider::new(asserter.client()));
+        (asserter, provider)
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_execute_block() {
+        let (asserter, provider) = mocked_provider();
+
+        // The parent state holds a single account, so the state trie is a single leaf node.
+        let depositor = Address::repeat_byte(0xDE);
+        let mut account = Vec::new();
+        TrieAccount::default().encode(&mut account);
+        let mut trie = TrieNode::Empty;
+        trie.insert(&Nibbles::unpack(keccak256(depositor)), account.into(), &NoopTrieProvider)
+            .unwrap();
+        let mut root_node = Vec::new();
+        trie.encode(&mut root_node);
+        trie.blind();
+
+        let config = RollupConfig::default();
+        let parent_header = Header {
+            gas_limit: 30_000_000,
+            state_root: trie.blinded_commitment().unwrap(),
+            ..Default::default()
+        }
+        .seal_slow();
+        let deposit = OpTxEnvelope::Deposit(Sealed::new_unchecked(
+            TxDeposit {
+                from: depositor,
+                gas_limit: 100_000,
+                to: TxKind::Create,
+                ..Default::default()
+            },
+            B256::ZERO,
+        ));
+        let payload = OpPayloadAttributes {
+            payload_attributes: PayloadAttributes { timestamp: 2, ..Default::default() },
+            gas_limit: Some(30_000_000),
+            transactions: Some(vec![deposit.encoded_2718().into()]),
+            ..Default::default()
+        };
+
+        // The root node is the only preimage fetched while executing the block.
+        asserter.push_success(&Bytes::from(root_node));
+        let mut executor = StatelessL2BlockExecutor::builder(&config, provider, NoopTrieHinter)
+            .with_parent_header(parent_header)
+            .build();
+        let artifacts = executor.execute_payload(payload).unwrap();
+        assert!(artifacts.receipts[0].is_success());
+        assert_eq!(artifacts.block_header.number, 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_bytecode_and_header_by_hash() {
+        let (asserter, provider) = mocked_provider();
+
+        // The prefixed code key is missing, so the unprefixed code hash is fetched.
+        let code = Bytes::from_static(&[0x60, 0x00]);
+        asserter.push_failure_msg("leveldb: not found");
+        asserter.push_success(&code);
+        assert_eq!(provider.bytecode_by_hash(keccak256(&code)).unwrap(), code);
+        assert_eq!(asserter.methods(), ["debug_dbGet", "debug_dbGet"]);
+
+        // Any other error fetching the prefixed code key is returned without a fallback.
+        asserter.push_failure_msg("rate limited");
+        assert!(provider.bytecode_by_hash(keccak256(&code)).is_err());
+        assert_eq!(asserter.methods().len(), 3);
+
+        let header = Header { number: 7, ..Default::default() };
+        let mut raw_header = Vec::new();
+        header.encode(&mut raw_header);
+        asserter.push_success(&Bytes::from(raw_header));
+        assert_eq!(provider.header_by_hash(header.hash_slow()).unwrap(), header);
+
+        asserter.push_failure_msg("header not found");
+        assert!(provider.header_by_hash(B256::ZERO).is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_preimage_mismatch() {
+        let (asserter, provider) = mocked_provider();
+
+        // A trie node that does not hash to the requested key is rejected.
+        let mut node = Vec::new();
+        TrieNode::Empty.encode(&mut node);
+        asserter.push_success(&Bytes::from(node.clone()));
+        assert!(provider.trie_node_by_hash(keccak256(&node)).is_ok());
+        asserter.push_success(&Bytes::from(node));
+        assert!(provider.trie_node_by_hash(B256::ZERO).is_err());
+
+        // Code served under either key must hash to the requested code hash.
+        let code = Bytes::from_static(&[0x60, 0x00]);
+        asserter.push_success(&code);
+        assert!(provider.bytecode_by_hash(B256::ZERO).is_err());
+        asserter.push_failure_msg("leveldb: not found");
+        asserter.push_success(&code);
+        assert!(provider.bytecode_by_hash(B256::ZERO).is_err());
+
+        // A header that does not hash to the requested block hash is rejected.
+        let mut raw_header = Vec::new();
+        Header::default().encode(&mut raw_header);
+        asserter.push_success(&Bytes::from(raw_header));
+        assert!(provider.header_by_hash(B256::ZERO).is_err());
+    }
+}
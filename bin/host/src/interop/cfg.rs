@@ -6,14 +6,18 @@ use crate::{
         cli_styles,
         parser::{parse_b256, parse_bytes},
     },
-    eth::http_provider,
-    DiskKeyValueStore, MemoryKeyValueStore, OfflineHostBackend, OnlineHostBackend,
-    OnlineHostBackendCfg, PreimageServer, SharedKeyValueStore, SplitKeyValueStore,
+    eth::{
+        blob_kzg_settings, http_provider, load_kzg_settings, FileBlobProvider, HostBlobProvider,
+    },
+    BoundedKeyValueStore, DiskKeyValueStore, MemoryKeyValueStore, OfflineHostBackend,
+    OnlineHostBackend, OnlineHostBackendCfg, PreimageServer, SharedKeyValueStore,
+    SplitKeyValueStore,
 };
 use alloy_primitives::{Bytes, B256};
 use alloy_provider::{Provider, RootProvider};
 use anyhow::{anyhow, Result};
-use clap::Parser;
+use clap::{ArgGroup, Parser};
+use kona_client::bounds::ProgramBounds;
 use kona_preimage::{
     BidirectionalChannel, Channel, HintReader, HintWriter, OracleReader, OracleServer,
 };
@@ -23,8 +27,9 @@ use kona_providers_alloy::{OnlineBeaconClient, OnlineBlobProvider};
 use kona_std_fpvm::{FileChannel, FileDescriptor};
 use maili_genesis::RollupConfig;
 use op_alloy_network::Optimism;
+use revm::primitives::kzg::EnvKzgSettings;
 use serde::Serialize;
-use std::{collections::HashMap, path::PathBuf, sync::Arc};
+use std::{collections::HashMap, path::PathBuf, sync::Arc, time::Duration};
 use tokio::{
     sync::RwLock,
     task::{self, JoinHandle},
@@ -33,6 +38,7 @@ use tokio::{
 /// The interop host application.
 #[derive(Default, Parser, Serialize, Clone, Debug)]
 #[command(styles = cli_styles())]
+#[command(group(ArgGroup::new("blob_source").args(["l1_beacon_address", "blob_dir"])))]
 pub struct InteropHost {
     /// Hash of the L1 head block, marking a static, trusted cutoff point for reading data from the
     /// L1 chain.
@@ -54,7 +60,7 @@ pub struct InteropHost {
         long,
         visible_alias = "l2s",
         requires = "l1_node_address",
-        requires = "l1_beacon_address",
+        requires = "blob_source",
         value_delimiter = ',',
         env
     )]
@@ -64,7 +70,7 @@ pub struct InteropHost {
         long,
         visible_alias = "l1",
         requires = "l2_node_address",
-        requires = "l1_beacon_address",
+        requires = "blob_source",
         env
     )]
     pub l1_node_address: Option<String>,
@@ -77,12 +83,20 @@ pub struct InteropHost {
         env
     )]
     pub l1_beacon_address: Option<String>,
+    /// Directory containing blobs to serve instead of fetching them from the L1 Beacon API. Each
+    /// blob is stored raw in a file named `<versioned_hash>.blob`.
+    #[clap(long, requires = "l1_node_address", requires = "l2_node_addresses", env)]
+    pub blob_dir: Option<PathBuf>,
+    /// Path to a KZG trusted setup file to use for the KZG point evaluation precompile, in place
+    /// of the Ethereum mainnet trusted setup.
+    #[clap(long, env)]
+    pub kzg_trusted_setup: Option<PathBuf>,
     /// The Data Directory for preimage data storage. Optional if running in online mode,
     /// required if running in offline mode.
     #[clap(
         long,
         visible_alias = "db",
-        required_unless_present_all = ["l2_node_addresses", "l1_node_address", "l1_beacon_address"],
+        required_unless_present_all = ["l2_node_addresses", "l1_node_address"],
         env
     )]
     pub data_dir: Option<PathBuf>,
@@ -97,6 +111,18 @@ pub struct InteropHost {
     /// look up the configs in the superchain registry.
     #[clap(long, alias = "rollup-cfgs", value_delimiter = ',', env)]
     pub rollup_config_paths: Option<Vec<PathBuf>>,
+    /// Timeout for each request made to the L1 and L2 execution layer nodes, in seconds. If not
+    /// provided, requests never time out.
+    #[clap(long, env)]
+    pub rpc_timeout: Option<u64>,
+    /// The maximum size of a single preimage fetched from the remote providers, in bytes. Larger
+    /// preimages are rejected before they are stored. If not provided, the size is unbounded.
+    #[clap(long, env)]
+    pub max_preimage_bytes: Option<usize>,
+    /// The number of preimages buffered in memory before they are written to the data directory
+    /// in a single batch. If not provided, each preimage is written as soon as it is fetched.
+    #[clap(long, requires = "data_dir", env)]
+    pub write_batch_size: Option<usize>,
 }
 
 impl InteropHost {
@@ -162,6 +188,7 @@ impl InteropHost {
             OracleReader::new(preimage.client),
             HintWriter::new(hint.client),
             None,
+            ProgramBounds::default(),
         ));
 
         let (_, client_result) = tokio::try_join!(server_task, client_task)?;
@@ -175,6 +202,7 @@ impl InteropHost {
         self.l1_node_address.is_none() &&
             self.l2_node_addresses.is_none() &&
             self.l1_beacon_address.is_none() &&
+            self.blob_dir.is_none() &&
             self.data_dir.is_some()
     }
 
@@ -206,39 +234,65 @@ impl InteropHost {
         let local_kv_store = InteropLocalInputs::new(self.clone());
 
         let kv_store: SharedKeyValueStore = if let Some(ref data_dir) = self.data_dir {
-            let disk_kv_store = DiskKeyValueStore::new(data_dir.clone());
-            let split_kv_store = SplitKeyValueStore::new(local_kv_store, disk_kv_store);
+            let mut disk_kv_store = DiskKeyValueStore::new(data_dir.clone());
+            if let Some(write_batch_size) = self.write_batch_size {
+                disk_kv_store = disk_kv_store.with_write_batch_size(write_batch_size);
+            }
+            let bounded_kv_store =
+                BoundedKeyValueStore::new(disk_kv_store, self.max_preimage_bytes);
+            let split_kv_store = SplitKeyValueStore::new(local_kv_store, bounded_kv_store);
             Arc::new(RwLock::new(split_kv_store))
         } else {
             let mem_kv_store = MemoryKeyValueStore::new();
-            let split_kv_store = SplitKeyValueStore::new(local_kv_store, mem_kv_store);
+            let bounded_kv_store = BoundedKeyValueStore::new(mem_kv_store, self.max_preimage_bytes);
+            let split_kv_store = SplitKeyValueStore::new(local_kv_store, bounded_kv_store);
             Arc::new(RwLock::new(split_kv_store))
         };
 
         Ok(kv_store)
     }
 
+    /// Returns the per-request timeout for the execution layer providers, if enabled.
+    fn rpc_timeout(&self) -> Option<Duration> {
+        self.rpc_timeout.map(Duration::from_secs)
+    }
+
     /// Creates the providers required for the preimage server backend.
     async fn create_providers(&self) -> Result<InteropProviders> {
-        let l1_provider =
-            http_provider(self.l1_node_address.as_ref().ok_or(anyhow!("Provider must be set"))?);
+        let l1_provider = http_provider(
+            self.l1_node_address.as_ref().ok_or(anyhow!("Provider must be set"))?,
+            self.rpc_timeout(),
+        )?;
 
-        let blob_provider = OnlineBlobProvider::init(OnlineBeaconClient::new_http(
-            self.l1_beacon_address.clone().ok_or(anyhow!("Beacon API URL must be set"))?,
-        ))
-        .await;
+        let kzg_settings = load_kzg_settings(self.kzg_trusted_setup.as_deref())?;
+        let blob_provider = if let Some(ref blob_dir) = self.blob_dir {
+            HostBlobProvider::File(FileBlobProvider::new(blob_dir.clone()))
+        } else {
+            HostBlobProvider::Online(
+                OnlineBlobProvider::init(OnlineBeaconClient::new_http(
+                    self.l1_beacon_address.clone().ok_or(anyhow!("Beacon API URL must be set"))?,
+                ))
+                .await
+                .with_kzg_settings(blob_kzg_settings(&kzg_settings)),
+            )
+        };
 
         // Resolve all chain IDs to their corresponding providers.
         let l2_node_addresses =
             self.l2_node_addresses.as_ref().ok_or(anyhow!("L2 node addresses must be set"))?;
         let mut l2_providers = HashMap::default();
         for l2_node_address in l2_node_addresses {
-            let l2_provider = http_provider::<Optimism>(l2_node_address);
+            let l2_provider = http_provider::<Optimism>(l2_node_address, self.rpc_timeout())?;
             let chain_id = l2_provider.get_chain_id().await?;
             l2_providers.insert(chain_id, l2_provider);
         }
 
-        Ok(InteropProviders { l1: l1_provider, blobs: blob_provider, l2s: l2_providers })
+        Ok(InteropProviders {
+            l1: l1_provider,
+            blobs: blob_provider,
+            l2s: l2_providers,
+            kzg_settings,
+        })
     }
 }
 
@@ -252,10 +306,12 @@ impl OnlineHostBackendCfg for InteropHost {
 pub struct InteropProviders {
     /// The L1 EL provider.
     pub l1: RootProvider,
-    /// The L1 beacon node provider.
-    pub blobs: OnlineBlobProvider<OnlineBeaconClient>,
+    /// The L1 blob provider.
+    pub blobs: HostBlobProvider,
     /// The L2 EL providers, keyed by chain ID.
     pub l2s: HashMap<u64, RootProvider<Optimism>>,
+    /// The KZG settings used by the KZG point evaluation precompile.
+    pub kzg_settings: EnvKzgSettings,
 }
 
 impl InteropProviders {
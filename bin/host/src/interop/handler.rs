@@ -2,19 +2,23 @@
 
 use super::InteropHost;
 use crate::{
-    backend::util::store_ordered_trie, HintHandler, OnlineHostBackendCfg, SharedKeyValueStore,
+    backend::util::{
+        encode_op_receipt, encode_receipt, fetch_raw_receipts, store_ancestor_headers, store_blob,
+        store_ordered_trie, store_output_root,
+    },
+    HintHandler, OnlineHostBackendCfg, SharedKeyValueStore,
 };
-use alloy_consensus::Header;
 use alloy_eips::{
     eip2718::Encodable2718,
     eip4844::{IndexedBlobHash, FIELD_ELEMENTS_PER_BLOB},
 };
-use alloy_primitives::{address, keccak256, Address, Bytes, B256};
+use alloy_primitives::{keccak256, Address, Bytes, B256};
 use alloy_provider::Provider;
 use alloy_rlp::Decodable;
 use alloy_rpc_types::{Block, BlockTransactionsKind};
 use anyhow::{anyhow, ensure, Result};
 use async_trait::async_trait;
+use kona_driver::block_for_timestamp;
 use kona_preimage::{PreimageKey, PreimageKeyType};
 use kona_proof_interop::{HintType, PreState};
 use maili_protocol::BlockInfo;
@@ -65,8 +69,7 @@ impl HintHandler for InteropHintHandler {
                 ensure!(hint.data.len() == 32, "Invalid hint data length");
 
                 let hash: B256 = hint.data.as_ref().try_into()?;
-                let raw_receipts: Vec<Bytes> =
-                    providers.l1.client().request("debug_getRawReceipts", [hash]).await?;
+                let raw_receipts = fetch_raw_receipts(&providers.l1, hash, encode_receipt).await?;
 
                 store_ordered_trie(kv.as_ref(), raw_receipts.as_slice()).await?;
             }
@@ -95,41 +98,7 @@ impl HintHandler for InteropHintHandler {
                 }
                 let sidecar = sidecars.remove(0);
 
-                // Acquire a lock on the key-value store and set the preimages.
-                let mut kv_lock = kv.write().await;
-
-                // Set the preimage for the blob commitment.
-                kv_lock.set(
-                    PreimageKey::new(*hash, PreimageKeyType::Sha256).into(),
-                    sidecar.kzg_commitment.to_vec(),
-                )?;
-
-                // Write all the field elements to the key-value store. There should be 4096.
-                // The preimage oracle key for each field element is the keccak256 hash of
-                // `abi.encodePacked(sidecar.KZGCommitment, uint256(i))`
-                let mut blob_key = [0u8; 80];
-                blob_key[..48].copy_from_slice(sidecar.kzg_commitment.as_ref());
-                for i in 0..FIELD_ELEMENTS_PER_BLOB {
-                    blob_key[72..].copy_from_slice(i.to_be_bytes().as_ref());
-                    let blob_key_hash = keccak256(blob_key.as_ref());
-
-                    kv_lock
-                        .set(PreimageKey::new_keccak256(*blob_key_hash).into(), blob_key.into())?;
-                    kv_lock.set(
-                        PreimageKey::new(*blob_key_hash, PreimageKeyType::Blob).into(),
-                        sidecar.blob[(i as usize) << 5..(i as usize + 1) << 5].to_vec(),
-                    )?;
-                }
-
-                // Write the KZG Proof as the 4096th element.
-                blob_key[72..].copy_from_slice((FIELD_ELEMENTS_PER_BLOB).to_be_bytes().as_ref());
-                let blob_key_hash = keccak256(blob_key.as_ref());
-
-                kv_lock.set(PreimageKey::new_keccak256(*blob_key_hash).into(), blob_key.into())?;
-                kv_lock.set(
-                    PreimageKey::new(*blob_key_hash, PreimageKeyType::Blob).into(),
-                    sidecar.kzg_proof.to_vec(),
-                )?;
+                store_blob(kv.as_ref(), hash, &sidecar, FIELD_ELEMENTS_PER_BLOB).await?;
             }
             HintType::L1Precompile => {
                 ensure!(hint.data.len() >= 20, "Invalid hint data length");
@@ -138,15 +107,16 @@ impl HintHandler for InteropHintHandler {
                 let input = hint.data[20..].to_vec();
                 let input_hash = keccak256(hint.data.as_ref());
 
-                let result = crate::eth::execute(address, input).map_or_else(
-                    |_| vec![0u8; 1],
-                    |raw_res| {
-                        let mut res = Vec::with_capacity(1 + raw_res.len());
-                        res.push(0x01);
-                        res.extend_from_slice(&raw_res);
-                        res
-                    },
-                );
+                let result = crate::eth::execute(address, input, &providers.kzg_settings)
+                    .map_or_else(
+                        |_| vec![0u8; 1],
+                        |raw_res| {
+                            let mut res = Vec::with_capacity(1 + raw_res.len());
+                            res.push(0x01);
+                            res.extend_from_slice(&raw_res);
+                            res
+                        },
+                    );
 
                 let mut kv_lock = kv.write().await;
                 kv_lock.set(PreimageKey::new_keccak256(*input_hash).into(), hint.data.into())?;
@@ -171,10 +141,6 @@ impl HintHandler for InteropHintHandler {
                 )?;
             }
             HintType::L2OutputRoot => {
-                const OUTPUT_ROOT_VERSION: u8 = 0;
-                const L2_TO_L1_MESSAGE_PASSER_ADDRESS: Address =
-                    address!("4200000000000000000000000000000000000016");
-
                 ensure!(hint.data.len() >= 32 && hint.data.len() <= 40, "Invalid hint data length");
 
                 let hash = B256::from_slice(&hint.data.as_ref()[0..32]);
@@ -200,37 +166,11 @@ impl HintHandler for InteropHintHandler {
                         local_cfgs.get(&chain_id).cloned()
                     })
                     .ok_or(anyhow!("No rollup config found for chain ID: {chain_id}"))?;
-                let block_number =
-                    (timestamp - rollup_config.genesis.l2_time) / rollup_config.block_time;
-
-                // Fetch the header for the L2 head block.
-                let raw_header: Bytes = l2_provider
-                    .client()
-                    .request("debug_getRawHeader", &[format!("0x{block_number:x}")])
-                    .await
-                    .map_err(|e| anyhow!("Failed to fetch header RLP: {e}"))?;
-                let header = Header::decode(&mut raw_header.as_ref())?;
-
-                // Fetch the storage root for the L2 head block.
-                let l2_to_l1_message_passer = l2_provider
-                    .get_proof(L2_TO_L1_MESSAGE_PASSER_ADDRESS, Default::default())
-                    .block_id(block_number.into())
-                    .await?;
-
-                let mut raw_output = [0u8; 128];
-                raw_output[31] = OUTPUT_ROOT_VERSION;
-                raw_output[32..64].copy_from_slice(header.state_root.as_ref());
-                raw_output[64..96].copy_from_slice(l2_to_l1_message_passer.storage_hash.as_ref());
-                raw_output[96..128].copy_from_slice(header.hash_slow().as_ref());
-                let output_root = keccak256(raw_output);
-
-                ensure!(
-                    output_root == hash,
-                    "Output root does not match L2 head. Expected: {hash}, got: {output_root}"
-                );
+                let block_number = block_for_timestamp(&rollup_config, timestamp).ok_or(
+                    anyhow!("No L2 block at timestamp {timestamp} for chain ID: {chain_id}"),
+                )?;
 
-                let mut kv_lock = kv.write().await;
-                kv_lock.set(PreimageKey::new_keccak256(*output_root).into(), raw_output.into())?;
+                store_output_root(kv.as_ref(), l2_provider, block_number, hash).await?;
             }
             HintType::L2BlockHeader => {
                 ensure!(hint.data.len() == 40, "Invalid hint data length");
@@ -268,11 +208,8 @@ impl HintHandler for InteropHintHandler {
                 let hash: B256 = hint.data.as_ref()[..32].try_into()?;
                 let chain_id = u64::from_be_bytes(hint.data[32..40].try_into()?);
 
-                let raw_receipts: Vec<Bytes> = providers
-                    .l2(&chain_id)?
-                    .client()
-                    .request("debug_getRawReceipts", [hash])
-                    .await?;
+                let raw_receipts =
+                    fetch_raw_receipts(providers.l2(&chain_id)?, hash, encode_op_receipt).await?;
 
                 store_ordered_trie(kv.as_ref(), raw_receipts.as_slice()).await?;
             }
@@ -378,6 +315,16 @@ impl HintHandler for InteropHintHandler {
             HintType::L2BlockData => {
                 unimplemented!("L2BlockData hint type is not yet implemented");
             }
+            HintType::L2AncestorHeaders => {
+                ensure!(hint.data.len() == 48, "Invalid hint data length");
+
+                let hash = B256::from_slice(&hint.data.as_ref()[..32]);
+                let block_number = u64::from_be_bytes(hint.data[32..40].try_into()?);
+                let chain_id = u64::from_be_bytes(hint.data[40..48].try_into()?);
+                let l2_provider = providers.l2(&chain_id)?;
+
+                store_ancestor_headers(kv.as_ref(), l2_provider, hash, block_number).await?;
+            }
         }
 
         Ok(())
@@ -45,4 +45,17 @@ impl KeyValueStore for InteropLocalInputs {
     fn set(&mut self, _: B256, _: Vec<u8>) -> Result<()> {
         unreachable!("LocalKeyValueStore is read-only")
     }
+
+    fn keys(&self) -> Vec<B256> {
+        [
+            L1_HEAD_KEY,
+            L2_AGREED_PRE_STATE_KEY,
+            L2_CLAIMED_POST_STATE_KEY,
+            L2_CLAIMED_TIMESTAMP_KEY,
+            L2_ROLLUP_CONFIG_KEY,
+        ]
+        .into_iter()
+        .map(|key| PreimageKey::new_local(key.to()).into())
+        .collect()
+    }
 }
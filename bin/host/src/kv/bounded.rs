@@ -0,0 +1,83 @@
+//! Contains a concrete implementation of the [KeyValueStore] trait that bounds the size of the
+//! values stored in an inner [KeyValueStore].
+
+use super::KeyValueStore;
+use alloy_primitives::B256;
+use anyhow::{bail, Result};
+
+/// A [KeyValueStore] that rejects values larger than a configured maximum before they are written
+/// to the inner [KeyValueStore].
+#[derive(Clone, Debug)]
+pub struct BoundedKeyValueStore<KV>
+where
+    KV: KeyValueStore,
+{
+    inner: KV,
+    max_value_bytes: Option<usize>,
+}
+
+impl<KV> BoundedKeyValueStore<KV>
+where
+    KV: KeyValueStore,
+{
+    /// Create a new [BoundedKeyValueStore] wrapping the given [KeyValueStore]. If
+    /// `max_value_bytes` is [None], values of any size are accepted.
+    pub const fn new(inner: KV, max_value_bytes: Option<usize>) -> Self {
+        Self { inner, max_value_bytes }
+    }
+}
+
+impl<KV> KeyValueStore for BoundedKeyValueStore<KV>
+where
+    KV: KeyValueStore,
+{
+    fn get(&self, key: B256) -> Option<Vec<u8>> {
+        self.inner.get(key)
+    }
+
+    fn set(&mut self, key: B256, value: Vec<u8>) -> Result<()> {
+        if let Some(max_value_bytes) = self.max_value_bytes {
+            if value.len() > max_value_bytes {
+                bail!(
+                    "Preimage for key {key} is {} bytes, exceeding the maximum of {} bytes",
+                    value.len(),
+                    max_value_bytes
+                );
+            }
+        }
+        self.inner.set(key, value)
+    }
+
+    fn keys(&self) -> Vec<B256> {
+        self.inner.keys()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::MemoryKeyValueStore;
+
+    #[test]
+    fn test_rejects_oversized_value() {
+        let mut kv = BoundedKeyValueStore::new(MemoryKeyValueStore::new(), Some(4));
+        let key = B256::with_last_byte(1);
+
+        let err = kv.set(key, vec![0xFF; 5]).unwrap_err();
+        assert!(err.to_string().contains(&key.to_string()));
+        assert!(kv.get(key).is_none());
+        assert!(kv.inner.store.is_empty());
+
+        kv.set(key, vec![0xFF; 4]).unwrap();
+        assert_eq!(kv.get(key), Some(vec![0xFF; 4]));
+    }
+
+    #[test]
+    fn test_unbounded() {
+        let mut kv = BoundedKeyValueStore::new(MemoryKeyValueStore::new(), None);
+        let key = B256::with_last_byte(1);
+
+        kv.set(key, vec![0xFF; 1 << 16]).unwrap();
+        assert_eq!(kv.get(key).map(|v| v.len()), Some(1 << 16));
+    }
+}
@@ -4,14 +4,23 @@
 use super::{KeyValueStore, MemoryKeyValueStore};
 use alloy_primitives::B256;
 use anyhow::{anyhow, Result};
-use rocksdb::{Options, DB};
-use std::path::PathBuf;
+use rocksdb::{Options, WriteBatch, DB};
+use std::{collections::HashMap, path::PathBuf};
 
 /// A simple, synchronous key-value store that stores data on disk.
+///
+/// By default, each value is written to disk as soon as it is set. With
+/// [DiskKeyValueStore::with_write_batch_size], writes are buffered in memory and written to disk
+/// in a single batch once the buffer is full, or when [DiskKeyValueStore::flush] is called.
+/// Buffered values are readable before they are flushed.
 #[derive(Debug)]
 pub struct DiskKeyValueStore {
     data_directory: PathBuf,
     db: DB,
+    /// The writes buffered since the last flush.
+    pending: HashMap<B256, Vec<u8>>,
+    /// The number of buffered writes that triggers a flush. Writes are not buffered if [None].
+    write_batch_size: Option<usize>,
 }
 
 impl DiskKeyValueStore {
@@ -20,7 +29,27 @@ impl DiskKeyValueStore {
         let db = DB::open(&Self::get_db_options(), data_directory.as_path())
             .unwrap_or_else(|e| panic!("Failed to open database at {data_directory:?}: {e}"));
 
-        Self { data_directory, db }
+        Self { data_directory, db, pending: HashMap::new(), write_batch_size: None }
+    }
+
+    /// Buffers writes in memory, flushing them to disk in a single batch once `write_batch_size`
+    /// writes are pending. Defaults to writing each value immediately.
+    pub fn with_write_batch_size(mut self, write_batch_size: usize) -> Self {
+        self.write_batch_size = Some(write_batch_size);
+        self
+    }
+
+    /// Writes all buffered values to disk.
+    pub fn flush(&mut self) -> Result<()> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+
+        let mut batch = WriteBatch::default();
+        self.pending.iter().for_each(|(key, value)| batch.put(key, value));
+        self.db.write(batch).map_err(|e| anyhow!("Failed to write batch: {}", e))?;
+        self.pending.clear();
+        Ok(())
     }
 
     /// Gets the [Options] for the underlying RocksDB instance.
@@ -34,16 +63,42 @@ impl DiskKeyValueStore {
 
 impl KeyValueStore for DiskKeyValueStore {
     fn get(&self, key: alloy_primitives::B256) -> Option<Vec<u8>> {
+        if let Some(value) = self.pending.get(&key) {
+            return Some(value.clone());
+        }
         self.db.get(*key).ok()?
     }
 
     fn set(&mut self, key: alloy_primitives::B256, value: Vec<u8>) -> Result<()> {
-        self.db.put(*key, value).map_err(|e| anyhow!("Failed to set key-value pair: {}", e))
+        let Some(write_batch_size) = self.write_batch_size else {
+            return self
+                .db
+                .put(*key, value)
+                .map_err(|e| anyhow!("Failed to set key-value pair: {}", e));
+        };
+
+        self.pending.insert(key, value);
+        if self.pending.len() >= write_batch_size {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    fn keys(&self) -> Vec<B256> {
+        let mut keys = self
+            .db
+            .iterator(rocksdb::IteratorMode::Start)
+            .filter_map(|entry| B256::try_from(entry.ok()?.0.as_ref()).ok())
+            .filter(|key| !self.pending.contains_key(key))
+            .collect::<Vec<_>>();
+        keys.extend(self.pending.keys().copied());
+        keys
     }
 }
 
 impl Drop for DiskKeyValueStore {
     fn drop(&mut self) {
+        let _ = self.flush();
         let _ = DB::destroy(&Self::get_db_options(), self.data_directory.as_path());
     }
 }
@@ -51,7 +106,8 @@ impl Drop for DiskKeyValueStore {
 impl TryFrom<DiskKeyValueStore> for MemoryKeyValueStore {
     type Error = anyhow::Error;
 
-    fn try_from(disk_store: DiskKeyValueStore) -> Result<Self> {
+    fn try_from(mut disk_store: DiskKeyValueStore) -> Result<Self> {
+        disk_store.flush()?;
         let mut memory_store = Self::new();
         let mut db_iter = disk_store.db.full_iterator(rocksdb::IteratorMode::Start);
 
@@ -71,13 +127,15 @@ impl TryFrom<DiskKeyValueStore> for MemoryKeyValueStore {
 mod test {
     use super::DiskKeyValueStore;
     use crate::kv::{KeyValueStore, MemoryKeyValueStore};
+    use alloy_primitives::B256;
     use proptest::{
         arbitrary::any,
         collection::{hash_map, vec},
         proptest,
         test_runner::Config,
     };
-    use std::env::temp_dir;
+    use std::collections::HashSet;
+    use tempfile::tempdir;
 
     proptest! {
         #![proptest_config(Config::with_cases(16))]
@@ -85,8 +143,8 @@ mod test {
         /// Test that converting from a [DiskKeyValueStore] to a [MemoryKeyValueStore] is lossless.
         #[test]
         fn convert_disk_kv_to_mem_kv(k_v in hash_map(any::<[u8; 32]>(), vec(any::<u8>(), 0..128), 1..128)) {
-            let tempdir = temp_dir();
-            let mut disk_kv = DiskKeyValueStore::new(tempdir);
+            let tempdir = tempdir().unwrap();
+            let mut disk_kv = DiskKeyValueStore::new(tempdir.path().to_path_buf());
             k_v.iter().for_each(|(k, v)| {
                 disk_kv.set(k.into(), v.to_vec()).unwrap();
             });
@@ -96,5 +154,50 @@ mod test {
                 assert_eq!(mem_kv.get(k.into()).unwrap(), v.to_vec());
             }
         }
+
+        /// Test that the keys listed by a [DiskKeyValueStore] match the inserted keys.
+        #[test]
+        fn disk_kv_keys(keys in hash_map(any::<[u8; 32]>(), any::<u8>(), 1..128)) {
+            let tempdir = tempdir().unwrap();
+            let mut disk_kv = DiskKeyValueStore::new(tempdir.path().to_path_buf());
+            keys.iter().for_each(|(k, v)| {
+                disk_kv.set(k.into(), vec![*v]).unwrap();
+            });
+
+            let listed = disk_kv.keys().into_iter().collect::<HashSet<_>>();
+            let expected = keys.keys().map(B256::from).collect::<HashSet<_>>();
+            assert_eq!(listed, expected);
+        }
+    }
+
+    #[test]
+    fn test_buffered_writes() {
+        let tempdir = tempdir().unwrap();
+        let mut disk_kv =
+            DiskKeyValueStore::new(tempdir.path().to_path_buf()).with_write_batch_size(3);
+        let (a, b, c) = (B256::with_last_byte(1), B256::with_last_byte(2), B256::with_last_byte(3));
+
+        // Buffered writes are readable, but not yet on disk.
+        disk_kv.set(a, vec![1]).unwrap();
+        disk_kv.set(b, vec![2]).unwrap();
+        assert_eq!(disk_kv.get(a), Some(vec![1]));
+        assert_eq!(disk_kv.keys().into_iter().collect::<HashSet<_>>(), HashSet::from([a, b]));
+        assert!(disk_kv.db.get(a).unwrap().is_none());
+
+        // An explicit flush writes the buffered values to disk.
+        disk_kv.flush().unwrap();
+        assert!(disk_kv.pending.is_empty());
+        assert_eq!(disk_kv.db.get(a).unwrap(), Some(vec![1]));
+        assert_eq!(disk_kv.db.get(b).unwrap(), Some(vec![2]));
+
+        // Reaching the batch size flushes the buffered values.
+        disk_kv.set(c, vec![3]).unwrap();
+        disk_kv.set(a, vec![4]).unwrap();
+        assert!(disk_kv.db.get(c).unwrap().is_none());
+        disk_kv.set(b, vec![5]).unwrap();
+        assert!(disk_kv.pending.is_empty());
+        assert_eq!(disk_kv.db.get(c).unwrap(), Some(vec![3]));
+        assert_eq!(disk_kv.get(a), Some(vec![4]));
+        assert_eq!(disk_kv.get(b), Some(vec![5]));
     }
 }
@@ -29,4 +29,25 @@ impl KeyValueStore for MemoryKeyValueStore {
         self.store.insert(key, value);
         Ok(())
     }
+
+    fn keys(&self) -> Vec<B256> {
+        self.store.keys().copied().collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_keys() {
+        let mut kv = MemoryKeyValueStore::new();
+        assert!(kv.keys().is_empty());
+
+        let inserted = (0..8u8).map(B256::with_last_byte).collect::<HashSet<_>>();
+        inserted.iter().for_each(|k| kv.set(*k, k.to_vec()).unwrap());
+
+        assert_eq!(kv.keys().into_iter().collect::<HashSet<_>>(), inserted);
+    }
 }
@@ -14,6 +14,9 @@ pub use disk::DiskKeyValueStore;
 mod split;
 pub use split::SplitKeyValueStore;
 
+mod bounded;
+pub use bounded::BoundedKeyValueStore;
+
 /// A type alias for a shared key-value store.
 pub type SharedKeyValueStore = Arc<RwLock<dyn KeyValueStore + Send + Sync>>;
 
@@ -24,4 +27,7 @@ pub trait KeyValueStore {
 
     /// Set the value associated with the given key.
     fn set(&mut self, key: B256, value: Vec<u8>) -> Result<()>;
+
+    /// Returns all keys present in the store, in no particular order.
+    fn keys(&self) -> Vec<B256>;
 }
@@ -44,4 +44,10 @@ where
     fn set(&mut self, key: B256, value: Vec<u8>) -> Result<()> {
         self.remote_store.set(key, value)
     }
+
+    fn keys(&self) -> Vec<B256> {
+        let mut keys = self.local_store.keys();
+        keys.extend(self.remote_store.keys());
+        keys
+    }
 }
@@ -6,7 +6,8 @@ pub use server::PreimageServer;
 
 mod kv;
 pub use kv::{
-    DiskKeyValueStore, KeyValueStore, MemoryKeyValueStore, SharedKeyValueStore, SplitKeyValueStore,
+    BoundedKeyValueStore, DiskKeyValueStore, KeyValueStore, MemoryKeyValueStore,
+    SharedKeyValueStore, SplitKeyValueStore,
 };
 
 mod backend;
@@ -3,14 +3,18 @@
 use super::{SingleChainHintHandler, SingleChainLocalInputs};
 use crate::{
     cli::{cli_styles, parser::parse_b256},
-    eth::http_provider,
-    DiskKeyValueStore, MemoryKeyValueStore, OfflineHostBackend, OnlineHostBackend,
-    OnlineHostBackendCfg, PreimageServer, SharedKeyValueStore, SplitKeyValueStore,
+    eth::{
+        blob_kzg_settings, http_provider, load_kzg_settings, FileBlobProvider, HostBlobProvider,
+    },
+    BoundedKeyValueStore, DiskKeyValueStore, MemoryKeyValueStore, OfflineHostBackend,
+    OnlineHostBackend, OnlineHostBackendCfg, PreimageServer, SharedKeyValueStore,
+    SplitKeyValueStore,
 };
 use alloy_primitives::B256;
 use alloy_provider::RootProvider;
 use anyhow::{anyhow, Result};
-use clap::Parser;
+use clap::{ArgGroup, Parser};
+use kona_client::bounds::ProgramBounds;
 use kona_preimage::{
     BidirectionalChannel, Channel, HintReader, HintWriter, OracleReader, OracleServer,
 };
@@ -19,8 +23,13 @@ use kona_providers_alloy::{OnlineBeaconClient, OnlineBlobProvider};
 use kona_std_fpvm::{FileChannel, FileDescriptor};
 use maili_genesis::RollupConfig;
 use op_alloy_network::Optimism;
+use revm::primitives::kzg::EnvKzgSettings;
 use serde::Serialize;
-use std::{path::PathBuf, sync::Arc};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
 use tokio::{
     sync::RwLock,
     task::{self, JoinHandle},
@@ -29,6 +38,7 @@ use tokio::{
 /// The host binary CLI application arguments.
 #[derive(Default, Parser, Serialize, Clone, Debug)]
 #[command(styles = cli_styles())]
+#[command(group(ArgGroup::new("blob_source").args(["l1_beacon_address", "blob_dir"])))]
 pub struct SingleChainHost {
     /// Hash of the L1 head block. Derivation stops after this block is processed.
     #[clap(long, value_parser = parse_b256, env)]
@@ -50,7 +60,7 @@ pub struct SingleChainHost {
         long,
         visible_alias = "l2",
         requires = "l1_node_address",
-        requires = "l1_beacon_address",
+        requires = "blob_source",
         env
     )]
     pub l2_node_address: Option<String>,
@@ -59,7 +69,7 @@ pub struct SingleChainHost {
         long,
         visible_alias = "l1",
         requires = "l2_node_address",
-        requires = "l1_beacon_address",
+        requires = "blob_source",
         env
     )]
     pub l1_node_address: Option<String>,
@@ -72,22 +82,50 @@ pub struct SingleChainHost {
         env
     )]
     pub l1_beacon_address: Option<String>,
+    /// Directory containing blobs to serve instead of fetching them from the L1 Beacon API. Each
+    /// blob is stored raw in a file named `<versioned_hash>.blob`.
+    #[clap(long, requires = "l1_node_address", requires = "l2_node_address", env)]
+    pub blob_dir: Option<PathBuf>,
+    /// Path to a KZG trusted setup file to use for the KZG point evaluation precompile, in place
+    /// of the Ethereum mainnet trusted setup.
+    #[clap(long, env)]
+    pub kzg_trusted_setup: Option<PathBuf>,
     /// The Data Directory for preimage data storage. Optional if running in online mode,
     /// required if running in offline mode.
     #[clap(
         long,
         visible_alias = "db",
-        required_unless_present_all = ["l2_node_address", "l1_node_address", "l1_beacon_address"],
+        required_unless_present_all = ["l2_node_address", "l1_node_address"],
         env
     )]
     pub data_dir: Option<PathBuf>,
     /// Run the client program natively.
-    #[clap(long, conflicts_with = "server", required_unless_present = "server")]
+    #[clap(
+        long,
+        conflicts_with = "server",
+        required_unless_present_any = ["server", "replay_hints"]
+    )]
     pub native: bool,
     /// Run in pre-image server mode without executing any client program. If not provided, the
     /// host will run the client program in the host process.
-    #[clap(long, conflicts_with = "native", required_unless_present = "native")]
+    #[clap(
+        long,
+        conflicts_with = "native",
+        required_unless_present_any = ["native", "replay_hints"]
+    )]
     pub server: bool,
+    /// Path to a file of newline-delimited hints to replay against the online backend, populating
+    /// the data directory for a later offline run. The host exits once all hints are replayed,
+    /// without serving preimages or running the client program.
+    #[clap(
+        long,
+        conflicts_with_all = ["native", "server"],
+        requires = "l1_node_address",
+        requires = "l2_node_address",
+        requires = "data_dir",
+        env
+    )]
+    pub replay_hints: Option<PathBuf>,
     /// The L2 chain ID of a supported chain. If provided, the host will look for the corresponding
     /// rollup config in the superchain registry.
     #[clap(
@@ -107,11 +145,27 @@ pub struct SingleChainHost {
         env
     )]
     pub rollup_config_path: Option<PathBuf>,
+    /// Timeout for each request made to the L1 and L2 execution layer nodes, in seconds. If not
+    /// provided, requests never time out.
+    #[clap(long, env)]
+    pub rpc_timeout: Option<u64>,
+    /// The maximum size of a single preimage fetched from the remote providers, in bytes. Larger
+    /// preimages are rejected before they are stored. If not provided, the size is unbounded.
+    #[clap(long, env)]
+    pub max_preimage_bytes: Option<usize>,
+    /// The number of preimages buffered in memory before they are written to the data directory
+    /// in a single batch. If not provided, each preimage is written as soon as it is fetched.
+    #[clap(long, requires = "data_dir", env)]
+    pub write_batch_size: Option<usize>,
 }
 
 impl SingleChainHost {
     /// Starts the [SingleChainHost] application.
     pub async fn start(self) -> Result<()> {
+        if let Some(ref path) = self.replay_hints {
+            return self.start_replay(path).await;
+        }
+
         if self.server {
             let hint = FileChannel::new(FileDescriptor::HintRead, FileDescriptor::HintWrite);
             let preimage =
@@ -161,6 +215,17 @@ impl SingleChainHost {
         Ok(task_handle)
     }
 
+    /// Replays the hints in the file at the given path against the online backend, populating the
+    /// key-value store.
+    async fn start_replay(&self, path: &Path) -> Result<()> {
+        let kv_store = self.create_key_value_store()?;
+        let providers = self.create_providers().await?;
+        let backend =
+            OnlineHostBackend::new(self.clone(), kv_store, providers, SingleChainHintHandler);
+        backend.replay_hints_file(path).await?;
+        Ok(())
+    }
+
     /// Starts the host in native mode, running both the client and preimage server in the same
     /// process.
     async fn start_native(&self) -> Result<()> {
@@ -172,6 +237,7 @@ impl SingleChainHost {
             OracleReader::new(preimage.client),
             HintWriter::new(hint.client),
             None,
+            ProgramBounds::default(),
         ));
 
         let (_, client_result) = tokio::try_join!(server_task, client_task)?;
@@ -185,6 +251,7 @@ impl SingleChainHost {
         self.l1_node_address.is_none() &&
             self.l2_node_address.is_none() &&
             self.l1_beacon_address.is_none() &&
+            self.blob_dir.is_none() &&
             self.data_dir.is_some()
     }
 
@@ -210,31 +277,58 @@ impl SingleChainHost {
         let local_kv_store = SingleChainLocalInputs::new(self.clone());
 
         let kv_store: SharedKeyValueStore = if let Some(ref data_dir) = self.data_dir {
-            let disk_kv_store = DiskKeyValueStore::new(data_dir.clone());
-            let split_kv_store = SplitKeyValueStore::new(local_kv_store, disk_kv_store);
+            let mut disk_kv_store = DiskKeyValueStore::new(data_dir.clone());
+            if let Some(write_batch_size) = self.write_batch_size {
+                disk_kv_store = disk_kv_store.with_write_batch_size(write_batch_size);
+            }
+            let bounded_kv_store =
+                BoundedKeyValueStore::new(disk_kv_store, self.max_preimage_bytes);
+            let split_kv_store = SplitKeyValueStore::new(local_kv_store, bounded_kv_store);
             Arc::new(RwLock::new(split_kv_store))
         } else {
             let mem_kv_store = MemoryKeyValueStore::new();
-            let split_kv_store = SplitKeyValueStore::new(local_kv_store, mem_kv_store);
+            let bounded_kv_store = BoundedKeyValueStore::new(mem_kv_store, self.max_preimage_bytes);
+            let split_kv_store = SplitKeyValueStore::new(local_kv_store, bounded_kv_store);
             Arc::new(RwLock::new(split_kv_store))
         };
 
         Ok(kv_store)
     }
 
+    /// Returns the per-request timeout for the execution layer providers, if enabled.
+    fn rpc_timeout(&self) -> Option<Duration> {
+        self.rpc_timeout.map(Duration::from_secs)
+    }
+
     /// Creates the providers required for the host backend.
     async fn create_providers(&self) -> Result<SingleChainProviders> {
-        let l1_provider =
-            http_provider(self.l1_node_address.as_ref().ok_or(anyhow!("Provider must be set"))?);
-        let blob_provider = OnlineBlobProvider::init(OnlineBeaconClient::new_http(
-            self.l1_beacon_address.clone().ok_or(anyhow!("Beacon API URL must be set"))?,
-        ))
-        .await;
+        let l1_provider = http_provider(
+            self.l1_node_address.as_ref().ok_or(anyhow!("Provider must be set"))?,
+            self.rpc_timeout(),
+        )?;
+        let kzg_settings = load_kzg_settings(self.kzg_trusted_setup.as_deref())?;
+        let blob_provider = if let Some(ref blob_dir) = self.blob_dir {
+            HostBlobProvider::File(FileBlobProvider::new(blob_dir.clone()))
+        } else {
+            HostBlobProvider::Online(
+                OnlineBlobProvider::init(OnlineBeaconClient::new_http(
+                    self.l1_beacon_address.clone().ok_or(anyhow!("Beacon API URL must be set"))?,
+                ))
+                .await
+                .with_kzg_settings(blob_kzg_settings(&kzg_settings)),
+            )
+        };
         let l2_provider = http_provider::<Optimism>(
             self.l2_node_address.as_ref().ok_or(anyhow!("L2 node address must be set"))?,
-        );
+            self.rpc_timeout(),
+        )?;
 
-        Ok(SingleChainProviders { l1: l1_provider, blobs: blob_provider, l2: l2_provider })
+        Ok(SingleChainProviders {
+            l1: l1_provider,
+            blobs: blob_provider,
+            l2: l2_provider,
+            kzg_settings,
+        })
     }
 }
 
@@ -248,10 +342,12 @@ impl OnlineHostBackendCfg for SingleChainHost {
 pub struct SingleChainProviders {
     /// The L1 EL provider.
     pub l1: RootProvider,
-    /// The L1 beacon node provider.
-    pub blobs: OnlineBlobProvider<OnlineBeaconClient>,
+    /// The L1 blob provider.
+    pub blobs: HostBlobProvider,
     /// The L2 EL provider.
     pub l2: RootProvider<Optimism>,
+    /// The KZG settings used by the KZG point evaluation precompile.
+    pub kzg_settings: EnvKzgSettings,
 }
 
 #[cfg(test)]
@@ -298,8 +394,91 @@ mod test {
                 .as_slice(),
                 true,
             ),
+            (
+                [
+                    "--l1-node-address",
+                    "dummy",
+                    "--l2-node-address",
+                    "dummy",
+                    "--blob-dir",
+                    "dummy",
+                    "--server",
+                    "--l2-chain-id",
+                    "0",
+                ]
+                .as_slice(),
+                true,
+            ),
+            (
+                [
+                    "--l1-node-address",
+                    "dummy",
+                    "--l2-node-address",
+                    "dummy",
+                    "--l1-beacon-address",
+                    "dummy",
+                    "--data-dir",
+                    "dummy",
+                    "--replay-hints",
+                    "dummy",
+                    "--l2-chain-id",
+                    "0",
+                ]
+                .as_slice(),
+                true,
+            ),
+            (
+                [
+                    "--server",
+                    "--l2-chain-id",
+                    "0",
+                    "--data-dir",
+                    "dummy",
+                    "--write-batch-size",
+                    "64",
+                ]
+                .as_slice(),
+                true,
+            ),
             // invalid
             (["--server", "--native", "--l2-chain-id", "0"].as_slice(), false),
+            (
+                [
+                    "--l1-node-address",
+                    "dummy",
+                    "--l2-node-address",
+                    "dummy",
+                    "--l1-beacon-address",
+                    "dummy",
+                    "--server",
+                    "--l2-chain-id",
+                    "0",
+                    "--write-batch-size",
+                    "64",
+                ]
+                .as_slice(),
+                false,
+            ),
+            (
+                [
+                    "--l1-node-address",
+                    "dummy",
+                    "--l2-node-address",
+                    "dummy",
+                    "--l1-beacon-address",
+                    "dummy",
+                    "--replay-hints",
+                    "dummy",
+                    "--l2-chain-id",
+                    "0",
+                ]
+                .as_slice(),
+                false,
+            ),
+            (
+                ["--replay-hints", "dummy", "--data-dir", "dummy", "--l2-chain-id", "0"].as_slice(),
+                false,
+            ),
             (["--l2-chain-id", "0", "--rollup-config-path", "dummy", "--server"].as_slice(), false),
             (["--server"].as_slice(), false),
             (["--native"].as_slice(), false),
@@ -308,6 +487,24 @@ mod test {
             (["--l1-node-address", "dummy", "--server", "--l2-chain-id", "0"].as_slice(), false),
             (["--l2-node-address", "dummy", "--server", "--l2-chain-id", "0"].as_slice(), false),
             (["--l1-beacon-address", "dummy", "--server", "--l2-chain-id", "0"].as_slice(), false),
+            (["--blob-dir", "dummy", "--server", "--l2-chain-id", "0"].as_slice(), false),
+            (
+                [
+                    "--l1-node-address",
+                    "dummy",
+                    "--l2-node-address",
+                    "dummy",
+                    "--l1-beacon-address",
+                    "dummy",
+                    "--blob-dir",
+                    "dummy",
+                    "--server",
+                    "--l2-chain-id",
+                    "0",
+                ]
+                .as_slice(),
+                false,
+            ),
             ([].as_slice(), false),
         ];
 
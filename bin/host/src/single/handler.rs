@@ -1,7 +1,11 @@
 //! [HintHandler] for the [SingleChainHost].
 
 use crate::{
-    backend::util::store_ordered_trie, kv::SharedKeyValueStore, single::cfg::SingleChainHost,
+    backend::util::{
+        encode_receipt, fetch_raw_receipts, store_ancestor_headers, store_blob, store_ordered_trie,
+    },
+    kv::SharedKeyValueStore,
+    single::cfg::SingleChainHost,
     HintHandler, OnlineHostBackendCfg,
 };
 use alloy_consensus::Header;
@@ -15,6 +19,7 @@ use alloy_rlp::Decodable;
 use alloy_rpc_types::{debug::ExecutionWitness, Block, BlockTransactionsKind};
 use anyhow::{anyhow, ensure, Result};
 use async_trait::async_trait;
+use kona_executor::OutputRootComponents;
 use kona_preimage::{PreimageKey, PreimageKeyType};
 use kona_proof::HintType;
 use maili_protocol::BlockInfo;
@@ -66,8 +71,7 @@ impl HintHandler for SingleChainHintHandler {
                 ensure!(hint.data.len() == 32, "Invalid hint data length");
 
                 let hash: B256 = hint.data.as_ref().try_into()?;
-                let raw_receipts: Vec<Bytes> =
-                    providers.l1.client().request("debug_getRawReceipts", [hash]).await?;
+                let raw_receipts = fetch_raw_receipts(&providers.l1, hash, encode_receipt).await?;
 
                 store_ordered_trie(kv.as_ref(), raw_receipts.as_slice()).await?;
             }
@@ -96,41 +100,7 @@ impl HintHandler for SingleChainHintHandler {
                 }
                 let sidecar = sidecars.remove(0);
 
-                // Acquire a lock on the key-value store and set the preimages.
-                let mut kv_lock = kv.write().await;
-
-                // Set the preimage for the blob commitment.
-                kv_lock.set(
-                    PreimageKey::new(*hash, PreimageKeyType::Sha256).into(),
-                    sidecar.kzg_commitment.to_vec(),
-                )?;
-
-                // Write all the field elements to the key-value store. There should be 4096.
-                // The preimage oracle key for each field element is the keccak256 hash of
-                // `abi.encodePacked(sidecar.KZGCommitment, uint256(i))`
-                let mut blob_key = [0u8; 80];
-                blob_key[..48].copy_from_slice(sidecar.kzg_commitment.as_ref());
-                for i in 0..FIELD_ELEMENTS_PER_BLOB {
-                    blob_key[72..].copy_from_slice(i.to_be_bytes().as_ref());
-                    let blob_key_hash = keccak256(blob_key.as_ref());
-
-                    kv_lock
-                        .set(PreimageKey::new_keccak256(*blob_key_hash).into(), blob_key.into())?;
-                    kv_lock.set(
-                        PreimageKey::new(*blob_key_hash, PreimageKeyType::Blob).into(),
-                        sidecar.blob[(i as usize) << 5..(i as usize + 1) << 5].to_vec(),
-                    )?;
-                }
-
-                // Write the KZG Proof as the 4096th element.
-                blob_key[72..].copy_from_slice((FIELD_ELEMENTS_PER_BLOB).to_be_bytes().as_ref());
-                let blob_key_hash = keccak256(blob_key.as_ref());
-
-                kv_lock.set(PreimageKey::new_keccak256(*blob_key_hash).into(), blob_key.into())?;
-                kv_lock.set(
-                    PreimageKey::new(*blob_key_hash, PreimageKeyType::Blob).into(),
-                    sidecar.kzg_proof.to_vec(),
-                )?;
+                store_blob(kv.as_ref(), hash, &sidecar, FIELD_ELEMENTS_PER_BLOB).await?;
             }
             HintType::L1Precompile => {
                 ensure!(hint.data.len() >= 20, "Invalid hint data length");
@@ -139,15 +109,16 @@ impl HintHandler for SingleChainHintHandler {
                 let input = hint.data[20..].to_vec();
                 let input_hash = keccak256(hint.data.as_ref());
 
-                let result = crate::eth::execute(address, input).map_or_else(
-                    |_| vec![0u8; 1],
-                    |raw_res| {
-                        let mut res = Vec::with_capacity(1 + raw_res.len());
-                        res.push(0x01);
-                        res.extend_from_slice(&raw_res);
-                        res
-                    },
-                );
+                let result = crate::eth::execute(address, input, &providers.kzg_settings)
+                    .map_or_else(
+                        |_| vec![0u8; 1],
+                        |raw_res| {
+                            let mut res = Vec::with_capacity(1 + raw_res.len());
+                            res.push(0x01);
+                            res.extend_from_slice(&raw_res);
+                            res
+                        },
+                    );
 
                 let mut kv_lock = kv.write().await;
                 kv_lock.set(PreimageKey::new_keccak256(*input_hash).into(), hint.data.into())?;
@@ -185,7 +156,6 @@ impl HintHandler for SingleChainHintHandler {
                 store_ordered_trie(kv.as_ref(), encoded_transactions.as_slice()).await?;
             }
             HintType::StartingL2Output => {
-                const OUTPUT_ROOT_VERSION: u8 = 0;
                 const L2_TO_L1_MESSAGE_PASSER_ADDRESS: Address =
                     address!("4200000000000000000000000000000000000016");
 
@@ -206,11 +176,12 @@ impl HintHandler for SingleChainHintHandler {
                     .block_id(cfg.agreed_l2_head_hash.into())
                     .await?;
 
-                let mut raw_output = [0u8; 128];
-                raw_output[31] = OUTPUT_ROOT_VERSION;
-                raw_output[32..64].copy_from_slice(header.state_root.as_ref());
-                raw_output[64..96].copy_from_slice(l2_to_l1_message_passer.storage_hash.as_ref());
-                raw_output[96..128].copy_from_slice(cfg.agreed_l2_head_hash.as_ref());
+                let raw_output = OutputRootComponents {
+                    state_root: header.state_root,
+                    storage_root: l2_to_l1_message_passer.storage_hash,
+                    block_hash: cfg.agreed_l2_head_hash,
+                }
+                .encode();
                 let output_root = keccak256(raw_output);
 
                 ensure!(
@@ -348,8 +319,80 @@ impl HintHandler for SingleChainHintHandler {
                     kv_lock.set(key.into(), preimage.into())?;
                 }
             }
+            HintType::L2AncestorHeaders => {
+                ensure!(hint.data.len() == 40, "Invalid hint data length");
+
+                let hash = B256::from_slice(&hint.data.as_ref()[..32]);
+                let block_number = u64::from_be_bytes(hint.data[32..40].try_into()?);
+
+                store_ancestor_headers(kv.as_ref(), &providers.l2, hash, block_number).await?;
+            }
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        eth::{http_provider, FileBlobProvider, HostBlobProvider},
+        single::SingleChainProviders,
+        MemoryKeyValueStore,
+    };
+    use alloy_eips::eip4844::{kzg_to_versioned_hash, Blob, BlobTransactionSidecar};
+    use kona_proof::Hint;
+    use revm::primitives::kzg::EnvKzgSettings;
+    use std::sync::Arc;
+    use tokio::sync::RwLock;
+
+    #[tokio::test]
+    async fn test_fetch_l1_blob_from_file() {
+        let mut blob = Blob::default();
+        blob[1] = 0xFF;
+        let sidecar = BlobTransactionSidecar::try_from_blobs(vec![blob]).unwrap();
+        let commitment = sidecar.commitments[0];
+        let hash = kzg_to_versioned_hash(commitment.as_slice());
+
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join(format!("{hash}.blob")), blob.as_slice()).unwrap();
+
+        // The execution layer providers are unreachable; only the blob provider is used.
+        let providers = SingleChainProviders {
+            l1: http_provider("http://127.0.0.1:1", None).unwrap(),
+            blobs: HostBlobProvider::File(FileBlobProvider::new(dir.path().to_path_buf())),
+            l2: http_provider("http://127.0.0.1:1", None).unwrap(),
+            kzg_settings: EnvKzgSettings::Default,
+        };
+        let kv: SharedKeyValueStore = Arc::new(RwLock::new(MemoryKeyValueStore::new()));
+
+        let mut hint_data = hash.to_vec();
+        hint_data.extend_from_slice(&0u64.to_be_bytes());
+        hint_data.extend_from_slice(&0u64.to_be_bytes());
+        let hint = Hint { ty: HintType::L1Blob, data: hint_data.into() };
+
+        SingleChainHintHandler::fetch_hint(
+            hint,
+            &SingleChainHost::default(),
+            &providers,
+            kv.clone(),
+        )
+        .await
+        .unwrap();
+
+        let kv = kv.read().await;
+        assert_eq!(
+            kv.get(PreimageKey::new(*hash, PreimageKeyType::Sha256).into()),
+            Some(commitment.to_vec())
+        );
+
+        let mut blob_key = [0u8; 80];
+        blob_key[..48].copy_from_slice(commitment.as_ref());
+        let blob_key_hash = keccak256(blob_key.as_ref());
+        assert_eq!(
+            kv.get(PreimageKey::new(*blob_key_hash, PreimageKeyType::Blob).into()),
+            Some(blob[..32].to_vec())
+        );
+    }
+}
@@ -49,4 +49,18 @@ impl KeyValueStore for SingleChainLocalInputs {
     fn set(&mut self, _: B256, _: Vec<u8>) -> Result<()> {
         unreachable!("LocalKeyValueStore is read-only")
     }
+
+    fn keys(&self) -> Vec<B256> {
+        [
+            L1_HEAD_KEY,
+            L2_OUTPUT_ROOT_KEY,
+            L2_CLAIM_KEY,
+            L2_CLAIM_BLOCK_NUMBER_KEY,
+            L2_CHAIN_ID_KEY,
+            L2_ROLLUP_CONFIG_KEY,
+        ]
+        .into_iter()
+        .map(|key| PreimageKey::new_local(key.to()).into())
+        .collect()
+    }
 }
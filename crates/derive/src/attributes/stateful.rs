@@ -4,7 +4,7 @@
 use crate::{
     errors::{BuilderError, PipelineEncodingError, PipelineError, PipelineErrorKind},
     traits::{AttributesBuilder, ChainProvider, L2ChainProvider},
-    types::PipelineResult,
+    types::{update_system_config_with_receipts, PipelineResult, UnknownUpdateTypePolicy},
 };
 use alloc::{boxed::Box, fmt::Debug, string::ToString, sync::Arc, vec, vec::Vec};
 use alloy_consensus::{Eip658Value, Receipt};
@@ -36,6 +36,8 @@ where
     config_fetcher: L2P,
     /// The L1 receipts fetcher.
     receipts_fetcher: L1P,
+    /// The policy for system config update logs with an unknown update type.
+    unknown_update_policy: UnknownUpdateTypePolicy,
 }
 
 impl<L1P, L2P> StatefulAttributesBuilder<L1P, L2P>
@@ -45,7 +47,19 @@ where
 {
     /// Create a new [StatefulAttributesBuilder] with the given epoch.
     pub const fn new(rcfg: Arc<RollupConfig>, sys_cfg_fetcher: L2P, receipts: L1P) -> Self {
-        Self { rollup_cfg: rcfg, config_fetcher: sys_cfg_fetcher, receipts_fetcher: receipts }
+        Self {
+            rollup_cfg: rcfg,
+            config_fetcher: sys_cfg_fetcher,
+            receipts_fetcher: receipts,
+            unknown_update_policy: UnknownUpdateTypePolicy::Error,
+        }
+    }
+
+    /// Sets the [UnknownUpdateTypePolicy] for system config update logs with an unknown update
+    /// type.
+    pub const fn with_unknown_update_policy(mut self, policy: UnknownUpdateTypePolicy) -> Self {
+        self.unknown_update_policy = policy;
+        self
     }
 }
 
@@ -91,13 +105,14 @@ where
                 derive_deposits(epoch.hash, &receipts, self.rollup_cfg.deposit_contract_address)
                     .await
                     .map_err(|e| PipelineError::BadEncoding(e).crit())?;
-            sys_config
-                .update_with_receipts(
-                    &receipts,
-                    self.rollup_cfg.l1_system_config_address,
-                    self.rollup_cfg.is_ecotone_active(header.timestamp),
-                )
-                .map_err(|e| PipelineError::SystemConfigUpdate(e).crit())?;
+            update_system_config_with_receipts(
+                &mut sys_config,
+                &receipts,
+                self.rollup_cfg.l1_system_config_address,
+                self.rollup_cfg.is_ecotone_active(header.timestamp),
+                self.unknown_update_policy,
+            )
+            .map_err(|e| PipelineError::SystemConfigUpdate(e).crit())?;
             l1_header = header;
             deposit_transactions = deposits;
             0
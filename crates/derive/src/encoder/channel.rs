@@ -0,0 +1,251 @@
+//! Contains the [ChannelEncoder], which encodes L2 blocks into span batch channel frames.
+
+use crate::errors::ChannelEncoderError;
+use alloc::{sync::Arc, vec::Vec};
+use alloy_eips::eip2718::Encodable2718;
+use alloy_primitives::Bytes;
+use alloy_rlp::Encodable;
+use maili_genesis::RollupConfig;
+use maili_protocol::{
+    BatchType, ChannelId, Frame, L2BlockInfo, SingleBatch, SpanBatch, DERIVATION_VERSION_0,
+};
+use op_alloy_consensus::{OpBlock, OpTxEnvelope};
+
+/// The zlib compression level used when compressing channel data.
+const ZLIB_COMPRESSION_LEVEL: u8 = 9;
+
+/// The [ChannelEncoder] encodes a set of L2 blocks into the [Frame]s of a single channel,
+/// containing one span batch (Delta+ format).
+///
+/// This is the reverse of the [FrameQueue], [ChannelProvider], and [ChannelReader] stages.
+///
+/// [FrameQueue]: crate::stages::FrameQueue
+/// [ChannelProvider]: crate::stages::ChannelProvider
+/// [ChannelReader]: crate::stages::ChannelReader
+#[derive(Debug, Clone)]
+pub struct ChannelEncoder {
+    /// The rollup configuration.
+    cfg: Arc<RollupConfig>,
+    /// The ID of the channel to produce.
+    id: ChannelId,
+    /// The maximum number of channel data bytes to place in a single [Frame].
+    max_frame_data_size: usize,
+}
+
+impl ChannelEncoder {
+    /// Creates a new [ChannelEncoder].
+    pub const fn new(cfg: Arc<RollupConfig>, id: ChannelId, max_frame_data_size: usize) -> Self {
+        Self { cfg, id, max_frame_data_size }
+    }
+
+    /// Converts an L2 block into a [SingleBatch], returning the batch alongside the block's
+    /// sequence number within its epoch.
+    pub fn block_to_batch(
+        &self,
+        block: &OpBlock,
+    ) -> Result<(SingleBatch, u64), ChannelEncoderError> {
+        let info = L2BlockInfo::from_block_and_genesis(block, &self.cfg.genesis)?;
+
+        // Deposited transactions are derived from L1, and are not included in batches.
+        let transactions = block
+            .body
+            .transactions
+            .iter()
+            .filter(|tx| !matches!(tx, OpTxEnvelope::Deposit(_)))
+            .map(|tx| tx.encoded_2718().into())
+            .collect();
+
+        let batch = SingleBatch {
+            parent_hash: block.header.parent_hash,
+            epoch_num: info.l1_origin.number,
+            epoch_hash: info.l1_origin.hash,
+            timestamp: block.header.timestamp,
+            transactions,
+        };
+        Ok((batch, info.seq_num))
+    }
+
+    /// Encodes the given L2 blocks into a [SpanBatch], returning the RLP-encoded channel data
+    /// prior to compression.
+    pub fn encode_span_batch(&self, blocks: &[OpBlock]) -> Result<Vec<u8>, ChannelEncoderError> {
+        if blocks.is_empty() {
+            return Err(ChannelEncoderError::EmptyChannel);
+        }
+
+        let mut span = SpanBatch {
+            genesis_timestamp: self.cfg.genesis.l2_time,
+            chain_id: self.cfg.l2_chain_id,
+            ..Default::default()
+        };
+        for block in blocks {
+            let (batch, seq_num) = self.block_to_batch(block)?;
+            span.append_singular_batch(batch, seq_num)?;
+        }
+
+        // Encode the batch, prefixed with its type.
+        let mut batch_data = Vec::new();
+        batch_data.push(BatchType::Span as u8);
+        span.to_raw_span_batch()?.encode(&mut batch_data)?;
+
+        // Batches are placed into the channel as RLP strings.
+        let batch_data = Bytes::from(batch_data);
+        let mut channel_data = Vec::with_capacity(batch_data.length());
+        batch_data.encode(&mut channel_data);
+        Ok(channel_data)
+    }
+
+    /// Encodes the given L2 blocks into the [Frame]s of a single channel.
+    ///
+    /// The channel data is compressed with zlib, which is valid both before and after Fjord.
+    pub fn encode(&self, blocks: &[OpBlock]) -> Result<Vec<Frame>, ChannelEncoderError> {
+        if self.max_frame_data_size == 0 {
+            return Err(ChannelEncoderError::ZeroFrameSize);
+        }
+
+        let channel_data = self.encode_span_batch(blocks)?;
+        let compressed =
+            miniz_oxide::deflate::compress_to_vec_zlib(&channel_data, ZLIB_COMPRESSION_LEVEL);
+
+        let chunks = compressed.chunks(self.max_frame_data_size).collect::<Vec<_>>();
+        let frame_count = chunks.len();
+        if frame_count > u16::MAX as usize {
+            return Err(ChannelEncoderError::TooManyFrames(frame_count));
+        }
+
+        let frames = chunks
+            .into_iter()
+            .enumerate()
+            .map(|(i, data)| Frame {
+                id: self.id,
+                number: i as u16,
+                data: data.to_vec(),
+                is_last: i == frame_count - 1,
+            })
+            .collect();
+        Ok(frames)
+    }
+}
+
+/// Encodes a set of [Frame]s into the data of a single batcher transaction.
+pub fn encode_batcher_tx(frames: &[Frame]) -> Bytes {
+    let mut data = Vec::new();
+    data.push(DERIVATION_VERSION_0);
+    for frame in frames {
+        data.extend_from_slice(&frame.encode());
+    }
+    data.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        errors::PipelineError,
+        stages::{BatchStreamProvider, ChannelProvider, ChannelReader, FrameQueue},
+        test_utils::TestFrameQueueProvider,
+    };
+    use alloc::vec;
+    use alloy_consensus::{BlockBody, Header, SignableTransaction, TxEip1559};
+    use alloy_primitives::{Address, PrimitiveSignature as Signature, TxKind, B256, U256};
+    use maili_genesis::SystemConfig;
+    use maili_protocol::{Batch, BlockInfo, L1BlockInfoTx};
+
+    fn mock_block(cfg: &RollupConfig, l1_header: &Header, number: u64, seq_num: u64) -> OpBlock {
+        let timestamp = cfg.genesis.l2_time + number * cfg.block_time;
+        let (_, deposit) = L1BlockInfoTx::try_new_with_deposit_tx(
+            cfg,
+            &SystemConfig::default(),
+            seq_num,
+            l1_header,
+            timestamp,
+        )
+        .unwrap();
+        let user_tx = TxEip1559 {
+            chain_id: cfg.l2_chain_id,
+            nonce: number,
+            gas_limit: 21_000,
+            max_fee_per_gas: 1,
+            to: TxKind::Call(Address::repeat_byte(0xBB)),
+            value: U256::from(number),
+            ..Default::default()
+        }
+        .into_signed(Signature::test_signature());
+
+        OpBlock {
+            header: Header {
+                number,
+                timestamp,
+                parent_hash: B256::with_last_byte(number as u8 - 1),
+                ..Default::default()
+            },
+            body: BlockBody {
+                transactions: vec![OpTxEnvelope::Deposit(deposit), OpTxEnvelope::Eip1559(user_tx)],
+                ommers: vec![],
+                withdrawals: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_encode_empty_channel() {
+        let encoder = ChannelEncoder::new(Arc::new(RollupConfig::default()), [0xFF; 16], 100);
+        assert!(matches!(encoder.encode(&[]), Err(ChannelEncoderError::EmptyChannel)));
+    }
+
+    #[tokio::test]
+    async fn test_encode_round_trip() {
+        let cfg = Arc::new(RollupConfig {
+            delta_time: Some(0),
+            block_time: 2,
+            l2_chain_id: 10,
+            ..Default::default()
+        });
+        let l1_header = Header { number: 1, timestamp: 1, ..Default::default() };
+        let blocks = [mock_block(&cfg, &l1_header, 1, 0), mock_block(&cfg, &l1_header, 2, 1)];
+
+        // Encode the blocks into several small frames.
+        let encoder = ChannelEncoder::new(cfg.clone(), [0xAA; 16], 16);
+        let frames = encoder.encode(&blocks).unwrap();
+        assert!(frames.len() > 1);
+        assert!(frames.last().unwrap().is_last);
+
+        // Feed the frames through the frame queue, channel provider, and channel reader stages.
+        let l1_origin = BlockInfo {
+            number: 1,
+            timestamp: 1,
+            hash: l1_header.hash_slow(),
+            ..Default::default()
+        };
+        let mut provider = TestFrameQueueProvider::new(vec![Ok(encode_batcher_tx(&frames))]);
+        provider.set_origin(l1_origin);
+        let frame_queue = FrameQueue::new(provider, cfg.clone());
+        let channel_provider = ChannelProvider::new(cfg.clone(), frame_queue);
+        let mut reader = ChannelReader::new(channel_provider, cfg.clone());
+
+        let batch = loop {
+            match reader.next_batch().await {
+                Ok(batch) => break batch,
+                Err(e) if e == PipelineError::NotEnoughData.temp() => continue,
+                Err(e) => panic!("Unexpected error: {e}"),
+            }
+        };
+        let Batch::Span(span) = batch else {
+            panic!("Expected a span batch");
+        };
+
+        // Recover the original batches from the span batch.
+        let recovered = span.get_singular_batches(&[l1_origin], L2BlockInfo::default()).unwrap();
+        assert_eq!(recovered.len(), blocks.len());
+        for (recovered, block) in recovered.iter().zip(blocks.iter()) {
+            let (expected, _) = encoder.block_to_batch(block).unwrap();
+            assert_eq!(recovered.timestamp, expected.timestamp);
+            assert_eq!(recovered.epoch_num, expected.epoch_num);
+            assert_eq!(recovered.epoch_hash, expected.epoch_hash);
+
+            // The deposit is stripped, and the signed user transaction survives the roundtrip.
+            let user_tx: Bytes = block.body.transactions[1].encoded_2718().into();
+            assert_eq!(recovered.transactions, vec![user_tx]);
+            assert_eq!(recovered.transactions, expected.transactions);
+        }
+    }
+}
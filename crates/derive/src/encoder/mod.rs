@@ -0,0 +1,7 @@
+//! The encoder module contains the inverse of the derivation pipeline's batch decoding stages.
+//!
+//! It is intended for sequencer-side tooling and round-trip testing, allowing a set of L2 blocks
+//! to be encoded into the span batch channel frames that a batcher would submit to L1.
+
+mod channel;
+pub use channel::{encode_batcher_tx, ChannelEncoder};
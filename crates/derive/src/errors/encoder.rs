@@ -0,0 +1,26 @@
+//! Error types for the channel encoder.
+
+use maili_protocol::{FromBlockError, SpanBatchError};
+use thiserror::Error;
+
+/// An error returned by the [ChannelEncoder].
+///
+/// [ChannelEncoder]: crate::encoder::ChannelEncoder
+#[derive(Error, Debug)]
+pub enum ChannelEncoderError {
+    /// No blocks were provided to the encoder.
+    #[error("No blocks provided to the channel encoder")]
+    EmptyChannel,
+    /// The maximum frame data size is zero.
+    #[error("Maximum frame data size must be non-zero")]
+    ZeroFrameSize,
+    /// The channel requires more frames than can be numbered.
+    #[error("Channel requires too many frames: {0}")]
+    TooManyFrames(usize),
+    /// Failed to derive the L2 block info from a block.
+    #[error("From block error: {0}")]
+    FromBlock(#[from] FromBlockError),
+    /// Failed to construct or encode the span batch.
+    #[error("Span batch error: {0}")]
+    SpanBatch(#[from] SpanBatchError),
+}
@@ -4,10 +4,13 @@ mod attributes;
 pub use attributes::BuilderError;
 
 mod stages;
-pub use stages::BatchDecompressionError;
+pub use stages::{BatchDecompressionError, FrameParseError};
 
 mod pipeline;
 pub use pipeline::{PipelineEncodingError, PipelineError, PipelineErrorKind, ResetError};
 
 mod sources;
 pub use sources::{BlobDecodingError, BlobProviderError};
+
+mod encoder;
+pub use encoder::ChannelEncoderError;
@@ -139,6 +139,10 @@ pub enum ResetError {
     /// A Holocene activation temporary error.
     #[error("Holocene activation reset")]
     HoloceneActivation,
+    /// A blob referenced by an L1 block could not be found, likely because the block was
+    /// reorged out. The argument is the versioned hash of the missing blob.
+    #[error("Blob not found, its L1 block may have been reorged out: {0}")]
+    BlobNotFound(B256),
 }
 
 impl ResetError {
@@ -226,6 +230,7 @@ mod tests {
                 Default::default(),
             )),
             ResetError::HoloceneActivation,
+            ResetError::BlobNotFound(Default::default()),
         ];
         for error in reset_errors.into_iter() {
             let expected = PipelineErrorKind::Reset(error.clone());
@@ -1,7 +1,8 @@
 //! Error types for sources.
 
-use super::{PipelineError, PipelineErrorKind};
+use super::{PipelineError, PipelineErrorKind, ResetError};
 use alloc::string::{String, ToString};
+use alloy_primitives::B256;
 use thiserror::Error;
 
 /// Blob Decoding Error
@@ -33,6 +34,10 @@ pub enum BlobProviderError {
     /// Blob decoding error.
     #[error("Blob decoding error: {0}")]
     BlobDecoding(#[from] BlobDecodingError),
+    /// The blob with the given versioned hash was not found, as happens when the L1 block that
+    /// referenced it has been reorged out.
+    #[error("Blob not found: {0}")]
+    BlobNotFound(B256),
     /// Error pertaining to the backend transport.
     #[error("{0}")]
     Backend(String),
@@ -46,6 +51,7 @@ impl From<BlobProviderError> for PipelineErrorKind {
             }
             BlobProviderError::SlotDerivation => PipelineError::Provider(val.to_string()).crit(),
             BlobProviderError::BlobDecoding(_) => PipelineError::Provider(val.to_string()).crit(),
+            BlobProviderError::BlobNotFound(hash) => ResetError::BlobNotFound(hash).reset(),
             BlobProviderError::Backend(_) => PipelineError::Provider(val.to_string()).temp(),
         }
     }
@@ -73,5 +79,11 @@ mod tests {
         let err: PipelineErrorKind =
             BlobProviderError::BlobDecoding(BlobDecodingError::InvalidFieldElement).into();
         assert!(matches!(err, PipelineErrorKind::Critical(_)));
+
+        let err: PipelineErrorKind = BlobProviderError::BlobNotFound(B256::ZERO).into();
+        assert_eq!(err, ResetError::BlobNotFound(B256::ZERO).reset());
+
+        let err: PipelineErrorKind = BlobProviderError::Backend("timeout".to_string()).into();
+        assert!(matches!(err, PipelineErrorKind::Temporary(PipelineError::Provider(_))));
     }
 }
@@ -1,6 +1,6 @@
 //! Error types for derivation pipeline stages.
 
-use maili_protocol::MAX_SPAN_BATCH_ELEMENTS;
+use maili_protocol::{FrameDecodingError, MAX_SPAN_BATCH_ELEMENTS};
 use thiserror::Error;
 
 /// A frame decompression error.
@@ -10,3 +10,17 @@ pub enum BatchDecompressionError {
     #[error("The batch exceeds the maximum number of elements: {max_size}", max_size = MAX_SPAN_BATCH_ELEMENTS)]
     BatchTooLarge,
 }
+
+/// An error returned when parsing a single [Frame] from raw bytes.
+///
+/// [Frame]: maili_protocol::Frame
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum FrameParseError {
+    /// The frame could not be decoded, e.g. because the data is too short or its length prefix
+    /// exceeds the maximum frame length or the remaining data.
+    #[error("Failed to decode frame: {0}")]
+    Decode(#[from] FrameDecodingError),
+    /// The frame's `is_last` flag is neither `0` nor `1`.
+    #[error("Invalid is_last flag: {0}")]
+    InvalidIsLast(u8),
+}
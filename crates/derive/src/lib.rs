@@ -15,11 +15,13 @@ extern crate tracing;
 /// Required types and traits for kona's derivation pipeline.
 pub mod prelude {
     pub use crate::{
-        attributes::*, errors::*, pipeline::*, sources::*, stages::*, traits::*, types::*,
+        attributes::*, encoder::*, errors::*, pipeline::*, sources::*, stages::*, traits::*,
+        types::*,
     };
 }
 
 pub mod attributes;
+pub mod encoder;
 pub mod errors;
 pub mod pipeline;
 pub mod sources;
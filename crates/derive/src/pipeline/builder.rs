@@ -7,6 +7,7 @@ use crate::{
         L1Retrieval, L1Traversal,
     },
     traits::{AttributesBuilder, ChainProvider, DataAvailabilityProvider, L2ChainProvider},
+    types::UnknownUpdateTypePolicy,
 };
 use alloc::sync::Arc;
 use core::fmt::Debug;
@@ -37,6 +38,7 @@ where
     builder: Option<B>,
     origin: Option<BlockInfo>,
     rollup_config: Option<Arc<RollupConfig>>,
+    unknown_update_policy: UnknownUpdateTypePolicy,
 }
 
 impl<B, P, T, D> Default for PipelineBuilder<B, P, T, D>
@@ -54,6 +56,7 @@ where
             builder: None,
             origin: None,
             rollup_config: None,
+            unknown_update_policy: UnknownUpdateTypePolicy::default(),
         }
     }
 }
@@ -106,6 +109,12 @@ where
         self
     }
 
+    /// Sets the policy for system config update logs with an unknown update type.
+    pub const fn unknown_update_policy(mut self, policy: UnknownUpdateTypePolicy) -> Self {
+        self.unknown_update_policy = policy;
+        self
+    }
+
     /// Builds the pipeline.
     pub fn build(self) -> DerivationPipeline<AttributesQueueStage<D, P, T, B>, T> {
         self.into()
@@ -129,7 +138,8 @@ where
         let attributes_builder = builder.builder.expect("builder must be set");
 
         // Compose the stage stack.
-        let mut l1_traversal = L1Traversal::new(chain_provider, Arc::clone(&rollup_config));
+        let mut l1_traversal = L1Traversal::new(chain_provider, Arc::clone(&rollup_config))
+            .with_unknown_update_policy(builder.unknown_update_policy);
         l1_traversal.block = Some(builder.origin.expect("origin must be set"));
         let l1_retrieval = L1Retrieval::new(l1_traversal, dap_source);
         let frame_queue = FrameQueue::new(l1_retrieval, Arc::clone(&rollup_config));
@@ -5,7 +5,7 @@ use crate::{
     traits::{
         L2ChainProvider, NextAttributes, OriginAdvancer, OriginProvider, Pipeline, SignalReceiver,
     },
-    types::{ActivationSignal, PipelineResult, ResetSignal, Signal, StepResult},
+    types::{ActivationSignal, PipelineResult, ResetSignal, Signal, StageId, StepResult},
 };
 use alloc::{boxed::Box, collections::VecDeque, sync::Arc};
 use async_trait::async_trait;
@@ -46,6 +46,23 @@ where
     ) -> Self {
         Self { attributes, prepared: VecDeque::new(), rollup_config, l2_chain_provider }
     }
+
+    /// Resets the pipeline from the given [StageId]. Only `stage` and the stages downstream of it
+    /// are reset, while the buffers of the upstream stages are preserved.
+    ///
+    /// ### Parameters
+    ///
+    /// The `signal` contains the L2 safe head and L1 origin to reset to.
+    pub async fn reset_from_stage(
+        &mut self,
+        stage: StageId,
+        signal: ResetSignal,
+    ) -> PipelineResult<()>
+    where
+        S: Sync,
+    {
+        self.signal(signal.with_from_stage(stage).signal()).await
+    }
 }
 
 impl<S, P> OriginProvider for DerivationPipeline<S, P>
@@ -192,10 +209,18 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{pipeline::DerivationPipeline, test_utils::*};
-    use alloc::{string::ToString, sync::Arc};
+    use crate::{
+        errors::ResetError,
+        pipeline::{DerivationPipeline, PipelineBuilder},
+        test_utils::*,
+    };
+    use alloc::{string::ToString, sync::Arc, vec};
+    use alloy_consensus::{Eip658Value, Receipt};
+    use alloy_primitives::{Address, Log, LogData, B256};
     use alloy_rpc_types_engine::PayloadAttributes;
-    use maili_genesis::{RollupConfig, SystemConfig};
+    use maili_genesis::{
+        RollupConfig, SystemConfig, CONFIG_UPDATE_EVENT_VERSION_0, CONFIG_UPDATE_TOPIC,
+    };
     use maili_protocol::L2BlockInfo;
     use maili_rpc::OpAttributesWithParent;
     use op_alloy_rpc_types_engine::OpPayloadAttributes;
@@ -253,6 +278,78 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_derivation_pipeline_non_canonical_origin() {
+        let origin = BlockInfo { hash: B256::repeat_byte(0x01), ..Default::default() };
+        let next =
+            BlockInfo { number: 1, parent_hash: B256::repeat_byte(0x02), ..Default::default() };
+        let mut chain_provider = TestChainProvider::default();
+        chain_provider.insert_block(1, next);
+        let mut pipeline = PipelineBuilder::new()
+            .rollup_config(Arc::new(RollupConfig::default()))
+            .origin(origin)
+            .dap_source(TestDAP::default())
+            .builder(TestAttributesBuilder::default())
+            .chain_provider(chain_provider)
+            .l2_chain_provider(TestL2ChainProvider::default())
+            .build();
+
+        // The next L1 block does not chain to the current origin, so a reset is requested.
+        let cursor = L2BlockInfo { l1_origin: origin.id(), ..Default::default() };
+        let result = pipeline.step(cursor).await;
+        assert_eq!(
+            result,
+            StepResult::OriginAdvanceErr(
+                ResetError::ReorgDetected(origin.hash, next.parent_hash).reset()
+            )
+        );
+        assert_eq!(pipeline.origin(), Some(origin));
+    }
+
+    #[tokio::test]
+    async fn test_derivation_pipeline_unknown_update_type_errors_by_default() {
+        let l1_system_config_address = Address::repeat_byte(0x13);
+        let next = BlockInfo { number: 1, hash: B256::repeat_byte(0x01), ..Default::default() };
+        let unknown_update_log = Log {
+            address: l1_system_config_address,
+            data: LogData::new_unchecked(
+                vec![
+                    CONFIG_UPDATE_TOPIC,
+                    CONFIG_UPDATE_EVENT_VERSION_0,
+                    B256::with_last_byte(0xFF),
+                ],
+                Default::default(),
+            ),
+        };
+        let receipt = Receipt {
+            status: Eip658Value::Eip658(true),
+            logs: vec![unknown_update_log],
+            ..Default::default()
+        };
+        let mut chain_provider = TestChainProvider::default();
+        chain_provider.insert_block(1, next);
+        chain_provider.insert_receipts(next.hash, vec![receipt]);
+        let rollup_config = RollupConfig { l1_system_config_address, ..Default::default() };
+        let mut pipeline = PipelineBuilder::new()
+            .rollup_config(Arc::new(rollup_config))
+            .origin(BlockInfo::default())
+            .dap_source(TestDAP::default())
+            .builder(TestAttributesBuilder::default())
+            .chain_provider(chain_provider)
+            .l2_chain_provider(TestL2ChainProvider::default())
+            .build();
+
+        // Without an explicit policy, an unknown update type is a critical error.
+        let result = pipeline.step(L2BlockInfo::default()).await;
+        assert!(matches!(
+            result,
+            StepResult::OriginAdvanceErr(PipelineErrorKind::Critical(
+                PipelineError::SystemConfigUpdate(_)
+            ))
+        ));
+        assert_eq!(pipeline.origin(), Some(BlockInfo::default()));
+    }
+
     #[tokio::test]
     async fn test_derivation_pipeline_prepared_attributes() {
         let rollup_config = Arc::new(RollupConfig::default());
@@ -1,7 +1,7 @@
 //! Blob Data Source
 
 use crate::{
-    errors::{BlobProviderError, PipelineError},
+    errors::{BlobProviderError, PipelineError, PipelineErrorKind},
     sources::BlobData,
     traits::{BlobProvider, ChainProvider, DataAvailabilityProvider},
     types::PipelineResult,
@@ -117,7 +117,11 @@ where
     }
 
     /// Loads blob data into the source if it is not open.
-    async fn load_blobs(&mut self, block_ref: &BlockInfo) -> Result<(), BlobProviderError> {
+    ///
+    /// Blobs that the [BlobProvider] reports as missing signal a pipeline reset, as the L1 block
+    /// that referenced them may have been reorged out. All other blob provider errors are
+    /// temporary, and the load is retried.
+    async fn load_blobs(&mut self, block_ref: &BlockInfo) -> PipelineResult<()> {
         if self.open {
             return Ok(());
         }
@@ -137,10 +141,18 @@ where
             return Ok(());
         }
 
-        let blobs = self.blob_fetcher.get_blobs(block_ref, &blob_hashes).await.map_err(|e| {
-            warn!(target: "blob-source", "Failed to fetch blobs: {e}");
-            BlobProviderError::Backend(e.to_string())
-        })?;
+        let blobs = match self.blob_fetcher.get_blobs(block_ref, &blob_hashes).await {
+            Ok(blobs) => blobs,
+            Err(e) => {
+                warn!(target: "blob-source", "Failed to fetch blobs: {e}");
+                let message = e.to_string();
+                let kind: PipelineErrorKind = e.into();
+                return Err(match kind {
+                    reset @ PipelineErrorKind::Reset(_) => reset,
+                    _ => BlobProviderError::Backend(message).into(),
+                });
+            }
+        };
 
         // Fill the blob pointers.
         let mut blob_index = 0;
@@ -152,7 +164,7 @@ where
                     }
                 }
                 Err(e) => {
-                    return Err(e.into());
+                    return Err(BlobProviderError::from(e).into());
                 }
             }
         }
@@ -212,7 +224,7 @@ where
 pub(crate) mod tests {
     use super::*;
     use crate::{
-        errors::PipelineErrorKind,
+        errors::ResetError,
         test_utils::{TestBlobProvider, TestChainProvider},
     };
     use alloy_rlp::Decodable;
@@ -244,7 +256,7 @@ pub(crate) mod tests {
         let mut source = default_test_blob_source();
         assert!(matches!(
             source.load_blobs(&BlockInfo::default()).await,
-            Err(BlobProviderError::Backend(_))
+            Err(PipelineErrorKind::Temporary(PipelineError::Provider(_)))
         ));
     }
 
@@ -271,7 +283,7 @@ pub(crate) mod tests {
         source.chain_provider.insert_block_with_transactions(1, block_info, txs);
         assert!(matches!(
             source.load_blobs(&BlockInfo::default()).await,
-            Err(BlobProviderError::Backend(_))
+            Err(PipelineErrorKind::Temporary(PipelineError::Provider(_)))
         ));
     }
 
@@ -311,6 +323,28 @@ pub(crate) mod tests {
         assert!(!source.data.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_load_blobs_missing_blob_resets() {
+        let mut source = default_test_blob_source();
+        let block_info = BlockInfo::default();
+        source.signer = alloy_primitives::address!("A83C816D4f9b2783761a22BA6FADB0eB0606D7B2");
+        source.batcher_address =
+            alloy_primitives::address!("11E9CA82A3a762b4B5bd264d4173a242e7a77064");
+        let txs = valid_blob_txs();
+        source.chain_provider.insert_block_with_transactions(1, block_info, txs);
+
+        // The blob provider has none of the referenced blobs, as if the block was reorged out.
+        let err = source.next(&BlockInfo::default()).await.unwrap_err();
+        assert_eq!(
+            err,
+            ResetError::BlobNotFound(alloy_primitives::b256!(
+                "012ec3d6f66766bedb002a190126b3549fce0047de0d4c25cffce0dc1c57921a"
+            ))
+            .reset()
+        );
+        assert!(!source.open);
+    }
+
     #[tokio::test]
     async fn test_open_empty_data_eof() {
         let mut source = default_test_blob_source();
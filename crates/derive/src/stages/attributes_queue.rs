@@ -6,7 +6,7 @@ use crate::{
         AttributesBuilder, AttributesProvider, NextAttributes, OriginAdvancer, OriginProvider,
         SignalReceiver,
     },
-    types::{PipelineResult, Signal},
+    types::{PipelineResult, Signal, StageId},
 };
 use alloc::{boxed::Box, sync::Arc};
 use async_trait::async_trait;
@@ -178,6 +178,10 @@ where
     AB: AttributesBuilder + Send + Debug,
 {
     async fn signal(&mut self, signal: Signal) -> PipelineResult<()> {
+        if !signal.applies_to(StageId::AttributesQueue) {
+            return Ok(());
+        }
+
         match signal {
             s @ Signal::Reset(_) | s @ Signal::Activation(_) => {
                 self.prev.signal(s).await?;
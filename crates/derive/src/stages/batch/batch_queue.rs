@@ -4,7 +4,7 @@ use super::NextBatchProvider;
 use crate::{
     errors::{PipelineEncodingError, PipelineError, PipelineErrorKind, ResetError},
     traits::{AttributesProvider, L2ChainProvider, OriginAdvancer, OriginProvider, SignalReceiver},
-    types::{PipelineResult, ResetSignal, Signal},
+    types::{PipelineResult, ResetSignal, Signal, StageId},
 };
 use alloc::{boxed::Box, sync::Arc, vec::Vec};
 use async_trait::async_trait;
@@ -435,6 +435,10 @@ where
     BF: L2ChainProvider + Send + Debug,
 {
     async fn signal(&mut self, signal: Signal) -> PipelineResult<()> {
+        if !signal.applies_to(StageId::BatchProvider) {
+            return Ok(());
+        }
+
         match signal {
             s @ Signal::Reset(ResetSignal { l1_origin, .. }) => {
                 self.prev.signal(s).await?;
@@ -1094,4 +1098,52 @@ mod tests {
         let batch = bq.next_batch(parent).await.unwrap();
         assert_eq!(batch, SingleBatch::default());
     }
+
+    async fn check_batch_validity(
+        cfg: RollupConfig,
+        parent_timestamp: u64,
+        batch_timestamp: u64,
+        l1_blocks: &[BlockInfo],
+    ) -> BatchValidity {
+        let batch = BatchWithInclusionBlock {
+            inclusion_block: BlockInfo::default(),
+            batch: Batch::Single(SingleBatch { timestamp: batch_timestamp, ..Default::default() }),
+        };
+        let parent = L2BlockInfo {
+            block_info: BlockInfo { timestamp: parent_timestamp, ..Default::default() },
+            ..Default::default()
+        };
+        let mut fetcher = TestL2ChainProvider::default();
+        batch.check_batch(&cfg, l1_blocks, parent, &mut fetcher).await
+    }
+
+    #[tokio::test]
+    async fn test_check_batch_outcomes() {
+        let l1_blocks = [BlockInfo::default(), BlockInfo::default()];
+        let holocene = RollupConfig { holocene_time: Some(0), block_time: 2, ..Default::default() };
+
+        // The batch builds on the parent, within the sequencer drift of its epoch.
+        let cfg = RollupConfig { max_sequencer_drift: 700, ..Default::default() };
+        assert_eq!(check_batch_validity(cfg, 100, 100, &l1_blocks).await, BatchValidity::Accept);
+
+        // The batch is ahead of the next L2 block.
+        assert_eq!(
+            check_batch_validity(RollupConfig::default(), 0, 100, &l1_blocks).await,
+            BatchValidity::Future
+        );
+
+        // The batch is behind the next L2 block, which is invalid before Holocene, and outdated
+        // after it.
+        assert_eq!(
+            check_batch_validity(RollupConfig::default(), 101, 100, &l1_blocks).await,
+            BatchValidity::Drop
+        );
+        assert_eq!(check_batch_validity(holocene, 101, 100, &l1_blocks).await, BatchValidity::Past);
+
+        // Without the L1 blocks of the sequencing window, no verdict can be reached yet.
+        assert_eq!(
+            check_batch_validity(RollupConfig::default(), 0, 0, &[]).await,
+            BatchValidity::Undecided
+        );
+    }
 }
@@ -4,7 +4,7 @@ use crate::{
     errors::{PipelineEncodingError, PipelineError},
     stages::NextBatchProvider,
     traits::{L2ChainProvider, OriginAdvancer, OriginProvider, SignalReceiver},
-    types::{PipelineResult, Signal},
+    types::{PipelineResult, Signal, StageId},
 };
 use alloc::{boxed::Box, collections::VecDeque, sync::Arc};
 use async_trait::async_trait;
@@ -210,6 +210,10 @@ where
     BF: L2ChainProvider + Send + Debug,
 {
     async fn signal(&mut self, signal: Signal) -> PipelineResult<()> {
+        if !signal.applies_to(StageId::BatchStream) {
+            return Ok(());
+        }
+
         self.prev.signal(signal).await?;
         self.buffer.clear();
         self.span.take();
@@ -6,7 +6,7 @@ use crate::{
     errors::ResetError,
     prelude::{OriginProvider, PipelineError, PipelineErrorKind},
     traits::{AttributesProvider, OriginAdvancer, SignalReceiver},
-    types::{PipelineResult, ResetSignal, Signal},
+    types::{PipelineResult, ResetSignal, Signal, StageId},
 };
 use alloc::{boxed::Box, sync::Arc, vec::Vec};
 use async_trait::async_trait;
@@ -290,6 +290,10 @@ where
     P: NextBatchProvider + OriginAdvancer + OriginProvider + SignalReceiver + Send + Debug,
 {
     async fn signal(&mut self, signal: Signal) -> PipelineResult<()> {
+        if !signal.applies_to(StageId::BatchProvider) {
+            return Ok(());
+        }
+
         match signal {
             s @ Signal::Reset(ResetSignal { l1_origin, .. }) => {
                 self.prev.signal(s).await?;
@@ -356,6 +360,7 @@ mod test {
                 0,
             ),
             system_config: None,
+            from_stage: None,
         }))
         .await
         .unwrap();
@@ -385,6 +390,7 @@ mod test {
                 0,
             ),
             system_config: None,
+            from_stage: None,
         }))
         .await
         .unwrap();
@@ -414,6 +420,7 @@ mod test {
                 0,
             ),
             system_config: None,
+            from_stage: None,
         }))
         .await
         .unwrap();
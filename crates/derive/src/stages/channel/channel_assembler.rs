@@ -4,7 +4,7 @@ use super::{ChannelReaderProvider, NextFrameProvider};
 use crate::{
     prelude::{OriginProvider, PipelineError},
     traits::{OriginAdvancer, SignalReceiver},
-    types::{PipelineResult, Signal},
+    types::{PipelineResult, Signal, StageId},
 };
 use alloc::{boxed::Box, sync::Arc};
 use alloy_primitives::{hex, Bytes};
@@ -176,6 +176,10 @@ where
     P: NextFrameProvider + OriginAdvancer + OriginProvider + SignalReceiver + Send + Debug,
 {
     async fn signal(&mut self, signal: Signal) -> PipelineResult<()> {
+        if !signal.applies_to(StageId::ChannelProvider) {
+            return Ok(());
+        }
+
         self.prev.signal(signal).await?;
         self.channel = None;
         Ok(())
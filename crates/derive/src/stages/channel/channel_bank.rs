@@ -5,9 +5,9 @@ use crate::{
     errors::{PipelineError, PipelineErrorKind},
     stages::ChannelReaderProvider,
     traits::{OriginAdvancer, OriginProvider, SignalReceiver},
-    types::{PipelineResult, Signal},
+    types::{PipelineResult, Signal, StageId},
 };
-use alloc::{boxed::Box, collections::VecDeque, sync::Arc};
+use alloc::{boxed::Box, collections::VecDeque, sync::Arc, vec::Vec};
 use alloy_primitives::{hex, map::HashMap, Bytes};
 use async_trait::async_trait;
 use core::fmt::Debug;
@@ -20,6 +20,19 @@ pub(crate) const MAX_CHANNEL_BANK_SIZE: usize = 100_000_000;
 /// The maximum size of a channel bank after the Fjord Hardfork.
 pub(crate) const FJORD_MAX_CHANNEL_BANK_SIZE: usize = 1_000_000_000;
 
+/// A read-only summary of a [Channel] buffered within the [ChannelBank].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChannelSummary {
+    /// The ID of the channel.
+    pub id: ChannelId,
+    /// The number of frames buffered for the channel.
+    pub frame_count: usize,
+    /// Whether or not the channel is ready to be read.
+    pub is_ready: bool,
+    /// The L1 origin block number at which the channel was opened.
+    pub opened_at_origin: u64,
+}
+
 /// [ChannelBank] is a stateful stage that does the following:
 /// 1. Unmarshalls frames from L1 transaction data
 /// 2. Applies those frames to a channel
@@ -60,6 +73,22 @@ where
         self.channels.iter().fold(0, |acc, (_, c)| acc + c.size())
     }
 
+    /// Returns a [ChannelSummary] for each buffered channel, in FIFO order.
+    ///
+    /// This is a read-only diagnostic, and does not step the pipeline.
+    pub fn buffered_channels(&self) -> Vec<ChannelSummary> {
+        self.channel_queue
+            .iter()
+            .filter_map(|id| self.channels.get(id).map(|channel| (*id, channel)))
+            .map(|(id, channel)| ChannelSummary {
+                id,
+                frame_count: channel.len(),
+                is_ready: channel.is_ready(),
+                opened_at_origin: channel.open_block_number(),
+            })
+            .collect()
+    }
+
     /// Prunes the Channel bank, until it is below the max channel bank size.
     /// Prunes from the high-priority channel since it failed to be read.
     pub fn prune(&mut self) -> PipelineResult<()> {
@@ -105,7 +134,8 @@ where
             return Ok(());
         }
 
-        // Ingest the frame. If it fails, ignore the frame.
+        // Ingest the frame. If it fails, ignore the frame. This includes frames numbered at or
+        // beyond the closing frame of the channel, which `Channel::add_frame` rejects.
         let frame_id = frame.id;
         if current_channel.add_frame(frame, origin).is_err() {
             warn!(target: "channel-bank", "Failed to add frame to channel: {:?}", frame_id);
@@ -233,6 +263,10 @@ where
     P: NextFrameProvider + OriginAdvancer + OriginProvider + SignalReceiver + Send + Debug,
 {
     async fn signal(&mut self, signal: Signal) -> PipelineResult<()> {
+        if !signal.applies_to(StageId::ChannelProvider) {
+            return Ok(());
+        }
+
         self.prev.signal(signal).await?;
         self.channels.clear();
         self.channel_queue = VecDeque::with_capacity(10);
@@ -390,6 +424,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_buffered_channels() {
+        let mut mock = TestNextFrameProvider::new(vec![]);
+        mock.block_info = Some(BlockInfo { number: 5, ..Default::default() });
+        let cfg = Arc::new(RollupConfig::default());
+        let mut channel_bank = ChannelBank::new(cfg, mock);
+        assert!(channel_bank.buffered_channels().is_empty());
+
+        // Ingest two frames for the first channel, and one frame for the second.
+        channel_bank.ingest_frame(crate::frame!(0xAA, 0, vec![0xDD; 50], false)).unwrap();
+        channel_bank.ingest_frame(crate::frame!(0xBB, 0, vec![0xDD; 50], false)).unwrap();
+        channel_bank.ingest_frame(crate::frame!(0xAA, 1, vec![0xDD; 50], false)).unwrap();
+
+        let summaries = channel_bank.buffered_channels();
+        assert_eq!(
+            summaries,
+            vec![
+                ChannelSummary {
+                    id: [0xAA; 16],
+                    frame_count: 2,
+                    is_ready: false,
+                    opened_at_origin: 5
+                },
+                ChannelSummary {
+                    id: [0xBB; 16],
+                    frame_count: 1,
+                    is_ready: false,
+                    opened_at_origin: 5
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ingest_frames_beyond_closing_frame() {
+        let trace_store: TraceStorage = Default::default();
+        let layer = CollectingLayer::new(trace_store.clone());
+        let subscriber = tracing_subscriber::Registry::default().with(layer);
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let mock = TestNextFrameProvider::new(vec![]);
+        let mut channel_bank = ChannelBank::new(Arc::new(RollupConfig::default()), mock);
+
+        // The closing frame declares two frames for the channel, so later frames are rejected.
+        channel_bank.ingest_frame(crate::frame!(0xAA, 1, vec![0xDD; 50], true)).unwrap();
+        channel_bank.ingest_frame(crate::frame!(0xAA, 2, vec![0xDD; 50], false)).unwrap();
+        channel_bank.ingest_frame(crate::frame!(0xAA, 3, vec![0xDD; 50], false)).unwrap();
+        let summary = channel_bank.buffered_channels()[0];
+        assert_eq!(summary.frame_count, 1);
+        assert!(!summary.is_ready);
+        assert_eq!(trace_store.get_by_level(Level::WARN).len(), 2);
+        assert!(trace_store.get_by_level(Level::WARN)[0].contains("Failed to add frame to channel"));
+
+        // The channel completes once the declared frames are received.
+        channel_bank.ingest_frame(crate::frame!(0xAA, 0, vec![0xDD; 50], false)).unwrap();
+        assert!(channel_bank.buffered_channels()[0].is_ready);
+        assert_eq!(channel_bank.read().unwrap().unwrap().len(), 100);
+    }
+
     #[test]
     fn test_ingest_empty_origin() {
         let mut mock = TestNextFrameProvider::new(vec![]);
@@ -415,6 +508,29 @@ mod tests {
         assert!(channel_bank.prev.reset);
     }
 
+    #[tokio::test]
+    async fn test_reset_from_downstream_stage() {
+        let mock = TestNextFrameProvider::new(vec![]);
+        let cfg = Arc::new(RollupConfig::default());
+        let mut channel_bank = ChannelBank::new(cfg, mock);
+        channel_bank.channels.insert([0xFF; 16], Channel::default());
+        channel_bank.channel_queue.push_back([0xFF; 16]);
+
+        // A reset from the batch queue leaves the channel bank's buffer intact.
+        let signal = ResetSignal::default().with_from_stage(StageId::BatchProvider).signal();
+        channel_bank.signal(signal).await.unwrap();
+        assert_eq!(channel_bank.channels.len(), 1);
+        assert_eq!(channel_bank.channel_queue.len(), 1);
+        assert!(!channel_bank.prev.reset);
+
+        // A reset from the channel bank clears it.
+        let signal = ResetSignal::default().with_from_stage(StageId::ChannelProvider).signal();
+        channel_bank.signal(signal).await.unwrap();
+        assert_eq!(channel_bank.channels.len(), 0);
+        assert_eq!(channel_bank.channel_queue.len(), 0);
+        assert!(channel_bank.prev.reset);
+    }
+
     #[test]
     fn test_ingest_invalid_frame() {
         let trace_store: TraceStorage = Default::default();
@@ -4,7 +4,7 @@ use crate::{
     errors::PipelineError,
     stages::BatchStreamProvider,
     traits::{OriginAdvancer, OriginProvider, SignalReceiver},
-    types::{PipelineResult, Signal},
+    types::{PipelineResult, Signal, StageId},
 };
 use alloc::{boxed::Box, sync::Arc};
 use alloy_primitives::Bytes;
@@ -146,6 +146,10 @@ where
     P: ChannelReaderProvider + OriginAdvancer + OriginProvider + SignalReceiver + Debug + Send,
 {
     async fn signal(&mut self, signal: Signal) -> PipelineResult<()> {
+        if !signal.applies_to(StageId::ChannelReader) {
+            return Ok(());
+        }
+
         match signal {
             Signal::FlushChannel => {
                 // Drop the current in-progress channel.
@@ -12,15 +12,16 @@
 //! [BatchQueue]: crate::stages::BatchQueue
 
 use crate::types::PipelineResult;
-use alloc::boxed::Box;
+use alloc::{boxed::Box, vec::Vec};
+use alloy_primitives::map::HashMap;
 use async_trait::async_trait;
-use maili_protocol::Frame;
+use maili_protocol::{ChannelId, Frame};
 
 pub(crate) mod channel_provider;
 pub use channel_provider::ChannelProvider;
 
 pub(crate) mod channel_bank;
-pub use channel_bank::ChannelBank;
+pub use channel_bank::{ChannelBank, ChannelSummary};
 
 pub(crate) mod channel_assembler;
 pub use channel_assembler::ChannelAssembler;
@@ -36,3 +37,52 @@ pub trait NextFrameProvider {
     /// [FrameQueue]: crate::stages::FrameQueue
     async fn next_frame(&mut self) -> PipelineResult<Frame>;
 }
+
+/// Groups the given [Frame]s by the [ChannelId] they belong to.
+///
+/// The channel ID of a [Frame] is its public `id` field. Within each group, the [Frame]s are kept
+/// in the order they were provided; no reordering or validation of frame numbers is performed.
+pub fn group_frames_by_channel(
+    frames: impl IntoIterator<Item = Frame>,
+) -> HashMap<ChannelId, Vec<Frame>> {
+    let mut groups: HashMap<ChannelId, Vec<Frame>> = HashMap::default();
+    for frame in frames {
+        groups.entry(frame.id).or_default().push(frame);
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_group_frames_by_channel() {
+        let frames = vec![
+            crate::frame!(0xAA, 0, vec![0x01], false),
+            crate::frame!(0xBB, 0, vec![0x02], false),
+            crate::frame!(0xAA, 1, vec![0x03], true),
+            crate::frame!(0xBB, 1, vec![0x04], true),
+        ];
+
+        let groups = group_frames_by_channel(frames);
+        assert_eq!(groups.len(), 2);
+
+        let a = &groups[&[0xAA; 16]];
+        assert_eq!(a.len(), 2);
+        assert_eq!(a[0].data, vec![0x01]);
+        assert_eq!(a[1].data, vec![0x03]);
+        assert!(a[1].is_last);
+
+        let b = &groups[&[0xBB; 16]];
+        assert_eq!(b.len(), 2);
+        assert_eq!(b[0].data, vec![0x02]);
+        assert_eq!(b[1].data, vec![0x04]);
+    }
+
+    #[test]
+    fn test_group_frames_by_channel_empty() {
+        assert!(group_frames_by_channel(Vec::new()).is_empty());
+    }
+}
@@ -1,10 +1,10 @@
 //! This module contains the [FrameQueue] stage of the derivation pipeline.
 
 use crate::{
-    errors::PipelineError,
+    errors::{FrameParseError, PipelineError},
     stages::NextFrameProvider,
     traits::{OriginAdvancer, OriginProvider, SignalReceiver},
-    types::{PipelineResult, Signal},
+    types::{PipelineResult, Signal, StageId},
 };
 use alloc::{boxed::Box, collections::VecDeque, sync::Arc};
 use alloy_primitives::Bytes;
@@ -25,6 +25,23 @@ pub trait FrameQueueProvider {
     async fn next_data(&mut self) -> PipelineResult<Self::Item>;
 }
 
+/// Parses a single [Frame] from the front of `data` with [Frame::decode], additionally rejecting
+/// an `is_last` flag other than `0` or `1`.
+///
+/// Unlike the [FrameQueue], which parses every frame of a batcher transaction at once, this
+/// parses exactly one frame and leaves any trailing bytes untouched.
+///
+/// ## Returns
+/// - `Ok((frame, consumed))`: The parsed [Frame] and the number of bytes it occupied.
+/// - `Err(_)`: The frame could not be decoded, or its `is_last` flag is invalid.
+pub fn parse_frame(data: &[u8]) -> Result<(Frame, usize), FrameParseError> {
+    let (consumed, frame) = Frame::decode(data)?;
+    match data[consumed - 1] {
+        0 | 1 => Ok((frame, consumed)),
+        flag => Err(FrameParseError::InvalidIsLast(flag)),
+    }
+}
+
 /// The [FrameQueue] stage of the derivation pipeline.
 /// This stage takes the output of the [L1Retrieval] stage and parses it into frames.
 ///
@@ -184,6 +201,10 @@ where
     P: FrameQueueProvider + OriginAdvancer + OriginProvider + SignalReceiver + Send + Debug,
 {
     async fn signal(&mut self, signal: Signal) -> PipelineResult<()> {
+        if !signal.applies_to(StageId::FrameQueue) {
+            return Ok(());
+        }
+
         self.prev.signal(signal).await?;
         self.queue = VecDeque::default();
         Ok(())
@@ -195,6 +216,67 @@ pub(crate) mod tests {
     use super::*;
     use crate::{test_utils::TestFrameQueueProvider, types::ResetSignal};
     use alloc::vec;
+    use maili_protocol::MAX_FRAME_LEN;
+
+    /// The encoded length of a [Frame] with no data.
+    const BASE_FRAME_LEN: usize = 16 + 2 + 4 + 1;
+
+    #[test]
+    fn test_parse_frame_valid() {
+        let frame = crate::frame!(0xFF, 3, vec![0xDD; 50], true);
+        let encoded = frame.encode();
+        assert_eq!(parse_frame(&encoded), Ok((frame, BASE_FRAME_LEN + 50)));
+    }
+
+    #[test]
+    fn test_parse_frame_truncated() {
+        let encoded = crate::frame!(0xFF, 0, vec![0xDD; 50], false).encode();
+        assert!(matches!(
+            parse_frame(&encoded[..encoded.len() - 2]),
+            Err(FrameParseError::Decode(_))
+        ));
+        assert!(matches!(parse_frame(&encoded[..10]), Err(FrameParseError::Decode(_))));
+
+        // A length prefix beyond the buffer is rejected without reading past it.
+        let mut encoded = encoded;
+        encoded[18..22].copy_from_slice(&(MAX_FRAME_LEN as u32).to_be_bytes());
+        assert!(matches!(parse_frame(&encoded), Err(FrameParseError::Decode(_))));
+    }
+
+    #[test]
+    fn test_parse_frame_too_large() {
+        let frame = crate::frame!(0xFF, 0, vec![0xDD; MAX_FRAME_LEN + 1], true);
+        assert!(matches!(parse_frame(&frame.encode()), Err(FrameParseError::Decode(_))));
+
+        // A length prefix exceeding the maximum frame length is rejected.
+        let mut encoded = crate::frame!(0xFF, 0, vec![0xDD; 50], false).encode();
+        encoded[18..22].copy_from_slice(&u32::MAX.to_be_bytes());
+        assert!(matches!(parse_frame(&encoded), Err(FrameParseError::Decode(_))));
+
+        // A frame of exactly the maximum length is accepted.
+        let frame = crate::frame!(0xFF, 0, vec![0xDD; MAX_FRAME_LEN], true);
+        assert_eq!(parse_frame(&frame.encode()), Ok((frame, BASE_FRAME_LEN + MAX_FRAME_LEN)));
+    }
+
+    #[test]
+    fn test_parse_frame_trailing_data() {
+        let frame = crate::frame!(0xFF, 1, vec![0xDD; 50], false);
+        let mut encoded = frame.encode();
+        let frame_len = encoded.len();
+        encoded.extend_from_slice(&[0xAA; 10]);
+
+        let (parsed, consumed) = parse_frame(&encoded).unwrap();
+        assert_eq!(parsed, frame);
+        assert_eq!(consumed, frame_len);
+        assert_eq!(&encoded[consumed..], &[0xAA; 10]);
+    }
+
+    #[test]
+    fn test_parse_frame_invalid_is_last() {
+        let mut encoded = crate::frame!(0xFF, 0, vec![0xDD; 4], false).encode();
+        *encoded.last_mut().unwrap() = 2;
+        assert_eq!(parse_frame(&encoded), Err(FrameParseError::InvalidIsLast(2)));
+    }
 
     #[tokio::test]
     async fn test_frame_queue_reset() {
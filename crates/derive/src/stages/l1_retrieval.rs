@@ -4,7 +4,7 @@ use crate::{
     errors::{PipelineError, PipelineErrorKind},
     stages::FrameQueueProvider,
     traits::{DataAvailabilityProvider, OriginAdvancer, OriginProvider, SignalReceiver},
-    types::{ActivationSignal, PipelineResult, ResetSignal, Signal},
+    types::{ActivationSignal, PipelineResult, ResetSignal, Signal, StageId},
 };
 use alloc::boxed::Box;
 use alloy_primitives::Address;
@@ -122,6 +122,10 @@ where
     P: L1RetrievalProvider + OriginAdvancer + OriginProvider + SignalReceiver + Send,
 {
     async fn signal(&mut self, signal: Signal) -> PipelineResult<()> {
+        if !signal.applies_to(StageId::L1Retrieval) {
+            return Ok(());
+        }
+
         self.prev.signal(signal).await?;
         match signal {
             Signal::Reset(ResetSignal { l1_origin, .. }) |
@@ -4,7 +4,10 @@ use crate::{
     errors::{PipelineError, ResetError},
     stages::L1RetrievalProvider,
     traits::{ChainProvider, OriginAdvancer, OriginProvider, SignalReceiver},
-    types::{ActivationSignal, PipelineResult, ResetSignal, Signal},
+    types::{
+        update_system_config_with_receipts, ActivationSignal, PipelineResult, ResetSignal, Signal,
+        StageId, UnknownUpdateTypePolicy,
+    },
 };
 use alloc::{boxed::Box, sync::Arc};
 use alloy_primitives::Address;
@@ -31,6 +34,8 @@ pub struct L1Traversal<Provider: ChainProvider> {
     pub system_config: SystemConfig,
     /// A reference to the rollup config.
     pub rollup_config: Arc<RollupConfig>,
+    /// The policy for system config update logs with an unknown update type.
+    pub unknown_update_policy: UnknownUpdateTypePolicy,
 }
 
 #[async_trait]
@@ -58,8 +63,16 @@ impl<F: ChainProvider> L1Traversal<F> {
             done: false,
             system_config: SystemConfig::default(),
             rollup_config: cfg,
+            unknown_update_policy: UnknownUpdateTypePolicy::default(),
         }
     }
+
+    /// Sets the [UnknownUpdateTypePolicy] for system config update logs with an unknown update
+    /// type.
+    pub const fn with_unknown_update_policy(mut self, policy: UnknownUpdateTypePolicy) -> Self {
+        self.unknown_update_policy = policy;
+        self
+    }
 }
 
 #[async_trait]
@@ -89,10 +102,12 @@ impl<F: ChainProvider + Send> OriginAdvancer for L1Traversal<F> {
         let receipts =
             self.data_source.receipts_by_hash(next_l1_origin.hash).await.map_err(Into::into)?;
 
-        if let Err(e) = self.system_config.update_with_receipts(
+        if let Err(e) = update_system_config_with_receipts(
+            &mut self.system_config,
             receipts.as_slice(),
             self.rollup_config.l1_system_config_address,
             self.rollup_config.is_ecotone_active(next_l1_origin.timestamp),
+            self.unknown_update_policy,
         ) {
             return Err(PipelineError::SystemConfigUpdate(e).crit());
         }
@@ -123,6 +138,10 @@ impl<F: ChainProvider> OriginProvider for L1Traversal<F> {
 #[async_trait]
 impl<F: ChainProvider + Send> SignalReceiver for L1Traversal<F> {
     async fn signal(&mut self, signal: Signal) -> PipelineResult<()> {
+        if !signal.applies_to(StageId::L1Traversal) {
+            return Ok(());
+        }
+
         match signal {
             Signal::Reset(ResetSignal { l1_origin, system_config, .. }) |
             Signal::Activation(ActivationSignal { l1_origin, system_config, .. }) => {
@@ -140,11 +159,16 @@ impl<F: ChainProvider + Send> SignalReceiver for L1Traversal<F> {
 #[cfg(test)]
 pub(crate) mod tests {
     use super::*;
-    use crate::{errors::PipelineErrorKind, test_utils::TestChainProvider};
+    use crate::{
+        errors::PipelineErrorKind,
+        test_utils::{CollectingLayer, TestChainProvider, TraceStorage},
+    };
     use alloc::vec;
     use alloy_consensus::Receipt;
     use alloy_primitives::{address, b256, hex, Bytes, Log, LogData, B256};
     use maili_genesis::{CONFIG_UPDATE_EVENT_VERSION_0, CONFIG_UPDATE_TOPIC};
+    use tracing::Level;
+    use tracing_subscriber::layer::SubscriberExt;
 
     const L1_SYS_CONFIG_ADDR: Address = address!("1337000000000000000000000000000000000000");
 
@@ -325,4 +349,46 @@ pub(crate) mod tests {
         let expected = address!("000000000000000000000000000000000000bEEF");
         assert_eq!(traversal.system_config.batcher_address, expected);
     }
+
+    #[tokio::test]
+    async fn test_l1_traversal_unknown_update_type_policy() {
+        let mut unknown_update_log = new_update_batcher_log();
+        unknown_update_log.data = LogData::new_unchecked(
+            vec![CONFIG_UPDATE_TOPIC, CONFIG_UPDATE_EVENT_VERSION_0, B256::with_last_byte(0xFF)],
+            unknown_update_log.data.data.clone(),
+        );
+        let receipt = Receipt {
+            status: alloy_consensus::Eip658Value::Eip658(true),
+            logs: vec![unknown_update_log, new_update_batcher_log()],
+            ..Receipt::default()
+        };
+        let expected = address!("000000000000000000000000000000000000bEEF");
+
+        for (policy, warnings) in
+            [(UnknownUpdateTypePolicy::Ignore, 0), (UnknownUpdateTypePolicy::Warn, 1)]
+        {
+            let trace_store: TraceStorage = Default::default();
+            let layer = CollectingLayer::new(trace_store.clone());
+            let subscriber = tracing_subscriber::Registry::default().with(layer);
+            let _guard = tracing::subscriber::set_default(subscriber);
+
+            let blocks = vec![BlockInfo::default(), BlockInfo::default()];
+            let mut traversal = new_test_traversal(blocks, vec![receipt.clone()])
+                .with_unknown_update_policy(policy);
+            assert!(traversal.advance_origin().await.is_ok());
+
+            // The unknown update is skipped, and the following update is still applied.
+            assert_eq!(traversal.system_config.batcher_address, expected);
+            assert_eq!(trace_store.get_by_level(Level::WARN).len(), warnings);
+        }
+
+        let blocks = vec![BlockInfo::default(), BlockInfo::default()];
+        let mut traversal = new_test_traversal(blocks, vec![receipt])
+            .with_unknown_update_policy(UnknownUpdateTypePolicy::Error);
+        assert!(matches!(
+            traversal.advance_origin().await.unwrap_err(),
+            PipelineErrorKind::Critical(PipelineError::SystemConfigUpdate(_))
+        ));
+        assert_eq!(traversal.system_config.batcher_address, Address::ZERO);
+    }
 }
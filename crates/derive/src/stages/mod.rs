@@ -22,12 +22,12 @@ mod l1_retrieval;
 pub use l1_retrieval::{L1Retrieval, L1RetrievalProvider};
 
 mod frame_queue;
-pub use frame_queue::{FrameQueue, FrameQueueProvider};
+pub use frame_queue::{parse_frame, FrameQueue, FrameQueueProvider};
 
 mod channel;
 pub use channel::{
-    ChannelAssembler, ChannelBank, ChannelProvider, ChannelReader, ChannelReaderProvider,
-    NextFrameProvider,
+    group_frames_by_channel, ChannelAssembler, ChannelBank, ChannelProvider, ChannelReader,
+    ChannelReaderProvider, ChannelSummary, NextFrameProvider,
 };
 
 mod batch;
@@ -42,9 +42,11 @@ impl BlobProvider for TestBlobProvider {
         }
         let mut blobs = Vec::new();
         for blob_hash in blob_hashes {
-            if let Some(data) = self.blobs.get(&blob_hash.hash) {
-                blobs.push(Box::new(*data));
-            }
+            let data = self
+                .blobs
+                .get(&blob_hash.hash)
+                .ok_or(BlobProviderError::BlobNotFound(blob_hash.hash))?;
+            blobs.push(Box::new(*data));
         }
         Ok(blobs)
     }
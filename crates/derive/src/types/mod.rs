@@ -4,4 +4,7 @@ mod results;
 pub use results::{PipelineResult, StepResult};
 
 mod signals;
-pub use signals::{ActivationSignal, ResetSignal, Signal};
+pub use signals::{ActivationSignal, ResetSignal, Signal, StageId};
+
+mod system_config;
+pub use system_config::{update_system_config_with_receipts, UnknownUpdateTypePolicy};
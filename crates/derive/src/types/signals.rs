@@ -28,6 +28,59 @@ impl Signal {
             Self::FlushChannel => Self::FlushChannel,
         }
     }
+
+    /// Returns `true` if the signal applies to the given stage.
+    ///
+    /// A [ResetSignal] with a [ResetSignal::from_stage] only applies to that stage and the stages
+    /// downstream of it. All other signals apply to every stage.
+    pub const fn applies_to(&self, stage: StageId) -> bool {
+        match self {
+            Self::Reset(ResetSignal { from_stage: Some(from_stage), .. }) => {
+                *from_stage as u8 <= stage as u8
+            }
+            _ => true,
+        }
+    }
+}
+
+/// Identifies a stage of the derivation pipeline, ordered from the most upstream stage to the
+/// most downstream stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum StageId {
+    /// The [L1Traversal] stage.
+    ///
+    /// [L1Traversal]: crate::stages::L1Traversal
+    L1Traversal,
+    /// The [L1Retrieval] stage.
+    ///
+    /// [L1Retrieval]: crate::stages::L1Retrieval
+    L1Retrieval,
+    /// The [FrameQueue] stage.
+    ///
+    /// [FrameQueue]: crate::stages::FrameQueue
+    FrameQueue,
+    /// The [ChannelBank] or [ChannelAssembler] stage.
+    ///
+    /// [ChannelBank]: crate::stages::ChannelBank
+    /// [ChannelAssembler]: crate::stages::ChannelAssembler
+    ChannelProvider,
+    /// The [ChannelReader] stage.
+    ///
+    /// [ChannelReader]: crate::stages::ChannelReader
+    ChannelReader,
+    /// The [BatchStream] stage.
+    ///
+    /// [BatchStream]: crate::stages::BatchStream
+    BatchStream,
+    /// The [BatchQueue] or [BatchValidator] stage.
+    ///
+    /// [BatchQueue]: crate::stages::BatchQueue
+    /// [BatchValidator]: crate::stages::BatchValidator
+    BatchProvider,
+    /// The [AttributesQueue] stage.
+    ///
+    /// [AttributesQueue]: crate::stages::AttributesQueue
+    AttributesQueue,
 }
 
 /// A pipeline reset signal.
@@ -39,6 +92,10 @@ pub struct ResetSignal {
     pub l1_origin: BlockInfo,
     /// The optional [SystemConfig] to reset with.
     pub system_config: Option<SystemConfig>,
+    /// The optional stage to reset from. If set, only this stage and the stages downstream of it
+    /// are reset, and the buffers of the upstream stages are preserved. If `None`, all stages are
+    /// reset.
+    pub from_stage: Option<StageId>,
 }
 
 impl ResetSignal {
@@ -51,6 +108,11 @@ impl ResetSignal {
     pub const fn with_system_config(self, system_config: SystemConfig) -> Self {
         Self { system_config: Some(system_config), ..self }
     }
+
+    /// Sets the stage to reset from. Only this stage and the stages downstream of it are reset.
+    pub const fn with_from_stage(self, from_stage: StageId) -> Self {
+        Self { from_stage: Some(from_stage), ..self }
+    }
 }
 
 /// A pipeline hardfork activation signal.
@@ -110,4 +172,20 @@ mod tests {
 
         assert_eq!(Signal::FlushChannel.with_system_config(system_config), Signal::FlushChannel);
     }
+
+    #[test]
+    fn test_signal_applies_to() {
+        let signal = ResetSignal::default().signal();
+        assert!(signal.applies_to(StageId::L1Traversal));
+        assert!(signal.applies_to(StageId::AttributesQueue));
+
+        let signal = ResetSignal::default().with_from_stage(StageId::BatchProvider).signal();
+        assert!(!signal.applies_to(StageId::ChannelProvider));
+        assert!(!signal.applies_to(StageId::BatchStream));
+        assert!(signal.applies_to(StageId::BatchProvider));
+        assert!(signal.applies_to(StageId::AttributesQueue));
+
+        assert!(Signal::FlushChannel.applies_to(StageId::L1Traversal));
+        assert!(ActivationSignal::default().signal().applies_to(StageId::L1Traversal));
+    }
 }
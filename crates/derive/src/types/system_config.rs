@@ -0,0 +1,74 @@
+//! Contains the policy for handling system config update logs with an unknown update type.
+
+use alloc::vec;
+use alloy_consensus::Receipt;
+use alloy_primitives::Address;
+use maili_genesis::{LogProcessingError, SystemConfig, SystemConfigUpdateError};
+
+/// The policy for handling system config update logs with an update type that is not recognized,
+/// such as those introduced by a future network upgrade.
+///
+/// Defaults to [UnknownUpdateTypePolicy::Error], matching the reference implementation. Skipping
+/// unknown updates diverges from consensus, so [UnknownUpdateTypePolicy::Ignore] and
+/// [UnknownUpdateTypePolicy::Warn] must never be used when deriving within a fault proof.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum UnknownUpdateTypePolicy {
+    /// Silently skip the update.
+    Ignore,
+    /// Skip the update, emitting a warning.
+    Warn,
+    /// Fail the system config update.
+    #[default]
+    Error,
+}
+
+/// Updates the [SystemConfig] with the config update logs in the given receipts, handling logs
+/// with an unknown update type according to the [UnknownUpdateTypePolicy].
+///
+/// Under [UnknownUpdateTypePolicy::Error], this is equivalent to
+/// [SystemConfig::update_with_receipts]. Otherwise, the logs are applied one at a time so that
+/// updates following a skipped log are still applied.
+pub fn update_system_config_with_receipts(
+    system_config: &mut SystemConfig,
+    receipts: &[Receipt],
+    l1_system_config_address: Address,
+    ecotone_active: bool,
+    policy: UnknownUpdateTypePolicy,
+) -> Result<(), SystemConfigUpdateError> {
+    if policy == UnknownUpdateTypePolicy::Error {
+        return system_config.update_with_receipts(
+            receipts,
+            l1_system_config_address,
+            ecotone_active,
+        );
+    }
+
+    for receipt in receipts {
+        for log in receipt.logs.iter() {
+            let receipt = Receipt {
+                status: receipt.status,
+                cumulative_gas_used: receipt.cumulative_gas_used,
+                logs: vec![log.clone()],
+            };
+            match system_config.update_with_receipts(
+                &[receipt],
+                l1_system_config_address,
+                ecotone_active,
+            ) {
+                Ok(()) => {}
+                Err(SystemConfigUpdateError::LogProcessing(
+                    LogProcessingError::InvalidSystemConfigUpdateType(update_type),
+                )) => {
+                    if policy == UnknownUpdateTypePolicy::Warn {
+                        warn!(
+                            target: "system-config",
+                            "Skipping system config update with unknown type {update_type}"
+                        );
+                    }
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,93 @@
+//! Conversions between L2 block numbers and timestamps.
+
+use maili_genesis::RollupConfig;
+
+/// Returns the timestamp of the L2 block with the given number under the [RollupConfig].
+///
+/// ## Takes
+/// - `config`: The [RollupConfig] holding the L2 genesis and block time.
+/// - `number`: The number of the L2 block.
+///
+/// ## Returns
+/// - `Some(timestamp)`: The timestamp of the block.
+/// - `None`: If the block precedes the L2 genesis, or its timestamp overflows.
+pub fn timestamp_for_block(config: &RollupConfig, number: u64) -> Option<u64> {
+    let blocks = number.checked_sub(config.genesis.l2.number)?;
+    blocks.checked_mul(config.block_time)?.checked_add(config.genesis.l2_time)
+}
+
+/// Returns the number of the L2 block at the given timestamp under the [RollupConfig]. This is
+/// the inverse of [timestamp_for_block].
+///
+/// Timestamps between two blocks resolve to the earlier block.
+///
+/// ## Takes
+/// - `config`: The [RollupConfig] holding the L2 genesis and block time.
+/// - `timestamp`: The timestamp to resolve.
+///
+/// ## Returns
+/// - `Some(number)`: The number of the block at the timestamp.
+/// - `None`: If the timestamp precedes the L2 genesis, the block time is zero, or the block number
+///   overflows.
+pub fn block_for_timestamp(config: &RollupConfig, timestamp: u64) -> Option<u64> {
+    let elapsed = timestamp.checked_sub(config.genesis.l2_time)?;
+    elapsed.checked_div(config.block_time)?.checked_add(config.genesis.l2.number)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloy_eips::BlockNumHash;
+    use maili_genesis::ChainGenesis;
+
+    fn config() -> RollupConfig {
+        RollupConfig {
+            genesis: ChainGenesis {
+                l2: BlockNumHash { number: 100, ..Default::default() },
+                l2_time: 1_000,
+                ..Default::default()
+            },
+            block_time: 2,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_block_time_conversions() {
+        let config = config();
+
+        // The genesis block.
+        assert_eq!(timestamp_for_block(&config, 100), Some(1_000));
+        assert_eq!(block_for_timestamp(&config, 1_000), Some(100));
+
+        // A mid-chain block, with timestamps between blocks resolving to the earlier block.
+        assert_eq!(timestamp_for_block(&config, 150), Some(1_100));
+        assert_eq!(block_for_timestamp(&config, 1_100), Some(150));
+        assert_eq!(block_for_timestamp(&config, 1_101), Some(150));
+    }
+
+    #[test]
+    fn test_block_time_conversions_out_of_range() {
+        let config = config();
+
+        // Before the L2 genesis.
+        assert_eq!(timestamp_for_block(&config, 99), None);
+        assert_eq!(block_for_timestamp(&config, 999), None);
+
+        // Overflowing results.
+        assert_eq!(timestamp_for_block(&config, u64::MAX), None);
+        let genesis_at_max = RollupConfig {
+            genesis: ChainGenesis {
+                l2: BlockNumHash { number: u64::MAX, ..Default::default() },
+                ..Default::default()
+            },
+            block_time: 1,
+            ..Default::default()
+        };
+        assert_eq!(block_for_timestamp(&genesis_at_max, 1), None);
+
+        // Without a block time, timestamps cannot be resolved to blocks.
+        let no_block_time = RollupConfig { block_time: 0, ..config };
+        assert_eq!(block_for_timestamp(&no_block_time, 1_000), None);
+    }
+}
@@ -1,6 +1,9 @@
 //! The driver of the kona derivation pipeline.
 
-use crate::{DriverError, DriverPipeline, DriverResult, Executor, PipelineCursor, TipCursor};
+use crate::{
+    block_for_timestamp, DriverError, DriverPipeline, DriverResult, Executor, PipelineCursor,
+    TipCursor,
+};
 use alloc::{sync::Arc, vec::Vec};
 use alloy_consensus::BlockBody;
 use alloy_primitives::{Bytes, B256};
@@ -12,12 +15,24 @@ use kona_derive::{
     types::Signal,
 };
 use kona_executor::ExecutionArtifacts;
+use kona_interop::{OutputRootWithChain, SuperRoot};
 use maili_genesis::RollupConfig;
-use maili_protocol::L2BlockInfo;
+use maili_protocol::{BatchValidationProvider, L2BlockInfo};
 use maili_rpc::OpAttributesWithParent;
 use op_alloy_consensus::{OpBlock, OpTxEnvelope, OpTxType};
 use spin::RwLock;
 
+/// The outcome of [Driver::advance_to_target], once the target block has been reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdvanceOutcome {
+    /// The [L2BlockInfo] of the safe head at the target.
+    pub safe_head: L2BlockInfo,
+    /// The output root of the safe head.
+    pub output_root: B256,
+    /// Whether the pipeline still had buffered attributes when the target was reached.
+    pub has_buffered_attributes: bool,
+}
+
 /// The Rollup Driver entrypoint.
 #[derive(Debug)]
 pub struct Driver<E, DP, P>
@@ -60,6 +75,101 @@ where
         self.executor.wait_until_ready().await;
     }
 
+    /// Advances each of the given drivers to the L2 block at `timestamp` on its chain, and returns
+    /// the [SuperRoot] committing to the output roots of every chain at that timestamp.
+    ///
+    /// ## Takes
+    /// - `drivers`: The rollup configuration and [Driver] of each chain in the dependency set.
+    /// - `timestamp`: The target timestamp of the superchain snapshot.
+    ///
+    /// ## Returns
+    /// - `Ok(super_root)` - The [SuperRoot] at `timestamp`, with one output root per chain.
+    /// - `Err(e)` - An error if any of the chains could not be advanced to the target, has a block
+    ///   time of zero, starts after `timestamp`, or already has a safe head past the target.
+    pub async fn advance_super_root(
+        drivers: &mut [(&RollupConfig, Self)],
+        timestamp: u64,
+    ) -> DriverResult<SuperRoot, E::Error> {
+        let mut output_roots = Vec::with_capacity(drivers.len());
+        for (cfg, driver) in drivers.iter_mut() {
+            if cfg.block_time == 0 {
+                return Err(DriverError::ZeroBlockTime(cfg.l2_chain_id));
+            }
+
+            // Translate the target timestamp to an L2 block number on the driver's chain.
+            let target =
+                block_for_timestamp(cfg, timestamp).ok_or(DriverError::TimestampBeforeGenesis {
+                    chain_id: cfg.l2_chain_id,
+                    timestamp,
+                })?;
+
+            // The output root of a block behind the safe head is not retained by the driver.
+            let safe_head = driver.cursor.read().l2_safe_head().block_info.number;
+            if safe_head > target {
+                return Err(DriverError::SafeHeadPastTarget {
+                    chain_id: cfg.l2_chain_id,
+                    safe_head,
+                    target,
+                });
+            }
+
+            let outcome = driver.advance_to_target(cfg, Some(target)).await?;
+            output_roots.push(OutputRootWithChain::new(cfg.l2_chain_id, outcome.output_root));
+        }
+
+        Ok(SuperRoot::new(timestamp, output_roots))
+    }
+
+    /// Derives the payload attributes of the L2 blocks up to the target block number, without
+    /// executing them.
+    ///
+    /// As the derived blocks are not executed, their hashes are unknown to the driver. Instead,
+    /// the [L2BlockInfo] of each derived block is read from the canonical L2 chain through the
+    /// given [BatchValidationProvider], which makes this suitable for auditing the batches
+    /// submitted for an existing chain. The [PipelineCursor] is not advanced.
+    ///
+    /// ## Takes
+    /// - `l2_provider`: The provider of the canonical L2 chain.
+    /// - `target`: The target block number.
+    ///
+    /// ## Returns
+    /// - `Ok(attributes)` - The derived [OpAttributesWithParent], in order. If the data source is
+    ///   exhausted before the target is reached, only the attributes derived so far are returned.
+    /// - `Err(e)` - An error if derivation failed, or a derived block is missing from the L2 chain.
+    pub async fn derive_only<L>(
+        &mut self,
+        l2_provider: &mut L,
+        target: u64,
+    ) -> DriverResult<Vec<OpAttributesWithParent>, E::Error>
+    where
+        L: BatchValidationProvider + Send,
+        L::Error: Into<PipelineErrorKind>,
+    {
+        let mut l2_safe_head = *self.cursor.read().l2_safe_head();
+        let mut derived = Vec::new();
+        while l2_safe_head.block_info.number < target {
+            let attributes = match self.pipeline.produce_payload(l2_safe_head).await {
+                Ok(attributes) => attributes,
+                Err(PipelineErrorKind::Critical(PipelineError::EndOfSource)) => {
+                    warn!(target: "client", "Exhausted data source; Halting derivation at L2 block #{}", l2_safe_head.block_info.number);
+                    break;
+                }
+                Err(e) => {
+                    error!(target: "client", "Failed to produce payload: {:?}", e);
+                    return Err(DriverError::Pipeline(e));
+                }
+            };
+            derived.push(attributes);
+
+            l2_safe_head = l2_provider
+                .l2_block_info_by_number(l2_safe_head.block_info.number + 1)
+                .await
+                .map_err(|e| DriverError::Pipeline(e.into()))?;
+        }
+
+        Ok(derived)
+    }
+
     /// Advances the derivation pipeline to the target block number.
     ///
     /// ## Takes
@@ -67,22 +177,31 @@ where
     /// - `target`: The target block number.
     ///
     /// ## Returns
-    /// - `Ok((l2_safe_head, output_root))` - A tuple containing the [L2BlockInfo] of the produced
-    ///   block and the output root.
+    /// - `Ok(outcome)` - The [AdvanceOutcome] holding the [L2BlockInfo] of the produced block, its
+    ///   output root, and whether the pipeline still had buffered attributes when the target was
+    ///   reached.
     /// - `Err(e)` - An error if the block could not be produced.
     pub async fn advance_to_target(
         &mut self,
         cfg: &RollupConfig,
         mut target: Option<u64>,
-    ) -> DriverResult<(L2BlockInfo, B256), E::Error> {
+    ) -> DriverResult<AdvanceOutcome, E::Error> {
         loop {
             // Check if we have reached the target block number.
             let pipeline_cursor = self.cursor.read();
             let tip_cursor = pipeline_cursor.tip();
             if let Some(tb) = target {
                 if tip_cursor.l2_safe_head.block_info.number >= tb {
-                    info!(target: "client", "Derivation complete, reached L2 safe head.");
-                    return Ok((tip_cursor.l2_safe_head, tip_cursor.l2_safe_head_output_root));
+                    let has_buffered_attributes = self.pipeline.peek().is_some();
+                    info!(
+                        target: "client",
+                        "Derivation complete, reached L2 safe head. Buffered attributes remaining: {has_buffered_attributes}"
+                    );
+                    return Ok(AdvanceOutcome {
+                        safe_head: tip_cursor.l2_safe_head,
+                        output_root: tip_cursor.l2_safe_head_output_root,
+                        has_buffered_attributes,
+                    });
                 }
             }
 
@@ -195,3 +314,437 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::boxed::Box;
+    use alloy_consensus::{Header, Sealable, Sealed};
+    use alloy_eips::eip2718::Encodable2718;
+    use async_trait::async_trait;
+    use kona_derive::{
+        traits::OriginProvider,
+        types::{PipelineResult, StepResult},
+    };
+    use maili_genesis::{ChainGenesis, SystemConfig};
+    use maili_protocol::{BlockInfo, L1BlockInfoTx};
+    use op_alloy_rpc_types_engine::OpPayloadAttributes;
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("mock executor error")]
+    struct MockExecutorError;
+
+    /// An executor that is never expected to be called.
+    #[derive(Debug)]
+    struct MockExecutor;
+
+    #[async_trait]
+    impl Executor for MockExecutor {
+        type Error = MockExecutorError;
+
+        async fn wait_until_ready(&mut self) {}
+
+        fn update_safe_head(&mut self, _: Sealed<Header>) {}
+
+        async fn execute_payload(
+            &mut self,
+            _: OpPayloadAttributes,
+        ) -> Result<ExecutionArtifacts, Self::Error> {
+            Err(MockExecutorError)
+        }
+
+        fn compute_output_root(&mut self) -> Result<B256, Self::Error> {
+            Err(MockExecutorError)
+        }
+    }
+
+    /// A pipeline holding an optional set of buffered attributes.
+    #[derive(Debug, Default)]
+    struct MockPipeline {
+        rollup_config: RollupConfig,
+        buffered: Option<OpAttributesWithParent>,
+    }
+
+    impl OriginProvider for MockPipeline {
+        fn origin(&self) -> Option<BlockInfo> {
+            None
+        }
+    }
+
+    impl Iterator for MockPipeline {
+        type Item = OpAttributesWithParent;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.buffered.take()
+        }
+    }
+
+    #[async_trait]
+    impl SignalReceiver for MockPipeline {
+        async fn signal(&mut self, _: Signal) -> PipelineResult<()> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl Pipeline for MockPipeline {
+        fn peek(&self) -> Option<&OpAttributesWithParent> {
+            self.buffered.as_ref()
+        }
+
+        async fn step(&mut self, _: L2BlockInfo) -> StepResult {
+            StepResult::StepFailed(PipelineError::EndOfSource.crit())
+        }
+
+        fn rollup_config(&self) -> &RollupConfig {
+            &self.rollup_config
+        }
+
+        async fn system_config_by_number(
+            &mut self,
+            _: u64,
+        ) -> Result<SystemConfig, PipelineErrorKind> {
+            Ok(SystemConfig::default())
+        }
+    }
+
+    impl DriverPipeline<Self> for MockPipeline {
+        fn flush(&mut self) {}
+    }
+
+    fn mock_driver(
+        safe_head_number: u64,
+        buffered: Option<OpAttributesWithParent>,
+    ) -> Driver<MockExecutor, MockPipeline, MockPipeline> {
+        mock_driver_with_output_root(safe_head_number, buffered, B256::ZERO)
+    }
+
+    fn mock_driver_with_output_root(
+        safe_head_number: u64,
+        buffered: Option<OpAttributesWithParent>,
+        output_root: B256,
+    ) -> Driver<MockExecutor, MockPipeline, MockPipeline> {
+        let safe_head = L2BlockInfo {
+            block_info: BlockInfo { number: safe_head_number, ..Default::default() },
+            ..Default::default()
+        };
+        let mut cursor = PipelineCursor::new(0, BlockInfo::default());
+        cursor.advance(
+            BlockInfo::default(),
+            TipCursor::new(safe_head, Header::default().seal_slow(), output_root),
+        );
+
+        let pipeline = MockPipeline { buffered, ..Default::default() };
+        Driver::new(Arc::new(RwLock::new(cursor)), MockExecutor, pipeline)
+    }
+
+    #[tokio::test]
+    async fn test_advance_to_target_buffered_attributes() {
+        let buffered = OpAttributesWithParent {
+            attributes: OpPayloadAttributes::default(),
+            parent: L2BlockInfo::default(),
+            is_last_in_span: false,
+        };
+        let mut driver = mock_driver(10, Some(buffered));
+
+        let outcome = driver.advance_to_target(&RollupConfig::default(), Some(10)).await.unwrap();
+        assert_eq!(outcome.safe_head.block_info.number, 10);
+        assert!(outcome.has_buffered_attributes);
+    }
+
+    #[tokio::test]
+    async fn test_advance_to_target_no_buffered_attributes() {
+        let mut driver = mock_driver(10, None);
+
+        let outcome = driver.advance_to_target(&RollupConfig::default(), Some(10)).await.unwrap();
+        assert_eq!(outcome.safe_head.block_info.number, 10);
+        assert!(!outcome.has_buffered_attributes);
+    }
+
+    #[tokio::test]
+    async fn test_advance_super_root() {
+        let cfg_a = RollupConfig { l2_chain_id: 901, block_time: 2, ..Default::default() };
+        let cfg_b = RollupConfig { l2_chain_id: 900, block_time: 1, ..Default::default() };
+        let (root_a, root_b) = (B256::repeat_byte(0xAA), B256::repeat_byte(0xBB));
+
+        // Both chains are already at the block at timestamp 10.
+        let mut drivers = [
+            (&cfg_a, mock_driver_with_output_root(5, None, root_a)),
+            (&cfg_b, mock_driver_with_output_root(10, None, root_b)),
+        ];
+        let super_root = Driver::advance_super_root(&mut drivers, 10).await.unwrap();
+
+        let expected = SuperRoot::new(
+            10,
+            vec![OutputRootWithChain::new(900, root_b), OutputRootWithChain::new(901, root_a)],
+        );
+        assert_eq!(super_root.output_roots, expected.output_roots);
+        assert_eq!(super_root.hash(), expected.hash());
+    }
+
+    #[tokio::test]
+    async fn test_advance_super_root_steps_pipeline() {
+        let cfg_a = RollupConfig { l2_chain_id: 901, block_time: 2, ..Default::default() };
+        let cfg_b = RollupConfig { l2_chain_id: 900, block_time: 1, ..Default::default() };
+        let (root_a, root_b) = (B256::repeat_byte(0xAA), B256::repeat_byte(0xBB));
+        let executor = |output_root| RecordingExecutor { output_root, ..Default::default() };
+
+        // Both chains start at genesis, and derive the blocks up to timestamp 2: block 1 on the
+        // first chain, and blocks 1 and 2 on the second.
+        let mut drivers = [
+            (&cfg_a, sequence_driver(executor(root_a), &l1_info_attributes(&cfg_a, 2))),
+            (&cfg_b, sequence_driver(executor(root_b), &l1_info_attributes(&cfg_b, 2))),
+        ];
+        let super_root = Driver::advance_super_root(&mut drivers, 2).await.unwrap();
+
+        let [(_, driver_a), (_, driver_b)] = &drivers;
+        assert_eq!(driver_a.executor.executed.len(), 1);
+        assert_eq!(driver_a.cursor.read().l2_safe_head().block_info.number, 1);
+        assert_eq!(driver_a.pipeline.attributes.len(), 1);
+        assert_eq!(driver_b.executor.executed.len(), 2);
+        assert_eq!(driver_b.cursor.read().l2_safe_head().block_info.number, 2);
+        assert!(driver_b.pipeline.attributes.is_empty());
+
+        let expected = SuperRoot::new(
+            2,
+            vec![OutputRootWithChain::new(900, root_b), OutputRootWithChain::new(901, root_a)],
+        );
+        assert_eq!(super_root.hash(), expected.hash());
+    }
+
+    #[tokio::test]
+    async fn test_advance_super_root_safe_head_past_target() {
+        let cfg = RollupConfig { l2_chain_id: 900, block_time: 1, ..Default::default() };
+        let mut drivers = [(&cfg, mock_driver(11, None))];
+        assert!(matches!(
+            Driver::advance_super_root(&mut drivers, 10).await,
+            Err(DriverError::SafeHeadPastTarget { chain_id: 900, safe_head: 11, target: 10 })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_advance_super_root_before_genesis() {
+        let cfg = RollupConfig {
+            l2_chain_id: 900,
+            block_time: 1,
+            genesis: ChainGenesis { l2_time: 20, ..Default::default() },
+            ..Default::default()
+        };
+        let mut drivers = [(&cfg, mock_driver(0, None))];
+        assert!(matches!(
+            Driver::advance_super_root(&mut drivers, 10).await,
+            Err(DriverError::TimestampBeforeGenesis { chain_id: 900, timestamp: 10 })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_advance_super_root_zero_block_time() {
+        let cfg = RollupConfig { l2_chain_id: 900, block_time: 0, ..Default::default() };
+        let mut drivers = [(&cfg, mock_driver(10, None))];
+        assert!(matches!(
+            Driver::advance_super_root(&mut drivers, 10).await,
+            Err(DriverError::ZeroBlockTime(900))
+        ));
+    }
+
+    /// An executor that records the payloads it executes, producing empty blocks on top of the
+    /// safe head.
+    #[derive(Debug, Default)]
+    struct RecordingExecutor {
+        safe_head: Header,
+        executed: Vec<OpPayloadAttributes>,
+        output_root: B256,
+    }
+
+    #[async_trait]
+    impl Executor for RecordingExecutor {
+        type Error = MockExecutorError;
+
+        async fn wait_until_ready(&mut self) {}
+
+        fn update_safe_head(&mut self, header: Sealed<Header>) {
+            self.safe_head = header.into_inner();
+        }
+
+        async fn execute_payload(
+            &mut self,
+            attributes: OpPayloadAttributes,
+        ) -> Result<ExecutionArtifacts, Self::Error> {
+            let header = Header {
+                number: self.safe_head.number + 1,
+                parent_hash: self.safe_head.hash_slow(),
+                timestamp: attributes.payload_attributes.timestamp,
+                ..Default::default()
+            };
+            self.executed.push(attributes);
+            Ok(ExecutionArtifacts {
+                block_header: header.seal_slow(),
+                receipts: Vec::new(),
+                no_tx_pool: false,
+            })
+        }
+
+        fn compute_output_root(&mut self) -> Result<B256, Self::Error> {
+            Ok(self.output_root)
+        }
+    }
+
+    /// A pipeline that produces a fixed sequence of attributes.
+    #[derive(Debug, Default)]
+    struct SequencePipeline {
+        rollup_config: RollupConfig,
+        attributes: alloc::collections::VecDeque<OpAttributesWithParent>,
+    }
+
+    impl OriginProvider for SequencePipeline {
+        fn origin(&self) -> Option<BlockInfo> {
+            Some(BlockInfo::default())
+        }
+    }
+
+    impl Iterator for SequencePipeline {
+        type Item = OpAttributesWithParent;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.attributes.pop_front()
+        }
+    }
+
+    #[async_trait]
+    impl SignalReceiver for SequencePipeline {
+        async fn signal(&mut self, _: Signal) -> PipelineResult<()> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl Pipeline for SequencePipeline {
+        fn peek(&self) -> Option<&OpAttributesWithParent> {
+            self.attributes.front()
+        }
+
+        async fn step(&mut self, _: L2BlockInfo) -> StepResult {
+            if self.attributes.is_empty() {
+                StepResult::StepFailed(PipelineError::EndOfSource.crit())
+            } else {
+                StepResult::PreparedAttributes
+            }
+        }
+
+        fn rollup_config(&self) -> &RollupConfig {
+            &self.rollup_config
+        }
+
+        async fn system_config_by_number(
+            &mut self,
+            _: u64,
+        ) -> Result<SystemConfig, PipelineErrorKind> {
+            Ok(SystemConfig::default())
+        }
+    }
+
+    impl DriverPipeline<Self> for SequencePipeline {
+        fn flush(&mut self) {}
+    }
+
+    /// An L2 chain provider serving the [L2BlockInfo] of every block number.
+    #[derive(Debug)]
+    struct MockL2ChainProvider;
+
+    #[async_trait]
+    impl BatchValidationProvider for MockL2ChainProvider {
+        type Error = PipelineErrorKind;
+
+        async fn l2_block_info_by_number(
+            &mut self,
+            number: u64,
+        ) -> Result<L2BlockInfo, Self::Error> {
+            Ok(L2BlockInfo {
+                block_info: BlockInfo { number, ..Default::default() },
+                ..Default::default()
+            })
+        }
+
+        async fn block_by_number(&mut self, _: u64) -> Result<OpBlock, Self::Error> {
+            Err(PipelineError::Provider("unused".into()).crit())
+        }
+    }
+
+    fn sequence_driver<E>(
+        executor: E,
+        attributes: &[OpAttributesWithParent],
+    ) -> Driver<E, SequencePipeline, SequencePipeline>
+    where
+        E: Executor + Send + Sync + Debug,
+    {
+        let mut cursor = PipelineCursor::new(0, BlockInfo::default());
+        cursor.advance(
+            BlockInfo::default(),
+            TipCursor::new(L2BlockInfo::default(), Header::default().seal_slow(), B256::ZERO),
+        );
+        let pipeline = SequencePipeline {
+            attributes: attributes.iter().cloned().collect(),
+            ..Default::default()
+        };
+        Driver::new(Arc::new(RwLock::new(cursor)), executor, pipeline)
+    }
+
+    /// Builds the attributes of blocks `1..=count` on the given chain, each holding only its L1
+    /// info deposit.
+    fn l1_info_attributes(cfg: &RollupConfig, count: u64) -> Vec<OpAttributesWithParent> {
+        (1..=count)
+            .map(|number| {
+                let timestamp = number * cfg.block_time;
+                let (_, deposit) = L1BlockInfoTx::try_new_with_deposit_tx(
+                    cfg,
+                    &SystemConfig::default(),
+                    number - 1,
+                    &Header::default(),
+                    timestamp,
+                )
+                .unwrap();
+                let mut attributes = OpPayloadAttributes {
+                    transactions: Some(vec![OpTxEnvelope::Deposit(deposit).encoded_2718().into()]),
+                    ..Default::default()
+                };
+                attributes.payload_attributes.timestamp = timestamp;
+                OpAttributesWithParent {
+                    attributes,
+                    parent: L2BlockInfo::default(),
+                    is_last_in_span: false,
+                }
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_derive_only_matches_full_advance() {
+        let cfg = RollupConfig { block_time: 2, ..Default::default() };
+        let attributes = l1_info_attributes(&cfg, 3);
+
+        // Derive and execute the blocks.
+        let mut driver = sequence_driver(RecordingExecutor::default(), &attributes);
+        let outcome = driver.advance_to_target(&cfg, Some(3)).await.unwrap();
+        assert_eq!(outcome.safe_head.block_info.number, 3);
+        let executed = driver.executor.executed;
+
+        // Derive the blocks without executing them.
+        let mut driver = sequence_driver(MockExecutor, &attributes);
+        let derived = driver.derive_only(&mut MockL2ChainProvider, 3).await.unwrap();
+        assert_eq!(derived.into_iter().map(|a| a.attributes).collect::<Vec<_>>(), executed);
+        assert_eq!(driver.cursor.read().l2_safe_head().block_info.number, 0);
+    }
+
+    #[tokio::test]
+    async fn test_derive_only_end_of_source() {
+        let attributes = [OpAttributesWithParent {
+            attributes: OpPayloadAttributes::default(),
+            parent: L2BlockInfo::default(),
+            is_last_in_span: false,
+        }];
+        let mut driver = sequence_driver(MockExecutor, &attributes);
+
+        let derived = driver.derive_only(&mut MockL2ChainProvider, 10).await.unwrap();
+        assert_eq!(derived.len(), 1);
+    }
+}
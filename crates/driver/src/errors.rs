@@ -25,4 +25,26 @@ where
     /// Error decoding or encoding RLP.
     #[error("RLP error: {0}")]
     Rlp(alloy_rlp::Error),
+    /// The chain with the given ID has a block time of zero, so its blocks cannot be located by
+    /// timestamp.
+    #[error("Chain {0} has a block time of zero")]
+    ZeroBlockTime(u64),
+    /// A timestamp does not resolve to a block on the chain, as it precedes the chain's genesis.
+    #[error("Timestamp {timestamp} precedes the genesis of chain {chain_id}")]
+    TimestampBeforeGenesis {
+        /// The chain ID.
+        chain_id: u64,
+        /// The timestamp.
+        timestamp: u64,
+    },
+    /// The safe head of a chain is already past the block targeted by a super root.
+    #[error("Safe head #{safe_head} of chain {chain_id} is past the target block #{target}")]
+    SafeHeadPastTarget {
+        /// The chain ID.
+        chain_id: u64,
+        /// The number of the chain's safe head.
+        safe_head: u64,
+        /// The number of the target block.
+        target: u64,
+    },
 }
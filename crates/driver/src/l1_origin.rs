@@ -0,0 +1,77 @@
+//! Resolution of the L1 origin of an L2 block.
+
+use kona_derive::{
+    errors::{PipelineErrorKind, ResetError},
+    traits::ChainProvider,
+    types::PipelineResult,
+};
+use maili_protocol::{BatchValidationProvider, BlockInfo};
+
+/// Resolves the L1 origin of the L2 block at the given number.
+///
+/// ## Takes
+/// - `l1_provider`: The provider of the L1 chain.
+/// - `l2_provider`: The provider of the canonical L2 chain.
+/// - `l2_block_number`: The number of the L2 block.
+///
+/// ## Returns
+/// - `Ok(l1_origin)` - The [BlockInfo] of the L2 block's L1 origin.
+/// - `Err(e)` - An error if either block could not be fetched, or a reset error if the L1 origin
+///   referenced by the L2 block is no longer canonical on L1.
+pub async fn l1_origin_for<L1, L2>(
+    l1_provider: &mut L1,
+    l2_provider: &mut L2,
+    l2_block_number: u64,
+) -> PipelineResult<BlockInfo>
+where
+    L1: ChainProvider + Send,
+    L2: BatchValidationProvider + Send,
+    L2::Error: Into<PipelineErrorKind>,
+{
+    let l2_block =
+        l2_provider.l2_block_info_by_number(l2_block_number).await.map_err(Into::into)?;
+    let l1_origin =
+        l1_provider.block_info_by_number(l2_block.l1_origin.number).await.map_err(Into::into)?;
+
+    if l1_origin.hash != l2_block.l1_origin.hash {
+        return Err(ResetError::ReorgDetected(l2_block.l1_origin.hash, l1_origin.hash).reset());
+    }
+    Ok(l1_origin)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloy_eips::BlockNumHash;
+    use alloy_primitives::B256;
+    use kona_derive::test_utils::{TestChainProvider, TestL2ChainProvider};
+    use maili_protocol::L2BlockInfo;
+
+    #[tokio::test]
+    async fn test_l1_origin_for() {
+        let l1_origin =
+            BlockInfo { hash: B256::repeat_byte(0x11), number: 4, ..Default::default() };
+        let mut l1_provider = TestChainProvider::default();
+        l1_provider.insert_block(4, l1_origin);
+        l1_provider.insert_block(5, BlockInfo { number: 5, ..Default::default() });
+
+        let l2_block = |number, l1_origin: BlockInfo| L2BlockInfo {
+            block_info: BlockInfo { number, ..Default::default() },
+            l1_origin: BlockNumHash { number: l1_origin.number, hash: l1_origin.hash },
+            ..Default::default()
+        };
+        let mut l2_provider = TestL2ChainProvider {
+            blocks: vec![
+                l2_block(10, l1_origin),
+                l2_block(11, BlockInfo { number: 5, hash: B256::repeat_byte(0x22), ..l1_origin }),
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(l1_origin_for(&mut l1_provider, &mut l2_provider, 10).await.unwrap(), l1_origin);
+
+        // The L1 origin of block 11 was reorged out.
+        let err = l1_origin_for(&mut l1_provider, &mut l2_provider, 11).await.unwrap_err();
+        assert!(matches!(err, PipelineErrorKind::Reset(ResetError::ReorgDetected(..))));
+    }
+}
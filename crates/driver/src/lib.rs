@@ -22,7 +22,13 @@ mod executor;
 pub use executor::Executor;
 
 mod core;
-pub use core::Driver;
+pub use core::{AdvanceOutcome, Driver};
+
+mod block_time;
+pub use block_time::{block_for_timestamp, timestamp_for_block};
+
+mod l1_origin;
+pub use l1_origin::l1_origin_for;
 
 mod cursor;
 pub use cursor::PipelineCursor;
@@ -81,6 +81,7 @@ where
                                         l2_safe_head,
                                         l1_origin,
                                         system_config: Some(system_config),
+                                        from_stage: None,
                                     }
                                     .signal(),
                                 )
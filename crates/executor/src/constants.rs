@@ -8,6 +8,18 @@ pub(crate) const FEE_RECIPIENT: Address = address!("4200000000000000000000000000
 /// The address of the L2 to L1 bridge predeploy.
 pub(crate) const L2_TO_L1_BRIDGE: Address = address!("4200000000000000000000000000000000000016");
 
+/// The address of the `GasPriceOracle` predeploy.
+pub(crate) const GAS_PRICE_ORACLE: Address = address!("420000000000000000000000000000000000000F");
+
+/// The address of the `L1Block` predeploy.
+pub(crate) const L1_BLOCK_CONTRACT: Address = address!("4200000000000000000000000000000000000015");
+
+/// The address of the depositor account that sends the L1 info deposit transaction.
+pub(crate) const L1_INFO_DEPOSITOR: Address = address!("deaddeaddeaddeaddeaddeaddeaddeaddead0001");
+
+/// The address of the operator fee vault predeploy.
+pub(crate) const OPERATOR_FEE_VAULT: Address = address!("420000000000000000000000000000000000001b");
+
 /// The current version of the output root format.
 pub(crate) const OUTPUT_ROOT_VERSION: u8 = 0x00;
 
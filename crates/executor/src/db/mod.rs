@@ -2,12 +2,12 @@
 //! incremental updates through fetching node preimages on the fly during execution.
 
 use crate::errors::{TrieDBError, TrieDBResult};
-use alloc::{string::ToString, vec::Vec};
+use alloc::{collections::BTreeSet, string::ToString, vec::Vec};
 use alloy_consensus::{Header, Sealed, EMPTY_ROOT_HASH};
-use alloy_primitives::{keccak256, Address, B256, U256};
+use alloy_primitives::{keccak256, Address, Bytes, B256, U256};
 use alloy_rlp::{Decodable, Encodable};
 use alloy_trie::TrieAccount;
-use kona_mpt::{Nibbles, TrieHinter, TrieNode, TrieNodeError};
+use kona_mpt::{Nibbles, OrderedListWalker, TrieHinter, TrieNode, TrieNodeError, TrieProvider};
 use revm::{
     db::{states::StorageSlot, BundleState},
     primitives::{AccountInfo, Bytecode, HashMap, BLOCK_HASH_HISTORY},
@@ -17,6 +17,13 @@ use revm::{
 mod traits;
 pub use traits::{NoopTrieDBProvider, TrieDBProvider};
 
+mod stats;
+pub use stats::TrieDBStats;
+use stats::{open_node_count, RecordingTrieProvider};
+
+mod retry;
+pub use retry::RetryingTrieDBProvider;
+
 /// A Trie DB that caches open state in-memory.
 ///
 /// When accounts that don't already exist within the cached [TrieNode] are queried, the database
@@ -36,11 +43,14 @@ pub use traits::{NoopTrieDBProvider, TrieDBProvider};
 ///   code hash of the account.
 /// - When a [BundleState] changeset is committed to the parent [State] database, the changes are
 ///   first applied to the [State]'s cache, then the trie hash is recomputed with
-///   [Self::state_root].
+///   [Self::state_root]. Only the paths to the accounts touched by the changeset are re-opened and
+///   re-hashed; the commitments of all other subtrees are left intact.
 /// - When the block hash of a block number is needed via [Self::block_hash], the
 ///   `HeaderByHashFetcher` is consulted to walk back to the desired block number by revealing the
 ///   parent hash of block headers until the desired block number is reached, up to a maximum of
-///   [BLOCK_HASH_HISTORY] blocks back relative to the current parent block hash.
+///   [BLOCK_HASH_HISTORY] blocks back relative to the current parent block hash. If historical
+///   block hashes are enabled with [Self::enable_historical_block_hashes], older block hashes are
+///   resolved as well, after hinting the headers of the ancestors to the `TrieHinter`.
 ///
 /// **Example Construction**:
 /// ```rust
@@ -81,6 +91,8 @@ where
 {
     /// The [TrieNode] representation of the root node.
     root_node: TrieNode,
+    /// The commitment of the root node as of the last computed state root.
+    root_commitment: B256,
     /// Storage roots of accounts within the trie.
     storage_roots: HashMap<Address, TrieNode>,
     /// The parent block hash of the current block.
@@ -89,6 +101,16 @@ where
     fetcher: F,
     /// The [TrieHinter]
     hinter: H,
+    /// The node cache churn accumulated since the last computed state root, if stats collection
+    /// is enabled.
+    pending_stats: Option<TrieDBStats>,
+    /// The node cache churn of the last state root computation.
+    last_stats: Option<TrieDBStats>,
+    /// The keccak256 hashes of the preimages fetched since the touched keys were last reset, if
+    /// recording is enabled.
+    touched_keys: Option<BTreeSet<B256>>,
+    /// Whether block hashes older than [BLOCK_HASH_HISTORY] blocks are resolved.
+    historical_block_hashes: bool,
 }
 
 impl<F, H> TrieDB<F, H>
@@ -100,10 +122,89 @@ where
     pub fn new(root: B256, parent_block_header: Sealed<Header>, fetcher: F, hinter: H) -> Self {
         Self {
             root_node: TrieNode::new_blinded(root),
+            root_commitment: root,
             storage_roots: Default::default(),
             parent_block_header,
             fetcher,
             hinter,
+            pending_stats: None,
+            last_stats: None,
+            touched_keys: None,
+            historical_block_hashes: false,
+        }
+    }
+
+    /// Enables the collection of [TrieDBStats] on the node cache of the trie DB. Disabled by
+    /// default.
+    ///
+    /// The stats are diagnostics-only, and do not affect the computed state roots.
+    pub fn enable_stats(&mut self) {
+        self.pending_stats.get_or_insert_with(TrieDBStats::default);
+    }
+
+    /// Enables the recording of the keys of the preimages fetched by the trie DB, returned by
+    /// [Self::touched_keys]. Disabled by default.
+    pub fn enable_touched_keys(&mut self) {
+        self.touched_keys.get_or_insert_with(BTreeSet::new);
+    }
+
+    /// Enables the resolution of block hashes older than [BLOCK_HASH_HISTORY] blocks by
+    /// [Self::block_hash]. Disabled by default, in which case the zero hash is returned for them.
+    ///
+    /// Before walking back beyond the history window, the headers of the ancestors are hinted with
+    /// [TrieHinter::hint_ancestor_headers], so that the host can fetch them in bulk.
+    pub const fn enable_historical_block_hashes(&mut self) {
+        self.historical_block_hashes = true;
+    }
+
+    /// Returns the [TrieDBStats] of the last state root computation, if stats collection is
+    /// enabled and a state root has been computed since.
+    pub const fn stats(&self) -> Option<TrieDBStats> {
+        self.last_stats
+    }
+
+    /// Records the trie nodes fetched through a [RecordingTrieProvider] as touched if recording is
+    /// enabled, and counts them as opened if stats collection is enabled.
+    fn record_fetched(
+        pending_stats: &mut Option<TrieDBStats>,
+        touched_keys: &mut Option<BTreeSet<B256>>,
+        fetcher: RecordingTrieProvider<'_, F>,
+    ) {
+        let fetched = fetcher.into_fetched();
+        if let Some(stats) = pending_stats {
+            stats.opened += fetched.len() as u64;
+        }
+        if let Some(touched_keys) = touched_keys {
+            touched_keys.extend(fetched);
+        }
+    }
+
+    /// Records the preimage with the given keccak256 hash as touched, if recording is enabled.
+    fn touch(&mut self, hash: B256) {
+        if let Some(touched_keys) = self.touched_keys.as_mut() {
+            touched_keys.insert(hash);
+        }
+    }
+
+    /// Returns the keccak256 hashes of the preimages fetched from the [TrieDBProvider] since the
+    /// last call to [Self::reset_touched_keys], if recording is enabled with
+    /// [Self::enable_touched_keys]. Only the keys are recorded, not the preimages.
+    ///
+    /// Preimages fetched by [Self::block_by_hash] and [Self::root_commitment_from_witness] are
+    /// not recorded.
+    pub const fn touched_keys(&self) -> Option<&BTreeSet<B256>> {
+        self.touched_keys.as_ref()
+    }
+
+    /// Resets the set of touched keys to the hash of the current parent block header, if
+    /// recording is enabled.
+    ///
+    /// The parent block header is supplied up front rather than fetched, but building on top of it
+    /// depends on its preimage all the same.
+    pub fn reset_touched_keys(&mut self) {
+        if let Some(touched_keys) = self.touched_keys.as_mut() {
+            touched_keys.clear();
+            touched_keys.insert(self.parent_block_header.seal());
         }
     }
 
@@ -117,6 +218,24 @@ where
         &self.root_node
     }
 
+    /// Recomputes the commitment of the root node from its witness.
+    ///
+    /// If the root node is blinded, its preimage is fetched from the [TrieDBProvider] before it is
+    /// re-blinded, so that a provider serving the wrong preimage for the root is detected. The
+    /// node cache of the trie DB is left untouched.
+    ///
+    /// ## Returns
+    /// - `Ok(commitment)`: The recomputed commitment of the root node.
+    /// - `Err(_)`: The root node's preimage could not be fetched.
+    pub fn root_commitment_from_witness(&self) -> TrieDBResult<B256> {
+        let mut root = self.root_node.clone();
+        root.unblind(&self.fetcher)?;
+        // The preimage of a blinded node is always long enough to be re-blinded, while an empty
+        // trie commits to the empty root hash.
+        root.blind();
+        root.blinded_commitment().ok_or(TrieDBError::RootNotBlinded)
+    }
+
     /// Returns the mapping of [Address]es to storage roots.
     pub const fn storage_roots(&self) -> &HashMap<Address, TrieNode> {
         &self.storage_roots
@@ -147,10 +266,21 @@ where
     pub fn state_root(&mut self, bundle: &BundleState) -> TrieDBResult<B256> {
         debug!(target: "client_executor", "Recomputing state root");
 
+        // Drop the paths opened by reads since the last state root was computed. Only the paths
+        // to the accounts touched by the changeset are re-opened below, so that the subtrees left
+        // untouched keep their commitments and do not need to be re-hashed.
+        if let Some(stats) = self.pending_stats.as_mut() {
+            stats.discarded += open_node_count(&self.root_node);
+        }
+        self.root_node = TrieNode::new_blinded(self.root_commitment);
+
         // Update the accounts in the trie with the changeset.
         self.update_accounts(bundle)?;
 
         // Recompute the root hash of the trie.
+        if let Some(stats) = self.pending_stats.as_mut() {
+            stats.blinded += open_node_count(&self.root_node);
+        }
         self.root_node.blind();
 
         debug!(
@@ -160,7 +290,51 @@ where
         );
 
         // Extract the new state root from the root node.
-        self.root_node.blinded_commitment().ok_or(TrieDBError::RootNotBlinded)
+        self.root_commitment =
+            self.root_node.blinded_commitment().ok_or(TrieDBError::RootNotBlinded)?;
+
+        // Snapshot the stats of this computation, and start accumulating the next window.
+        if let Some(stats) = self.pending_stats.as_mut() {
+            self.last_stats = Some(core::mem::take(stats));
+        }
+        Ok(self.root_commitment)
+    }
+
+    /// Hints the host to fetch the proof of an account at the parent block, without opening it in
+    /// the trie DB.
+    ///
+    /// ## Takes
+    /// - `address`: The address of the account.
+    ///
+    /// ## Returns
+    /// - `Ok(())`: If the hint was successful.
+    /// - `Err(_)`: If the hint was unsuccessful.
+    pub fn hint_account_proof(&self, address: Address) -> TrieDBResult<()> {
+        self.hinter
+            .hint_account_proof(address, self.parent_block_header.number)
+            .map_err(|e| TrieDBError::Provider(e.to_string()))
+    }
+
+    /// Fetches the header of a block and its EIP-2718 encoded transactions through the
+    /// [TrieDBProvider]. The transactions are read from the transactions trie committed to in the
+    /// header.
+    ///
+    /// ## Takes
+    /// - `block_hash`: The hash of the block.
+    ///
+    /// ## Returns
+    /// - `Ok((header, transactions))`: The header and transactions of the block.
+    /// - `Err(_)`: If the header or the transactions trie could not be fetched.
+    pub fn block_by_hash(&self, block_hash: B256) -> TrieDBResult<(Header, Vec<Bytes>)> {
+        let header = self
+            .fetcher
+            .header_by_hash(block_hash)
+            .map_err(|e| TrieDBError::Provider(e.to_string()))?;
+        let transactions =
+            OrderedListWalker::try_new_hydrated(header.transactions_root, &self.fetcher)?
+                .map(|(_, transaction)| transaction)
+                .collect();
+        Ok((header, transactions))
     }
 
     /// Fetches the [TrieAccount] of an account from the trie DB.
@@ -170,7 +344,9 @@ where
     ///
     /// ## Returns
     /// - `Ok(Some(TrieAccount))`: The [TrieAccount] of the account.
-    /// - `Ok(None)`: If the account does not exist in the trie.
+    /// - `Ok(None)`: If the account is proven absent from the trie.
+    /// - `Err(TrieDBError::IncompleteProof(_))`: If a trie node on the path to the account could
+    ///   not be fetched.
     /// - `Err(_)`: If the account could not be fetched.
     pub fn get_trie_account(
         &mut self,
@@ -184,8 +360,19 @@ where
 
         // Fetch the account from the trie.
         let hashed_address_nibbles = Nibbles::unpack(keccak256(address.as_slice()));
-        let Some(trie_account_rlp) = self.root_node.open(&hashed_address_nibbles, &self.fetcher)?
-        else {
+        let fetcher = RecordingTrieProvider::new(&self.fetcher);
+        let trie_account_rlp = self.root_node.open(&hashed_address_nibbles, &fetcher);
+        Self::record_fetched(&mut self.pending_stats, &mut self.touched_keys, fetcher);
+
+        // A node on the path that cannot be fetched leaves the account proof incomplete, which
+        // must not be mistaken for a proof of the account's absence.
+        let trie_account_rlp = trie_account_rlp.map_err(|e| match e {
+            TrieNodeError::Provider(_) => {
+                TrieDBError::IncompleteProof(hashed_address_nibbles.clone())
+            }
+            e => e.into(),
+        })?;
+        let Some(trie_account_rlp) = trie_account_rlp else {
             return Ok(None);
         };
 
@@ -205,6 +392,7 @@ where
     /// - `Ok(())` if the accounts were successfully updated.
     /// - `Err(_)` if the accounts could not be updated.
     fn update_accounts(&mut self, bundle: &BundleState) -> TrieDBResult<()> {
+        let fetcher = RecordingTrieProvider::new(&self.fetcher);
         for (address, bundle_account) in bundle.state() {
             if bundle_account.status.is_not_modified() {
                 continue;
@@ -213,11 +401,15 @@ where
             // Compute the path to the account in the trie.
             let account_path = Nibbles::unpack(keccak256(address.as_slice()));
 
-            // If the account was destroyed, delete it from the trie.
+            // If the account was destroyed, drop its cached storage trie so that a re-creation
+            // within the same bundle starts from an empty storage root. If the account was not
+            // re-created, delete it from the trie.
             if bundle_account.was_destroyed() {
-                self.root_node.delete(&account_path, &self.fetcher, &self.hinter)?;
                 self.storage_roots.remove(address);
-                continue;
+                if bundle_account.info.is_none() {
+                    self.root_node.delete(&account_path, &fetcher, &self.hinter)?;
+                    continue;
+                }
             }
 
             let account_info =
@@ -235,10 +427,13 @@ where
                 .entry(*address)
                 .or_insert_with(|| TrieNode::new_blinded(EMPTY_ROOT_HASH));
             bundle_account.storage.iter().try_for_each(|(index, value)| {
-                Self::change_storage(acc_storage_root, *index, value, &self.fetcher, &self.hinter)
+                Self::change_storage(acc_storage_root, *index, value, &fetcher, &self.hinter)
             })?;
 
             // Recompute the account storage root.
+            if let Some(stats) = self.pending_stats.as_mut() {
+                stats.blinded += open_node_count(acc_storage_root);
+            }
             acc_storage_root.blind();
 
             let commitment =
@@ -250,9 +445,10 @@ where
             trie_account.encode(&mut account_buf);
 
             // Insert or update the account in the trie.
-            self.root_node.insert(&account_path, account_buf.into(), &self.fetcher)?;
+            self.root_node.insert(&account_path, account_buf.into(), &fetcher)?;
         }
 
+        Self::record_fetched(&mut self.pending_stats, &mut self.touched_keys, fetcher);
         Ok(())
     }
 
@@ -266,11 +462,11 @@ where
     /// ## Returns
     /// - `Ok(())` if the storage slot was successfully modified.
     /// - `Err(_)` if the storage slot could not be modified.
-    fn change_storage(
+    fn change_storage<P: TrieProvider>(
         storage_root: &mut TrieNode,
         index: U256,
         value: &StorageSlot,
-        fetcher: &F,
+        fetcher: &P,
         hinter: &H,
     ) -> TrieDBResult<()> {
         if !value.is_changed() {
@@ -325,6 +521,7 @@ where
     }
 
     fn code_by_hash(&mut self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+        self.touch(code_hash);
         self.fetcher
             .bytecode_by_hash(code_hash)
             .map(Bytecode::new_raw)
@@ -348,7 +545,10 @@ where
             Some(storage_root) => {
                 // Fetch the storage slot from the trie.
                 let hashed_slot_key = keccak256(index.to_be_bytes::<32>().as_slice());
-                match storage_root.open(&Nibbles::unpack(hashed_slot_key), &self.fetcher)? {
+                let fetcher = RecordingTrieProvider::new(&self.fetcher);
+                let slot_value = storage_root.open(&Nibbles::unpack(hashed_slot_key), &fetcher);
+                Self::record_fetched(&mut self.pending_stats, &mut self.touched_keys, fetcher);
+                match slot_value? {
                     Some(slot_value) => {
                         // Decode the storage slot value.
                         let int_slot = U256::decode(&mut slot_value.as_ref())
@@ -369,14 +569,23 @@ where
         let mut header = self.parent_block_header.inner().clone();
 
         // Check if the block number is in range. If not, we can fail early.
-        if block_number > header.number ||
-            header.number.saturating_sub(block_number) > BLOCK_HASH_HISTORY
-        {
+        if block_number > header.number {
             return Ok(B256::default());
         }
+        if header.number.saturating_sub(block_number) > BLOCK_HASH_HISTORY {
+            if !self.historical_block_hashes {
+                return Ok(B256::default());
+            }
+
+            // Hint the headers of the ancestors, as the block is beyond the history window.
+            self.hinter
+                .hint_ancestor_headers(self.parent_block_header.seal(), block_number)
+                .map_err(|e| TrieDBError::Provider(e.to_string()))?;
+        }
 
         // Walk back the block headers to the desired block number.
         while header.number > block_number {
+            self.touch(header.parent_hash);
             header = self
                 .fetcher
                 .header_by_hash(header.parent_hash)
@@ -390,9 +599,13 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::MapTrieDBProvider;
+    use alloc::{string::String, vec};
     use alloy_consensus::Sealable;
-    use alloy_primitives::b256;
+    use alloy_primitives::{b256, Bytes};
+    use core::cell::RefCell;
     use kona_mpt::NoopTrieHinter;
+    use revm::db::{AccountStatus, BundleAccount};
 
     fn new_test_db() -> TrieDB<NoopTrieDBProvider, NoopTrieHinter> {
         TrieDB::new(
@@ -454,4 +667,182 @@ mod tests {
             b256!("78dec18c6d7da925bbe773c315653cdc70f6444ed6c1de9ac30bdb36cff74c3b")
         );
     }
+
+    /// A [TrieHinter] recording the ancestor header hints it receives.
+    #[derive(Debug, Default)]
+    struct AncestorHeadersHinter(RefCell<Vec<(B256, u64)>>);
+
+    impl TrieHinter for AncestorHeadersHinter {
+        type Error = String;
+
+        fn hint_trie_node(&self, _: B256) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn hint_account_proof(&self, _: Address, _: u64) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn hint_storage_proof(&self, _: Address, _: U256, _: u64) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn hint_ancestor_headers(
+            &self,
+            head_hash: B256,
+            block_number: u64,
+        ) -> Result<(), Self::Error> {
+            self.0.borrow_mut().push((head_hash, block_number));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_block_hash_historical() {
+        let parent_header =
+            Header { number: BLOCK_HASH_HISTORY + 10, ..Default::default() }.seal_slow();
+        let mut db = TrieDB::new(
+            B256::default(),
+            parent_header.clone(),
+            NoopTrieDBProvider,
+            AncestorHeadersHinter::default(),
+        );
+
+        // Within the history window, no hint is sent.
+        db.enable_historical_block_hashes();
+        assert_eq!(db.block_hash(BLOCK_HASH_HISTORY).unwrap(), Header::default().hash_slow());
+        assert!(db.hinter.0.borrow().is_empty());
+
+        // Beyond the history window, the block hash is resolved after hinting the ancestors.
+        assert_eq!(db.block_hash(0).unwrap(), Header::default().hash_slow());
+        assert_eq!(*db.hinter.0.borrow(), vec![(parent_header.seal(), 0)]);
+    }
+
+    #[test]
+    fn test_destroyed_and_recreated_account_storage_root() {
+        let address = Address::repeat_byte(0x01);
+        let info = AccountInfo { nonce: 1, ..Default::default() };
+        let storage: HashMap<_, _> =
+            [(U256::from(1), StorageSlot::new_changed(U256::ZERO, U256::from(42)))]
+                .into_iter()
+                .collect();
+        let bundle_with_status = |status| {
+            let mut bundle = BundleState::default();
+            bundle.state.insert(
+                address,
+                BundleAccount::new(None, Some(info.clone()), storage.clone(), status),
+            );
+            bundle
+        };
+        let new_empty_db = || {
+            TrieDB::new(
+                EMPTY_ROOT_HASH,
+                Header::default().seal_slow(),
+                NoopTrieDBProvider,
+                NoopTrieHinter,
+            )
+        };
+
+        // The expected state, with the account freshly created.
+        let mut expected_db = new_empty_db();
+        let expected_state_root =
+            expected_db.state_root(&bundle_with_status(AccountStatus::InMemoryChange)).unwrap();
+        let expected_storage_root = expected_db.storage_roots()[&address].blinded_commitment();
+
+        // The account's storage trie is cached from before its destruction.
+        let stale_storage_root = B256::repeat_byte(0xFF);
+        let mut db = new_empty_db();
+        db.storage_roots.insert(address, TrieNode::new_blinded(stale_storage_root));
+
+        let state_root =
+            db.state_root(&bundle_with_status(AccountStatus::DestroyedChanged)).unwrap();
+        assert_eq!(state_root, expected_state_root);
+        assert_eq!(db.storage_roots()[&address].blinded_commitment(), expected_storage_root);
+        assert_ne!(expected_storage_root, Some(stale_storage_root));
+    }
+
+    #[test]
+    fn test_incremental_state_root() {
+        let encode_account = |account: TrieAccount| {
+            let mut buf = Vec::with_capacity(account.length());
+            account.encode(&mut buf);
+            Bytes::from(buf)
+        };
+        let account_path = |address: &Address| Nibbles::unpack(keccak256(address.as_slice()));
+
+        // Build a state trie with many accounts.
+        let addresses = (0..64u8).map(Address::with_last_byte).collect::<Vec<_>>();
+        let mut trie = TrieNode::Empty;
+        for (i, address) in addresses.iter().enumerate() {
+            let account = TrieAccount { nonce: i as u64, ..Default::default() };
+            trie.insert(&account_path(address), encode_account(account), &NoopTrieDBProvider)
+                .unwrap();
+        }
+        let mut provider = MapTrieDBProvider::default();
+        provider.insert_trie(&trie);
+        trie.blind();
+        let parent_root = trie.blinded_commitment().unwrap();
+
+        // Open every account, then touch 3 of them.
+        let mut db =
+            TrieDB::new(parent_root, Header::default().seal_slow(), provider, NoopTrieHinter);
+        for address in addresses.iter() {
+            assert!(db.get_trie_account(address, 0).unwrap().is_some());
+        }
+        let touched = [addresses[3], addresses[17], addresses[42]];
+        let mut bundle = BundleState::default();
+        for address in touched.iter() {
+            let info = AccountInfo { nonce: 1337, ..Default::default() };
+            bundle.state.insert(
+                *address,
+                BundleAccount::new(None, Some(info), Default::default(), AccountStatus::Changed),
+            );
+        }
+        let state_root = db.state_root(&bundle).unwrap();
+
+        // Recompute the root from the fully opened trie.
+        let mut expected = TrieNode::new_blinded(parent_root);
+        for address in addresses.iter() {
+            expected.open(&account_path(address), &db.fetcher).unwrap();
+        }
+        for address in touched.iter() {
+            let account = TrieAccount { nonce: 1337, ..Default::default() };
+            expected.insert(&account_path(address), encode_account(account), &db.fetcher).unwrap();
+        }
+        expected.blind();
+
+        assert_ne!(state_root, parent_root);
+        assert_eq!(Some(state_root), expected.blinded_commitment());
+    }
+
+    #[test]
+    fn test_get_trie_account_incomplete_proof() {
+        let present = Address::with_last_byte(1);
+        let absent = Address::repeat_byte(0xFF);
+        let mut trie = TrieNode::Empty;
+        for address in (0..64u8).map(Address::with_last_byte) {
+            let mut buf = Vec::new();
+            TrieAccount::default().encode(&mut buf);
+            trie.insert(&Nibbles::unpack(keccak256(address)), buf.into(), &NoopTrieDBProvider)
+                .unwrap();
+        }
+        let mut provider = MapTrieDBProvider::default();
+        provider.insert_trie(&trie);
+        trie.blind();
+        let root = trie.blinded_commitment().unwrap();
+        let new_db =
+            |provider| TrieDB::new(root, Header::default().seal_slow(), provider, NoopTrieHinter);
+
+        // With the full witness, the account is found, and a missing account is proven absent.
+        let mut db = new_db(provider.clone());
+        assert_eq!(db.get_trie_account(&present, 0).unwrap(), Some(TrieAccount::default()));
+        assert_eq!(db.get_trie_account(&absent, 0).unwrap(), None);
+
+        // With only the root node's preimage, the path to the account cannot be resolved.
+        provider.0.retain(|key, _| *key == root);
+        assert_eq!(
+            new_db(provider).get_trie_account(&present, 0).unwrap_err(),
+            TrieDBError::IncompleteProof(Nibbles::unpack(keccak256(present)))
+        );
+    }
 }
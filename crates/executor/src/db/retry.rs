@@ -0,0 +1,147 @@
+//! Contains the [RetryingTrieDBProvider], which retries transient [TrieDBProvider] failures.
+
+use crate::TrieDBProvider;
+use alloy_consensus::Header;
+use alloy_primitives::{Bytes, B256};
+use kona_mpt::{TrieNode, TrieProvider};
+
+/// A [TrieDBProvider] that retries failed requests to the wrapped provider.
+///
+/// Each request is attempted up to `max_retries + 1` times before the last error is returned.
+/// Before each retry, the backoff function is called with the number of the upcoming retry,
+/// starting at `1`, which may block for a backoff period. The default backoff retries immediately,
+/// as the executor has no notion of time in `no_std` environments.
+///
+/// This is intended for providers backed by remote sources, where failures may be transient.
+#[derive(Debug, Clone)]
+pub struct RetryingTrieDBProvider<F> {
+    /// The wrapped [TrieDBProvider].
+    inner: F,
+    /// The maximum number of retries per request.
+    max_retries: u32,
+    /// The backoff function, called with the number of the upcoming retry.
+    backoff: fn(u32),
+}
+
+impl<F> RetryingTrieDBProvider<F> {
+    /// Creates a new [RetryingTrieDBProvider] that retries each failed request to `inner` up to
+    /// `max_retries` times.
+    pub const fn new(inner: F, max_retries: u32) -> Self {
+        Self { inner, max_retries, backoff: |_| {} }
+    }
+
+    /// Sets the backoff function, which is called with the number of the upcoming retry before
+    /// each retry.
+    pub const fn with_backoff(mut self, backoff: fn(u32)) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Returns a reference to the wrapped provider.
+    pub const fn inner(&self) -> &F {
+        &self.inner
+    }
+
+    /// Calls `f` until it succeeds, or until the maximum number of retries is exhausted.
+    fn retry<T, E: core::fmt::Display>(&self, mut f: impl FnMut() -> Result<T, E>) -> Result<T, E> {
+        let mut retries = 0;
+        loop {
+            match f() {
+                Ok(value) => return Ok(value),
+                Err(e) if retries < self.max_retries => {
+                    retries += 1;
+                    warn!(
+                        target: "client_executor",
+                        "Provider request failed, retrying ({retries}/{max}): {e}",
+                        max = self.max_retries
+                    );
+                    (self.backoff)(retries);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+impl<F: TrieProvider> TrieProvider for RetryingTrieDBProvider<F> {
+    type Error = F::Error;
+
+    fn trie_node_by_hash(&self, key: B256) -> Result<TrieNode, Self::Error> {
+        self.retry(|| self.inner.trie_node_by_hash(key))
+    }
+}
+
+impl<F: TrieDBProvider> TrieDBProvider for RetryingTrieDBProvider<F> {
+    fn bytecode_by_hash(&self, code_hash: B256) -> Result<Bytes, Self::Error> {
+        self.retry(|| self.inner.bytecode_by_hash(code_hash))
+    }
+
+    fn header_by_hash(&self, hash: B256) -> Result<Header, Self::Error> {
+        self.retry(|| self.inner.header_by_hash(hash))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::{String, ToString};
+    use core::cell::Cell;
+
+    /// A provider that fails a fixed number of requests before succeeding.
+    #[derive(Debug, Default)]
+    struct FlakyProvider {
+        failures: Cell<u32>,
+        requests: Cell<u32>,
+    }
+
+    impl FlakyProvider {
+        fn request<T: Default>(&self) -> Result<T, String> {
+            self.requests.set(self.requests.get() + 1);
+            if self.failures.get() > 0 {
+                self.failures.set(self.failures.get() - 1);
+                return Err("connection reset".to_string());
+            }
+            Ok(T::default())
+        }
+    }
+
+    impl TrieProvider for FlakyProvider {
+        type Error = String;
+
+        fn trie_node_by_hash(&self, _: B256) -> Result<TrieNode, Self::Error> {
+            self.request::<()>().map(|_| TrieNode::Empty)
+        }
+    }
+
+    impl TrieDBProvider for FlakyProvider {
+        fn bytecode_by_hash(&self, _: B256) -> Result<Bytes, Self::Error> {
+            self.request()
+        }
+
+        fn header_by_hash(&self, _: B256) -> Result<Header, Self::Error> {
+            self.request()
+        }
+    }
+
+    fn flaky(failures: u32) -> FlakyProvider {
+        FlakyProvider { failures: Cell::new(failures), ..Default::default() }
+    }
+
+    #[test]
+    fn test_retry_transient_failure() {
+        let provider = RetryingTrieDBProvider::new(flaky(1), 3);
+        assert_eq!(provider.header_by_hash(B256::ZERO).unwrap(), Header::default());
+        assert_eq!(provider.inner().requests.get(), 2);
+
+        let provider = RetryingTrieDBProvider::new(flaky(1), 3);
+        assert_eq!(provider.trie_node_by_hash(B256::ZERO).unwrap(), TrieNode::Empty);
+        assert_eq!(provider.inner().requests.get(), 2);
+    }
+
+    #[test]
+    fn test_retry_exhausted() {
+        let provider = RetryingTrieDBProvider::new(flaky(3), 2);
+        assert_eq!(provider.bytecode_by_hash(B256::ZERO).unwrap_err(), "connection reset");
+        assert_eq!(provider.inner().requests.get(), 3);
+    }
+}
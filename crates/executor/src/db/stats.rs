@@ -0,0 +1,87 @@
+//! Diagnostics for the node cache of the [TrieDB].
+//!
+//! [TrieDB]: crate::TrieDB
+
+use alloc::vec::Vec;
+use alloy_primitives::B256;
+use core::cell::RefCell;
+use kona_mpt::{TrieNode, TrieProvider};
+
+/// A snapshot of the node cache churn of a [TrieDB], covering one state root computation.
+///
+/// [TrieDB]: crate::TrieDB
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TrieDBStats {
+    /// The number of trie nodes opened through the [TrieDBProvider] since the previous state root
+    /// computation, both by reads during execution and by the application of the changeset.
+    ///
+    /// [TrieDBProvider]: crate::TrieDBProvider
+    pub opened: u64,
+    /// The number of open trie nodes collapsed into their commitments when the state root was
+    /// computed.
+    pub blinded: u64,
+    /// The number of open account trie nodes dropped when the state root was computed. Only the
+    /// paths to the accounts touched by the changeset are re-opened afterwards.
+    pub discarded: u64,
+}
+
+/// A [TrieProvider] that records the keys of the trie nodes fetched through the wrapped provider.
+#[derive(Debug)]
+pub(crate) struct RecordingTrieProvider<'a, F> {
+    /// The wrapped [TrieProvider].
+    inner: &'a F,
+    /// The keys of the trie nodes fetched, in request order.
+    fetched: RefCell<Vec<B256>>,
+}
+
+impl<'a, F> RecordingTrieProvider<'a, F> {
+    /// Wraps the given [TrieProvider].
+    pub(crate) const fn new(inner: &'a F) -> Self {
+        Self { inner, fetched: RefCell::new(Vec::new()) }
+    }
+
+    /// Consumes the provider, returning the keys of the trie nodes fetched through it.
+    pub(crate) fn into_fetched(self) -> Vec<B256> {
+        self.fetched.into_inner()
+    }
+}
+
+impl<F: TrieProvider> TrieProvider for RecordingTrieProvider<'_, F> {
+    type Error = F::Error;
+
+    fn trie_node_by_hash(&self, key: B256) -> Result<TrieNode, Self::Error> {
+        self.fetched.borrow_mut().push(key);
+        self.inner.trie_node_by_hash(key)
+    }
+}
+
+/// Returns the number of open (non-blinded, non-empty) nodes in the trie rooted at `node`.
+pub(crate) fn open_node_count(node: &TrieNode) -> u64 {
+    match node {
+        TrieNode::Empty | TrieNode::Blinded { .. } => 0,
+        TrieNode::Leaf { .. } => 1,
+        TrieNode::Extension { node, .. } => 1 + open_node_count(node),
+        TrieNode::Branch { stack } => 1 + stack.iter().map(open_node_count).sum::<u64>(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use alloy_primitives::Bytes;
+    use kona_mpt::Nibbles;
+
+    #[test]
+    fn test_open_node_count() {
+        assert_eq!(open_node_count(&TrieNode::Empty), 0);
+        assert_eq!(open_node_count(&TrieNode::new_blinded(B256::ZERO)), 0);
+
+        let leaf = TrieNode::Leaf { prefix: Nibbles::default(), value: Bytes::from_static(&[1]) };
+        let mut stack = vec![TrieNode::Empty; 17];
+        stack[0] = leaf.clone();
+        stack[1] = TrieNode::new_blinded(B256::ZERO);
+        stack[2] = TrieNode::Extension { prefix: Nibbles::default(), node: leaf.into() };
+        assert_eq!(open_node_count(&TrieNode::Branch { stack }), 4);
+    }
+}
@@ -1,7 +1,8 @@
 //! Errors for the `kona-executor` crate.
 
 use alloc::string::String;
-use kona_mpt::TrieNodeError;
+use alloy_primitives::{Address, B256};
+use kona_mpt::{Nibbles, OrderedListWalkerError, TrieNodeError};
 use revm::primitives::EVMError;
 use thiserror::Error;
 
@@ -16,21 +17,64 @@ pub enum ExecutorError {
     /// Missing transactions in the payload attributes.
     #[error("Transactions not provided in payload attributes")]
     MissingTransactions,
+    /// The payload attributes contain an empty transaction list, and empty blocks are not allowed.
+    #[error("Payload contains no transactions, and empty blocks are not allowed")]
+    EmptyTransactions,
+    /// The payload attributes contain more transactions than the configured maximum.
+    #[error("Payload contains {count} transactions, exceeding the maximum of {max}")]
+    TooManyTransactions {
+        /// The number of transactions in the payload.
+        count: usize,
+        /// The maximum number of transactions per block.
+        max: usize,
+    },
+    /// The payload suggests a fee recipient other than the expected one.
+    #[error("Fee recipient mismatch: expected {expected}, got {actual}")]
+    FeeRecipientMismatch {
+        /// The expected fee recipient.
+        expected: Address,
+        /// The fee recipient suggested by the payload.
+        actual: Address,
+    },
     /// Missing EIP-1559 parameters in execution payload post-Holocene.
     #[error("Missing EIP-1559 parameters in execution payload post-Holocene")]
     MissingEIP1559Params,
     /// Missing parent beacon block root in the payload attributes.
     #[error("Parent beacon block root not provided in payload attributes")]
     MissingParentBeaconBlockRoot,
+    /// The parent block header is unset (default), so the next block's environment can't be
+    /// derived from it.
+    #[error("Invalid parent block header: the parent header is unset")]
+    InvalidParentHeader,
     /// Invalid `extraData` field in the block header.
     #[error("Invalid `extraData` field in the block header")]
     InvalidExtraData,
+    /// The payload contains more than one transaction with the given hash.
+    #[error("Duplicate transaction in payload: {0}")]
+    DuplicateTransaction(B256),
     /// Block gas limit exceeded.
     #[error("Block gas limit exceeded")]
     BlockGasLimitExceeded,
-    /// Unsupported transaction type.
-    #[error("Unsupported transaction type: {0}")]
-    UnsupportedTransactionType(u8),
+    /// A transaction in the payload has a type that is not supported by the active hardfork.
+    #[error("Unsupported transaction type {tx_type} at index {index}")]
+    UnsupportedTransactionType {
+        /// The EIP-2718 type of the unsupported transaction.
+        tx_type: u8,
+        /// The index of the transaction within the payload.
+        index: usize,
+    },
+    /// A user transaction is present in a payload built with the transaction pool disabled.
+    #[error("Unexpected user transaction at index {index} in a `no_tx_pool` payload")]
+    UnexpectedUserTransaction {
+        /// The index of the transaction within the payload.
+        index: usize,
+    },
+    /// The payload does not begin with a valid L1 info deposit transaction.
+    #[error("Payload does not begin with a valid L1 info deposit transaction")]
+    InvalidL1InfoTx,
+    /// The system config's fee scalar is of an unsupported version.
+    #[error("Unsupported system config scalar version: {0}")]
+    UnsupportedScalarVersion(u8),
     /// Trie DB error.
     #[error("Trie error: {0}")]
     TrieDBError(#[from] TrieDBError),
@@ -43,9 +87,48 @@ pub enum ExecutorError {
     /// RLP error.
     #[error("RLP error: {0}")]
     RLPError(alloy_eips::eip2718::Eip2718Error),
+    /// An inspector and a handle register are both set. The handle register is bound to an EVM
+    /// without an inspector, so it cannot be applied to the inspected EVM.
+    #[error("An inspector cannot be attached alongside a handle register")]
+    InspectorWithHandleRegister,
     /// Missing the executor.
     #[error("Missing the executor")]
     MissingExecutor,
+    /// A block fetched for execution does not build on the executor's parent block.
+    #[error("Block parent hash mismatch: expected {expected}, got {actual}")]
+    ParentHashMismatch {
+        /// The hash of the executor's parent block.
+        expected: B256,
+        /// The parent hash of the fetched block.
+        actual: B256,
+    },
+    /// The commitment of the trie DB's root node, recomputed from its witness, does not match the
+    /// state root of the parent header.
+    #[error("Starting state mismatch: parent state root {expected}, witness root {actual}")]
+    StartingStateMismatch {
+        /// The state root of the parent header.
+        expected: B256,
+        /// The commitment recomputed from the witness of the root node.
+        actual: B256,
+    },
+    /// The output root computed after executing a payload does not match the expected output root.
+    #[error("Output root mismatch: expected {expected}, got {actual}")]
+    OutputRootMismatch {
+        /// The expected output root.
+        expected: B256,
+        /// The computed output root.
+        actual: B256,
+    },
+    /// A receipt's cumulative gas used is lower than that of the receipt before it.
+    #[error("Non-monotonic cumulative gas used at receipt {index}: {previous} > {current}")]
+    NonMonotonicCumulativeGasUsed {
+        /// The index of the receipt within the block.
+        index: usize,
+        /// The cumulative gas used of the previous receipt.
+        previous: u64,
+        /// The cumulative gas used of the receipt.
+        current: u64,
+    },
 }
 
 /// A [Result] type for the [ExecutorError] enum.
@@ -71,4 +154,11 @@ pub enum TrieDBError {
     /// Trie provider error.
     #[error("Trie provider error: {0}")]
     Provider(String),
+    /// A trie node on the path to an account could not be fetched, so the account proof is
+    /// incomplete. The account may exist, and the proof can be re-hinted.
+    #[error("Incomplete proof for account path {0:?}")]
+    IncompleteProof(Nibbles),
+    /// Error walking an ordered list trie, such as a block's transactions trie.
+    #[error("Ordered list walker error: {0}")]
+    OrderedListWalker(#[from] OrderedListWalkerError),
 }
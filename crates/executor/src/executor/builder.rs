@@ -1,16 +1,79 @@
 //! Contains the builder pattern for the [StatelessL2BlockExecutor].
 
 use super::StatelessL2BlockExecutor;
-use crate::db::{TrieDB, TrieDBProvider};
+use crate::{
+    constants::{GAS_PRICE_ORACLE, L1_BLOCK_CONTRACT, L2_TO_L1_BRIDGE},
+    db::{TrieDB, TrieDBProvider},
+};
+use alloc::{boxed::Box, vec, vec::Vec};
 use alloy_consensus::{Header, Sealable, Sealed};
+use alloy_eips::eip4788::BEACON_ROOTS_ADDRESS;
+use alloy_primitives::Address;
+use core::{any::Any, fmt::Debug};
 use kona_mpt::TrieHinter;
 use maili_genesis::RollupConfig;
-use revm::{db::State, handler::register::EvmHandler};
+use revm::{db::State, handler::register::EvmHandler, primitives::SpecId, Inspector};
 
 /// A type alias for the [revm::handler::register::HandleRegister] for kona's block executor.
 pub type KonaHandleRegister<F, H> =
     for<'i> fn(&mut EvmHandler<'i, (), &mut State<&mut TrieDB<F, H>>>);
 
+/// A revm [Inspector] that can be attached to the EVM of the [StatelessL2BlockExecutor] to trace
+/// the execution of transactions.
+///
+/// Implemented for all `'static` [Inspector]s over the executor's database.
+pub trait KonaInspector<F, H>:
+    for<'a, 'b> Inspector<&'a mut State<&'b mut TrieDB<F, H>>> + Debug + Send + Sync
+where
+    F: TrieDBProvider,
+    H: TrieHinter,
+{
+    /// Returns the inspector as a mutable [Any], to downcast it to its concrete type.
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    /// Converts the boxed inspector into a boxed [Any], to downcast it to its concrete type.
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+}
+
+impl<F, H, I> KonaInspector<F, H> for I
+where
+    F: TrieDBProvider,
+    H: TrieHinter,
+    I: for<'a, 'b> Inspector<&'a mut State<&'b mut TrieDB<F, H>>> + Debug + Send + Sync + 'static,
+{
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+/// The verbosity of the per-transaction logs emitted by the [StatelessL2BlockExecutor].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TxLogVerbosity {
+    /// No per-transaction logs are emitted.
+    Off,
+    /// Per-transaction logs are emitted for every `n`th transaction in a block, starting with the
+    /// first. A rate of `0` emits no per-transaction logs.
+    Sampled(usize),
+    /// Per-transaction logs are emitted for every transaction.
+    #[default]
+    Full,
+}
+
+impl TxLogVerbosity {
+    /// Returns whether the transaction at the given index in a block is logged.
+    pub const fn is_logged(&self, index: usize) -> bool {
+        match self {
+            Self::Off => false,
+            Self::Sampled(rate) => matches!(index.checked_rem(*rate), Some(0)),
+            Self::Full => true,
+        }
+    }
+}
+
 /// The builder pattern for the [StatelessL2BlockExecutor].
 #[derive(Debug)]
 pub struct StatelessL2BlockExecutorBuilder<'a, F, H>
@@ -28,6 +91,46 @@ where
     parent_header: Option<Sealed<Header>>,
     /// The [KonaHandleRegister] to use during execution.
     handler_register: Option<KonaHandleRegister<F, H>>,
+    /// The [KonaInspector] attached to the EVM during execution.
+    inspector: Option<Box<dyn KonaInspector<F, H>>>,
+    /// The address of the EIP-4788 beacon roots contract.
+    beacon_roots_address: Address,
+    /// Whether the EIP-4788 pre-block beacon root contract call is applied.
+    beacon_roots_call: bool,
+    /// The address of the L2 to L1 message passer.
+    message_passer_address: Address,
+    /// The [SpecId] to execute blocks with, regardless of their timestamp.
+    spec_override: Option<SpecId>,
+    /// The chain id of the EVM environment, overriding the [RollupConfig]'s `l2_chain_id`.
+    chain_id_override: Option<u64>,
+    /// Whether payloads with no transactions may be executed.
+    allow_empty_blocks: bool,
+    /// Whether user transactions are rejected in payloads with `no_tx_pool` set.
+    enforce_no_tx_pool: bool,
+    /// Whether payloads must begin with the L1 info deposit transaction.
+    require_l1_info_tx: bool,
+    /// Whether payloads containing duplicate transactions are rejected.
+    reject_duplicate_transactions: bool,
+    /// The minimum base fee of executed blocks.
+    min_base_fee: Option<u64>,
+    /// The maximum number of transactions in an executed payload.
+    max_transactions_per_block: Option<usize>,
+    /// The fee recipient that executed payloads must suggest, if enforced.
+    expected_fee_recipient: Option<Address>,
+    /// The addresses whose account proofs are hinted before executing a payload's transactions.
+    prewarm_addresses: Vec<Address>,
+    /// Whether [TrieDBStats] are collected on the node cache of the [TrieDB].
+    ///
+    /// [TrieDBStats]: crate::TrieDBStats
+    trie_db_stats: bool,
+    /// Whether to record the keys of the preimages touched by each executed block.
+    touched_keys: bool,
+    /// Whether block hashes older than [BLOCK_HASH_HISTORY] blocks are resolved by the [TrieDB].
+    ///
+    /// [BLOCK_HASH_HISTORY]: revm::primitives::BLOCK_HASH_HISTORY
+    historical_block_hashes: bool,
+    /// The verbosity of the per-transaction logs.
+    tx_log_verbosity: TxLogVerbosity,
 }
 
 impl<'a, F, H> StatelessL2BlockExecutorBuilder<'a, F, H>
@@ -37,7 +140,31 @@ where
 {
     /// Instantiate a new builder with the given [RollupConfig].
     pub fn new(config: &'a RollupConfig, provider: F, hinter: H) -> Self {
-        Self { config, provider, hinter, parent_header: None, handler_register: None }
+        Self {
+            config,
+            provider,
+            hinter,
+            parent_header: None,
+            handler_register: None,
+            inspector: None,
+            beacon_roots_address: BEACON_ROOTS_ADDRESS,
+            beacon_roots_call: true,
+            message_passer_address: L2_TO_L1_BRIDGE,
+            spec_override: None,
+            chain_id_override: None,
+            allow_empty_blocks: false,
+            enforce_no_tx_pool: false,
+            require_l1_info_tx: false,
+            reject_duplicate_transactions: false,
+            min_base_fee: None,
+            max_transactions_per_block: None,
+            expected_fee_recipient: None,
+            prewarm_addresses: vec![L1_BLOCK_CONTRACT, GAS_PRICE_ORACLE, L2_TO_L1_BRIDGE],
+            tx_log_verbosity: TxLogVerbosity::Full,
+            trie_db_stats: false,
+            touched_keys: false,
+            historical_block_hashes: false,
+        }
     }
 
     /// Set the [Header] to begin execution from.
@@ -52,6 +179,186 @@ where
         self
     }
 
+    /// Attach a [KonaInspector] to the EVM, to trace the execution of each payload's transactions.
+    /// Defaults to no inspector.
+    ///
+    /// The inspector observes the transactions of the payload, but not the pre-block system
+    /// calls. As the [KonaHandleRegister] is bound to an EVM without an inspector, execution fails
+    /// with an [`InspectorWithHandleRegister`](crate::ExecutorError::InspectorWithHandleRegister)
+    /// error if a handle register is also set. The inspector can be retrieved after execution with
+    /// [StatelessL2BlockExecutor::inspector_mut] or [StatelessL2BlockExecutor::into_inspector].
+    pub fn with_inspector(mut self, inspector: impl KonaInspector<F, H> + 'static) -> Self {
+        self.inspector = Some(Box::new(inspector));
+        self
+    }
+
+    /// Set the address of the EIP-4788 beacon roots contract. Defaults to the canonical
+    /// [BEACON_ROOTS_ADDRESS].
+    pub const fn with_beacon_roots_address(mut self, beacon_roots_address: Address) -> Self {
+        self.beacon_roots_address = beacon_roots_address;
+        self
+    }
+
+    /// Set whether the EIP-4788 pre-block beacon root contract call is applied once Ecotone is
+    /// active. Defaults to `true`.
+    ///
+    /// Disabling the call supports chains that are deployed without the beacon roots contract.
+    pub const fn with_beacon_roots_call(mut self, beacon_roots_call: bool) -> Self {
+        self.beacon_roots_call = beacon_roots_call;
+        self
+    }
+
+    /// Set the address of the L2 to L1 message passer, whose storage root is committed to in output
+    /// roots and, post-Isthmus, the block's withdrawals root. Defaults to the canonical
+    /// `0x4200000000000000000000000000000000000016` predeploy.
+    pub const fn with_message_passer_address(mut self, message_passer_address: Address) -> Self {
+        self.message_passer_address = message_passer_address;
+        self
+    }
+
+    /// Allow payloads with an empty transaction list to be executed. Such payloads produce a
+    /// block with empty transaction and receipt roots. Defaults to `false`.
+    ///
+    /// Payloads with an absent transaction list are always rejected.
+    pub const fn with_allow_empty_blocks(mut self, allow_empty_blocks: bool) -> Self {
+        self.allow_empty_blocks = allow_empty_blocks;
+        self
+    }
+
+    /// Reject payloads with `no_tx_pool` set that contain user (non-deposit) transactions.
+    /// Defaults to `false`.
+    ///
+    /// This is intended for sequencer tooling executing attributes templates, which only carry
+    /// deposits. Derived payloads also set `no_tx_pool` while including batched user
+    /// transactions, so enforcement must remain disabled when executing them.
+    pub const fn with_enforce_no_tx_pool(mut self, enforce_no_tx_pool: bool) -> Self {
+        self.enforce_no_tx_pool = enforce_no_tx_pool;
+        self
+    }
+
+    /// Reject payloads whose first transaction is not the L1 info deposit, sent by the L1 info
+    /// depositor account to the `L1Block` predeploy. Defaults to `false`.
+    ///
+    /// Every derived L2 block after genesis begins with the L1 info deposit, so a payload lacking
+    /// it stems from a malformed batch.
+    pub const fn with_require_l1_info_tx(mut self, require_l1_info_tx: bool) -> Self {
+        self.require_l1_info_tx = require_l1_info_tx;
+        self
+    }
+
+    /// Reject payloads containing more than one transaction with the same hash, before any of
+    /// them are executed. Defaults to `false`.
+    ///
+    /// A duplicated transaction stems from a malformed batch, and would otherwise be executed
+    /// against the state left behind by its first occurrence.
+    pub const fn with_reject_duplicate_transactions(
+        mut self,
+        reject_duplicate_transactions: bool,
+    ) -> Self {
+        self.reject_duplicate_transactions = reject_duplicate_transactions;
+        self
+    }
+
+    /// Set a minimum base fee, which the base fee computed from the EIP-1559 parameters is clamped
+    /// to. Defaults to no minimum.
+    ///
+    /// This supports chains that enforce a base fee floor beyond the [RollupConfig].
+    pub const fn with_min_base_fee(mut self, min_base_fee: u64) -> Self {
+        self.min_base_fee = Some(min_base_fee);
+        self
+    }
+
+    /// Set the chain id of the EVM environment, overriding the `l2_chain_id` of the
+    /// [RollupConfig]. Defaults to the [RollupConfig]'s chain id.
+    ///
+    /// This allows executing blocks of a forked chain, such as when testing replay protection.
+    pub const fn with_chain_id(mut self, chain_id: u64) -> Self {
+        self.chain_id_override = Some(chain_id);
+        self
+    }
+
+    /// Set the maximum number of transactions in a payload. Payloads with more transactions are
+    /// rejected before any of them are decoded or executed. Defaults to no limit.
+    ///
+    /// This guards against adversarially large derived blocks.
+    pub const fn with_max_transactions_per_block(mut self, max_transactions: usize) -> Self {
+        self.max_transactions_per_block = Some(max_transactions);
+        self
+    }
+
+    /// Require the `suggested_fee_recipient` of executed payloads to be the given address.
+    /// Payloads suggesting any other fee recipient are rejected before execution. Defaults to no
+    /// requirement.
+    ///
+    /// This is intended for chains that mandate the sequencer fee vault as the coinbase.
+    pub const fn with_expected_fee_recipient(mut self, fee_recipient: Address) -> Self {
+        self.expected_fee_recipient = Some(fee_recipient);
+        self
+    }
+
+    /// Set the addresses whose account proofs are hinted before executing a payload's
+    /// transactions. Defaults to the `L1Block`, `GasPriceOracle`, and `L2ToL1MessagePasser`
+    /// predeploys, which are touched in nearly every block.
+    ///
+    /// Hinting these proofs up front allows the host to fetch them ahead of the serial proof
+    /// fetches made during execution.
+    pub fn with_prewarm_addresses(mut self, prewarm_addresses: Vec<Address>) -> Self {
+        self.prewarm_addresses = prewarm_addresses;
+        self
+    }
+
+    /// Collect [TrieDBStats] on the node cache of the [TrieDB] for each executed block. Defaults to
+    /// `false`.
+    ///
+    /// The stats are diagnostics-only, and can be read back with
+    /// [StatelessL2BlockExecutor::trie_db_stats].
+    ///
+    /// [TrieDBStats]: crate::TrieDBStats
+    pub const fn with_trie_db_stats(mut self, trie_db_stats: bool) -> Self {
+        self.trie_db_stats = trie_db_stats;
+        self
+    }
+
+    /// Record the keys of the preimages touched by each executed block. Defaults to `false`.
+    ///
+    /// The keys can be read back with [StatelessL2BlockExecutor::touched_keys].
+    pub const fn with_touched_keys(mut self, touched_keys: bool) -> Self {
+        self.touched_keys = touched_keys;
+        self
+    }
+
+    /// Resolve block hashes older than [BLOCK_HASH_HISTORY] blocks in the [TrieDB], rather than
+    /// returning the zero hash for them. Defaults to `false`.
+    ///
+    /// The headers of the ancestors are hinted before they are walked back, so that the host can
+    /// fetch them in bulk. The `BLOCKHASH` opcode is unaffected, as the EVM bounds its lookups to
+    /// the history window before consulting the database.
+    ///
+    /// [BLOCK_HASH_HISTORY]: revm::primitives::BLOCK_HASH_HISTORY
+    pub const fn with_historical_block_hashes(mut self, historical_block_hashes: bool) -> Self {
+        self.historical_block_hashes = historical_block_hashes;
+        self
+    }
+
+    /// Set the verbosity of the per-transaction logs. Defaults to [TxLogVerbosity::Full].
+    ///
+    /// Large blocks may be logged with [TxLogVerbosity::Sampled] or [TxLogVerbosity::Off] to avoid
+    /// flooding the logs.
+    pub const fn with_tx_log_verbosity(mut self, tx_log_verbosity: TxLogVerbosity) -> Self {
+        self.tx_log_verbosity = tx_log_verbosity;
+        self
+    }
+
+    /// Force all blocks to be executed with the given [SpecId], regardless of the hardforks
+    /// active at their timestamp in the [RollupConfig].
+    ///
+    /// This only affects the EVM rules, and is intended for testing hardfork behavior in isolation.
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn with_spec_override(mut self, spec_id: SpecId) -> Self {
+        self.spec_override = Some(spec_id);
+        self
+    }
+
     /// Build the [StatelessL2BlockExecutor] from the builder configuration.
     pub fn build(self) -> StatelessL2BlockExecutor<'a, F, H> {
         let parent_header = self.parent_header.unwrap_or_else(|| {
@@ -59,12 +366,36 @@ where
             default_header.seal_slow()
         });
 
-        let trie_db =
+        let mut trie_db =
             TrieDB::new(parent_header.state_root, parent_header, self.provider, self.hinter);
+        if self.trie_db_stats {
+            trie_db.enable_stats();
+        }
+        if self.touched_keys {
+            trie_db.enable_touched_keys();
+        }
+        if self.historical_block_hashes {
+            trie_db.enable_historical_block_hashes();
+        }
         StatelessL2BlockExecutor {
             config: self.config,
             trie_db,
             handler_register: self.handler_register,
+            inspector: self.inspector,
+            beacon_roots_address: self.beacon_roots_address,
+            beacon_roots_call: self.beacon_roots_call,
+            message_passer_address: self.message_passer_address,
+            spec_override: self.spec_override,
+            chain_id_override: self.chain_id_override,
+            allow_empty_blocks: self.allow_empty_blocks,
+            enforce_no_tx_pool: self.enforce_no_tx_pool,
+            require_l1_info_tx: self.require_l1_info_tx,
+            reject_duplicate_transactions: self.reject_duplicate_transactions,
+            min_base_fee: self.min_base_fee,
+            max_transactions_per_block: self.max_transactions_per_block,
+            expected_fee_recipient: self.expected_fee_recipient,
+            prewarm_addresses: self.prewarm_addresses,
+            tx_log_verbosity: self.tx_log_verbosity,
         }
     }
 }
@@ -94,5 +425,47 @@ mod tests {
 
         assert_eq!(*executor.config, config);
         assert_eq!(*executor.trie_db.parent_block_header(), parent_header);
+        assert_eq!(executor.beacon_roots_address, BEACON_ROOTS_ADDRESS);
+        assert!(executor.beacon_roots_call);
+        assert_eq!(executor.message_passer_address, L2_TO_L1_BRIDGE);
+        assert!(!executor.allow_empty_blocks);
+        assert!(!executor.enforce_no_tx_pool);
+        assert!(!executor.require_l1_info_tx);
+        assert!(!executor.reject_duplicate_transactions);
+        assert_eq!(executor.chain_id_override, None);
+        assert_eq!(executor.min_base_fee, None);
+        assert_eq!(executor.max_transactions_per_block, None);
+        assert_eq!(executor.expected_fee_recipient, None);
+        assert_eq!(
+            executor.prewarm_addresses,
+            [L1_BLOCK_CONTRACT, GAS_PRICE_ORACLE, L2_TO_L1_BRIDGE]
+        );
+        assert_eq!(executor.tx_log_verbosity, TxLogVerbosity::Full);
+        assert!(executor.inspector.is_none());
+    }
+
+    #[test]
+    fn test_build_custom_beacon_roots_address() {
+        let config = RollupConfig::default();
+        let beacon_roots_address = Address::repeat_byte(0x11);
+
+        let executor =
+            StatelessL2BlockExecutorBuilder::new(&config, NoopTrieDBProvider, NoopTrieHinter)
+                .with_beacon_roots_address(beacon_roots_address)
+                .build();
+
+        assert_eq!(executor.beacon_roots_address, beacon_roots_address);
+    }
+
+    #[test]
+    fn test_build_allow_empty_blocks() {
+        let config = RollupConfig::default();
+
+        let executor =
+            StatelessL2BlockExecutorBuilder::new(&config, NoopTrieDBProvider, NoopTrieHinter)
+                .with_allow_empty_blocks(true)
+                .build();
+
+        assert!(executor.allow_empty_blocks);
     }
 }
@@ -1,6 +1,9 @@
 //! Environment preparation for the executor.
 
-use super::{util::decode_holocene_eip_1559_params, StatelessL2BlockExecutor};
+use super::{
+    util::{decode_holocene_eip_1559_params, deposit_context},
+    StatelessL2BlockExecutor,
+};
 use crate::{constants::FEE_RECIPIENT, ExecutorError, ExecutorResult, TrieDBProvider};
 use alloy_consensus::Header;
 use alloy_eips::{eip1559::BaseFeeParams, eip7840::BlobParams};
@@ -10,8 +13,8 @@ use maili_genesis::RollupConfig;
 use op_alloy_consensus::OpTxEnvelope;
 use op_alloy_rpc_types_engine::OpPayloadAttributes;
 use revm::primitives::{
-    AuthorizationList, BlobExcessGasAndPrice, BlockEnv, CfgEnv, CfgEnvWithHandlerCfg,
-    OptimismFields, SpecId, TransactTo, TxEnv,
+    AuthorizationList, BlobExcessGasAndPrice, BlockEnv, CfgEnv, CfgEnvWithHandlerCfg, SpecId,
+    TransactTo, TxEnv,
 };
 
 impl<P, H> StatelessL2BlockExecutor<'_, P, H>
@@ -19,6 +22,20 @@ where
     P: TrieDBProvider,
     H: TrieHinter,
 {
+    /// Returns the active [SpecId] for the executor, honoring the spec override if one is set.
+    ///
+    /// From Isthmus, the [SpecId] includes the Prague rules, under which revm charges each
+    /// transaction at least the EIP-7623 calldata floor.
+    ///
+    /// ## Takes
+    /// - `timestamp`: The timestamp of the executing block.
+    ///
+    /// ## Returns
+    /// The active [SpecId] for the executor.
+    pub(crate) fn spec_id(&self, timestamp: u64) -> SpecId {
+        self.spec_override.unwrap_or_else(|| self.config.spec_id(timestamp))
+    }
+
     /// Returns the active [CfgEnvWithHandlerCfg] for the executor.
     ///
     /// ## Takes
@@ -27,9 +44,10 @@ where
     /// ## Returns
     /// The active [CfgEnvWithHandlerCfg] for the executor.
     pub(crate) fn evm_cfg_env(&self, timestamp: u64) -> CfgEnvWithHandlerCfg {
-        let cfg_env = CfgEnv::default().with_chain_id(self.config.l2_chain_id);
+        let chain_id = self.chain_id_override.unwrap_or(self.config.l2_chain_id);
+        let cfg_env = CfgEnv::default().with_chain_id(chain_id);
         let mut cfg_handler_env =
-            CfgEnvWithHandlerCfg::new_with_spec_id(cfg_env, self.config.spec_id(timestamp));
+            CfgEnvWithHandlerCfg::new_with_spec_id(cfg_env, self.spec_id(timestamp));
         cfg_handler_env.enable_optimism();
         cfg_handler_env
     }
@@ -41,18 +59,22 @@ where
     /// - `parent_header`: The parent header of the block to be executed.
     /// - `payload_attrs`: The payload to prepare the environment for.
     /// - `base_fee_params`: The active base fee parameters for the block.
+    /// - `min_base_fee`: The optional minimum that the computed base fee is clamped to.
     pub(crate) fn prepare_block_env(
         spec_id: SpecId,
         parent_header: &Header,
         payload_attrs: &OpPayloadAttributes,
         base_fee_params: &BaseFeeParams,
+        min_base_fee: Option<u64>,
     ) -> ExecutorResult<BlockEnv> {
         let blob_excess_gas_and_price = parent_header
             .next_block_excess_blob_gas(BlobParams::cancun())
             .or_else(|| spec_id.is_enabled_in(SpecId::ECOTONE).then_some(0))
             .map(|e| BlobExcessGasAndPrice::new(e, spec_id.is_enabled_in(SpecId::PRAGUE)));
-        let next_block_base_fee =
-            parent_header.next_block_base_fee(*base_fee_params).unwrap_or_default();
+        let next_block_base_fee = parent_header
+            .next_block_base_fee(*base_fee_params)
+            .unwrap_or_default()
+            .max(min_base_fee.unwrap_or_default());
 
         Ok(BlockEnv {
             number: U256::from(parent_header.number + 1),
@@ -132,12 +154,7 @@ where
                 env.access_list.clear();
                 env.blob_hashes.clear();
                 env.max_fee_per_blob_gas.take();
-                env.optimism = OptimismFields {
-                    source_hash: None,
-                    mint: None,
-                    is_system_transaction: Some(false),
-                    enveloped_tx: Some(encoded_transaction.to_vec().into()),
-                };
+                env.optimism = deposit_context(transaction, encoded_transaction);
                 Ok(env)
             }
             OpTxEnvelope::Eip2930(signed_tx) => {
@@ -157,12 +174,7 @@ where
                 env.access_list = tx.access_list.to_vec();
                 env.blob_hashes.clear();
                 env.max_fee_per_blob_gas.take();
-                env.optimism = OptimismFields {
-                    source_hash: None,
-                    mint: None,
-                    is_system_transaction: Some(false),
-                    enveloped_tx: Some(encoded_transaction.to_vec().into()),
-                };
+                env.optimism = deposit_context(transaction, encoded_transaction);
                 Ok(env)
             }
             OpTxEnvelope::Eip1559(signed_tx) => {
@@ -182,12 +194,7 @@ where
                 env.access_list = tx.access_list.to_vec();
                 env.blob_hashes.clear();
                 env.max_fee_per_blob_gas.take();
-                env.optimism = OptimismFields {
-                    source_hash: None,
-                    mint: None,
-                    is_system_transaction: Some(false),
-                    enveloped_tx: Some(encoded_transaction.to_vec().into()),
-                };
+                env.optimism = deposit_context(transaction, encoded_transaction);
                 Ok(env)
             }
             OpTxEnvelope::Eip7702(signed_tx) => {
@@ -206,12 +213,7 @@ where
                 env.max_fee_per_blob_gas.take();
                 env.authorization_list =
                     Some(AuthorizationList::Signed(tx.authorization_list.to_vec()));
-                env.optimism = OptimismFields {
-                    source_hash: None,
-                    mint: None,
-                    is_system_transaction: Some(false),
-                    enveloped_tx: Some(encoded_transaction.to_vec().into()),
-                };
+                env.optimism = deposit_context(transaction, encoded_transaction);
                 Ok(env)
             }
             OpTxEnvelope::Deposit(tx) => {
@@ -228,12 +230,7 @@ where
                 env.data = tx.input.clone();
                 env.chain_id = None;
                 env.nonce = None;
-                env.optimism = OptimismFields {
-                    source_hash: Some(tx.source_hash),
-                    mint: tx.mint,
-                    is_system_transaction: Some(tx.is_system_transaction),
-                    enveloped_tx: Some(encoded_transaction.to_vec().into()),
-                };
+                env.optimism = deposit_context(transaction, encoded_transaction);
                 Ok(env)
             }
         }
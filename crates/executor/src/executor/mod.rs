@@ -1,38 +1,55 @@
 //! A stateless block executor for the OP Stack.
 
 use crate::{
-    constants::{L2_TO_L1_BRIDGE, OUTPUT_ROOT_VERSION, SHA256_EMPTY},
+    constants::{L1_BLOCK_CONTRACT, L1_INFO_DEPOSITOR, OUTPUT_ROOT_VERSION, SHA256_EMPTY},
     db::TrieDB,
-    errors::TrieDBError,
     syscalls::{
         ensure_create2_deployer_canyon, pre_block_beacon_root_contract_call,
         pre_block_block_hash_contract_call,
     },
-    ExecutorError, ExecutorResult, TrieDBProvider,
+    ExecutorError, ExecutorResult, TrieDBProvider, TrieDBStats,
 };
-use alloc::vec::Vec;
+use alloc::{boxed::Box, collections::BTreeSet, vec::Vec};
 use alloy_consensus::{
     Header, Sealable, Sealed, Transaction, EMPTY_OMMER_ROOT_HASH, EMPTY_ROOT_HASH,
 };
-use alloy_eips::eip2718::{Decodable2718, Encodable2718};
-use alloy_primitives::{keccak256, logs_bloom, Bytes, Log, B256, U256};
+use alloy_eips::eip2718::Decodable2718;
+use alloy_primitives::{keccak256, Address, Bytes, TxKind, B256, B64, U256};
+use alloy_rpc_types_engine::PayloadAttributes;
 use kona_mpt::{ordered_trie_with_encoder, TrieHinter};
 use maili_genesis::RollupConfig;
 use op_alloy_consensus::{OpReceiptEnvelope, OpTxEnvelope};
 use op_alloy_rpc_types_engine::OpPayloadAttributes;
 use revm::{
     db::{states::bundle_state::BundleRetention, State},
-    primitives::{calc_excess_blob_gas, EnvWithHandlerCfg},
+    inspector_handle_register,
+    primitives::{EnvWithHandlerCfg, SpecId},
     Evm,
 };
 
 mod builder;
-pub use builder::{KonaHandleRegister, StatelessL2BlockExecutorBuilder};
+pub use builder::{
+    KonaHandleRegister, KonaInspector, StatelessL2BlockExecutorBuilder, TxLogVerbosity,
+};
 
 mod env;
 
+mod operator_fee;
+use operator_fee::operator_fee_handle_register;
+
+mod output_root;
+pub use output_root::{OutputRootComponent, OutputRootComponents};
+
+mod receipts;
+use receipts::compute_receipts_root;
+pub use receipts::{compute_receipts_root_from_results, gas_used_from_receipts, TxExecutionResult};
+
 mod util;
-use util::encode_holocene_eip_1559_params;
+use util::{
+    compute_excess_blob_gas, compute_logs_bloom, encode_holocene_eip_1559_params,
+    is_block_gas_limit_exempt, message_passer_storage_root,
+};
+pub use util::{verify_logs_bloom, withdrawals_root_from_state};
 
 /// The [ExecutionArtifacts] holds the produced block header and receipts from the execution of a
 /// block.
@@ -42,6 +59,9 @@ pub struct ExecutionArtifacts {
     pub block_header: Sealed<Header>,
     /// The receipts generated during execution.
     pub receipts: Vec<OpReceiptEnvelope>,
+    /// Whether the payload was built with the transaction pool disabled (`no_tx_pool`), i.e. its
+    /// transaction list was fully determined by the payload attributes.
+    pub no_tx_pool: bool,
 }
 
 /// The block executor for the L2 client program. Operates off of a [TrieDB] backed [State],
@@ -58,6 +78,37 @@ where
     trie_db: TrieDB<F, H>,
     /// The [KonaHandleRegister] to use during execution.
     handler_register: Option<KonaHandleRegister<F, H>>,
+    /// The [KonaInspector] attached to the EVM during execution.
+    inspector: Option<Box<dyn KonaInspector<F, H>>>,
+    /// The address of the EIP-4788 beacon roots contract.
+    beacon_roots_address: Address,
+    /// Whether the EIP-4788 pre-block beacon root contract call is applied.
+    beacon_roots_call: bool,
+    /// The address of the L2 to L1 message passer, whose storage root is committed to in output
+    /// roots.
+    message_passer_address: Address,
+    /// The [SpecId] to execute blocks with, regardless of their timestamp.
+    spec_override: Option<SpecId>,
+    /// The chain id of the EVM environment, overriding the [RollupConfig]'s `l2_chain_id`.
+    chain_id_override: Option<u64>,
+    /// Whether payloads with no transactions may be executed.
+    allow_empty_blocks: bool,
+    /// Whether user transactions are rejected in payloads with `no_tx_pool` set.
+    enforce_no_tx_pool: bool,
+    /// Whether payloads must begin with the L1 info deposit transaction.
+    require_l1_info_tx: bool,
+    /// Whether payloads containing duplicate transactions are rejected.
+    reject_duplicate_transactions: bool,
+    /// The minimum base fee of executed blocks.
+    min_base_fee: Option<u64>,
+    /// The maximum number of transactions in an executed payload.
+    max_transactions_per_block: Option<usize>,
+    /// The fee recipient that executed payloads must suggest, if enforced.
+    expected_fee_recipient: Option<Address>,
+    /// The addresses whose account proofs are hinted before executing a payload's transactions.
+    prewarm_addresses: Vec<Address>,
+    /// The verbosity of the per-transaction logs.
+    tx_log_verbosity: TxLogVerbosity,
 }
 
 impl<'a, F, H> StatelessL2BlockExecutor<'a, F, H>
@@ -74,42 +125,97 @@ where
         StatelessL2BlockExecutorBuilder::new(config, provider, hinter)
     }
 
-    /// Fetches the L2 to L1 message passer account from the cache or underlying trie.
-    fn message_passer_account(
-        db: &mut TrieDB<F, H>,
-        block_number: u64,
-    ) -> Result<B256, TrieDBError> {
-        match db.storage_roots().get(&L2_TO_L1_BRIDGE) {
-            Some(storage_root) => {
-                storage_root.blinded_commitment().ok_or(TrieDBError::RootNotBlinded)
-            }
-            None => Ok(db
-                .get_trie_account(&L2_TO_L1_BRIDGE, block_number)?
-                .ok_or(TrieDBError::MissingAccountInfo)?
-                .storage_root),
+    /// Returns the current parent block header of the executor. After a payload has been
+    /// executed, this is the sealed header of the produced block.
+    pub const fn parent_header(&self) -> &Sealed<Header> {
+        self.trie_db.parent_block_header()
+    }
+
+    /// Returns the [TrieDBStats] of the last executed block, if stats collection was enabled with
+    /// [StatelessL2BlockExecutorBuilder::with_trie_db_stats].
+    pub const fn trie_db_stats(&self) -> Option<TrieDBStats> {
+        self.trie_db.stats()
+    }
+
+    /// Returns the keccak256 hashes of the preimages touched by the last call to
+    /// [Self::execute_payload], and by any [Self::compute_output_root] call since, if recording
+    /// was enabled with [StatelessL2BlockExecutorBuilder::with_touched_keys].
+    ///
+    /// The set covers the parent header, the trie nodes and bytecode fetched during execution and
+    /// state root computation, and the ancestor headers walked for `BLOCKHASH`. Unlike a full
+    /// witness, the preimages themselves are not retained.
+    pub const fn touched_keys(&self) -> Option<&BTreeSet<B256>> {
+        self.trie_db.touched_keys()
+    }
+
+    /// Returns a mutable reference to the [KonaInspector] attached with
+    /// [StatelessL2BlockExecutorBuilder::with_inspector], if it is of type `I`.
+    pub fn inspector_mut<I: KonaInspector<F, H> + 'static>(&mut self) -> Option<&mut I> {
+        self.inspector.as_mut()?.as_any_mut().downcast_mut()
+    }
+
+    /// Consumes the executor, returning the [KonaInspector] attached with
+    /// [StatelessL2BlockExecutorBuilder::with_inspector], if it is of type `I`.
+    pub fn into_inspector<I: KonaInspector<F, H> + 'static>(self) -> Option<I> {
+        self.inspector?.into_any().downcast().ok().map(|inspector| *inspector)
+    }
+
+    /// Verifies that the starting state of the executor's trie DB matches the state root of the
+    /// parent header, catching a wrong or corrupted witness before execution.
+    ///
+    /// The root node's preimage is fetched from the provider and re-blinded, and its commitment is
+    /// compared against the parent header's `state_root`.
+    ///
+    /// ## Returns
+    /// - `Ok(())`: The starting state matches the parent header.
+    /// - `Err(ExecutorError::StartingStateMismatch { .. })`: The recomputed root does not match.
+    /// - `Err(_)`: The root node's preimage could not be fetched.
+    pub fn verify_starting_state(&self) -> ExecutorResult<()> {
+        let expected = self.trie_db.parent_block_header().state_root;
+        let actual = self.trie_db.root_commitment_from_witness()?;
+        if actual != expected {
+            return Err(ExecutorError::StartingStateMismatch { expected, actual });
         }
+        Ok(())
     }
 
     /// Executes the given block, returning the resulting state root.
     ///
     /// ## Steps
     /// 1. Prepare the block environment.
-    /// 2. Apply the pre-block EIP-4788 contract call.
-    /// 3. Prepare the EVM with the given L2 execution payload in the block environment.
+    /// 2. Hint the account proofs of the prewarm addresses.
+    /// 3. Apply the pre-block EIP-4788 contract call, if enabled.
+    /// 4. Prepare the EVM with the given L2 execution payload in the block environment.
+    ///     - If a maximum is set, reject payloads with more transactions than it.
     ///     - Reject any EIP-4844 transactions, as they are not supported on the OP Stack.
+    ///     - If required, reject payloads that do not begin with the L1 info deposit.
+    ///     - If `no_tx_pool` enforcement is enabled, reject any user transactions in a payload with
+    ///       `no_tx_pool` set.
     ///     - If the transaction is a deposit, cache the depositor account prior to execution.
     ///     - Construct the EVM with the given configuration.
     ///     - Execute the transaction.
     ///     - Accumulate the gas used by the transaction to the block-scoped cumulative gas used
     ///       counter.
     ///     - Create a receipt envelope for the transaction.
-    /// 4. Merge all state transitions into the cache state.
-    /// 5. Compute the [state root, transactions root, receipts root, logs bloom] for the processed
+    /// 5. Merge all state transitions into the cache state.
+    /// 6. Compute the [state root, transactions root, receipts root, logs bloom] for the processed
     ///    block.
     pub fn execute_payload(
         &mut self,
         payload: OpPayloadAttributes,
     ) -> ExecutorResult<ExecutionArtifacts> {
+        // The block environment is derived from the parent header. Executed blocks are never the
+        // genesis block, so an unset parent header would silently produce a zeroed environment.
+        if *self.trie_db.parent_block_header().inner() == Header::default() {
+            return Err(ExecutorError::InvalidParentHeader);
+        }
+        // The handle register is bound to an EVM without an external context, so it cannot be
+        // applied in full to the inspected EVM.
+        if self.inspector.is_some() && self.handler_register.is_some() {
+            return Err(ExecutorError::InspectorWithHandleRegister);
+        }
+        self.trie_db.reset_touched_keys();
+
         // Prepare the `revm` environment.
         let base_fee_params = Self::active_base_fee_params(
             self.config,
@@ -117,10 +223,11 @@ where
             &payload,
         )?;
         let initialized_block_env = Self::prepare_block_env(
-            self.config.spec_id(payload.payload_attributes.timestamp),
+            self.spec_id(payload.payload_attributes.timestamp),
             self.trie_db.parent_block_header(),
             &payload,
             &base_fee_params,
+            self.min_base_fee,
         )?;
         let initialized_cfg = self.evm_cfg_env(payload.payload_attributes.timestamp);
         let block_number = initialized_block_env.number.to::<u64>();
@@ -128,6 +235,16 @@ where
         let gas_limit = payload.gas_limit.ok_or(ExecutorError::MissingGasLimit)?;
         let transactions =
             payload.transactions.as_ref().ok_or(ExecutorError::MissingTransactions)?;
+        if transactions.is_empty() && !self.allow_empty_blocks {
+            return Err(ExecutorError::EmptyTransactions);
+        }
+        if let Some(max) = self.max_transactions_per_block.filter(|max| transactions.len() > *max) {
+            return Err(ExecutorError::TooManyTransactions { count: transactions.len(), max });
+        }
+        let fee_recipient = payload.payload_attributes.suggested_fee_recipient;
+        if let Some(expected) = self.expected_fee_recipient.filter(|e| *e != fee_recipient) {
+            return Err(ExecutorError::FeeRecipientMismatch { expected, actual: fee_recipient });
+        }
 
         info!(
             target: "client_executor",
@@ -137,20 +254,85 @@ where
             tx_len = transactions.len(),
         );
 
+        // Decode the transactions in the payload, and ensure that all of them are supported by the
+        // active hardfork before executing any of them to avoid partial execution.
+        let is_isthmus = self.config.is_isthmus_active(payload.payload_attributes.timestamp);
+        let no_tx_pool = payload.no_tx_pool.unwrap_or_default();
+        let decoded_txs = transactions
+            .iter()
+            .map(|raw_tx| {
+                let tx = OpTxEnvelope::decode_2718(&mut raw_tx.as_ref())
+                    .map_err(ExecutorError::RLPError)?;
+                Ok((tx, raw_tx.as_ref()))
+            })
+            .collect::<ExecutorResult<Vec<_>>>()?;
+        let mut tx_hashes = BTreeSet::new();
+        for (index, (transaction, raw_transaction)) in decoded_txs.iter().enumerate() {
+            // Reject duplicate transactions, if enabled.
+            if self.reject_duplicate_transactions {
+                let tx_hash = keccak256(raw_transaction);
+                if !tx_hashes.insert(tx_hash) {
+                    return Err(ExecutorError::DuplicateTransaction(tx_hash));
+                }
+            }
+
+            // Prevent EIP-7702 transactions pre-isthmus hardfork.
+            if !is_isthmus && matches!(transaction, OpTxEnvelope::Eip7702(_)) {
+                return Err(ExecutorError::UnsupportedTransactionType {
+                    tx_type: transaction.tx_type() as u8,
+                    index,
+                });
+            }
+
+            // Reject user transactions in payloads built with the transaction pool disabled, if
+            // enforcement is enabled.
+            if self.enforce_no_tx_pool &&
+                no_tx_pool &&
+                !matches!(transaction, OpTxEnvelope::Deposit(_))
+            {
+                return Err(ExecutorError::UnexpectedUserTransaction { index });
+            }
+        }
+
+        // Every block after genesis begins with the L1 info deposit, sent by the L1 info depositor
+        // to the `L1Block` predeploy. Executed blocks are never the genesis block, so a payload
+        // lacking it is malformed.
+        if self.require_l1_info_tx &&
+            !decoded_txs.first().is_some_and(|(transaction, _)| {
+                matches!(
+                    transaction,
+                    OpTxEnvelope::Deposit(deposit)
+                        if deposit.from == L1_INFO_DEPOSITOR &&
+                            deposit.to == TxKind::Call(L1_BLOCK_CONTRACT)
+                )
+            })
+        {
+            return Err(ExecutorError::InvalidL1InfoTx);
+        }
+
+        // Hint the account proofs of the prewarm addresses, so that the host may fetch them ahead
+        // of the serial proof fetches made during execution.
+        for address in &self.prewarm_addresses {
+            self.trie_db.hint_account_proof(*address)?;
+        }
+
         let parent_block_hash: B256 = self.trie_db.parent_block_header().seal();
 
         let mut state =
             State::builder().with_database(&mut self.trie_db).with_bundle_update().build();
 
-        // Apply the pre-block EIP-4788 contract call.
-        pre_block_beacon_root_contract_call(
-            &mut state,
-            self.config,
-            block_number,
-            &initialized_cfg,
-            &initialized_block_env,
-            &payload,
-        )?;
+        // Apply the pre-block EIP-4788 contract call, unless it has been disabled.
+        if self.beacon_roots_call {
+            pre_block_beacon_root_contract_call(
+                &mut state,
+                self.config,
+                self.beacon_roots_address,
+                block_number,
+                &initialized_cfg,
+                &initialized_block_env,
+                &payload,
+            )?;
+        }
 
         // Apply the pre-block EIP-2935 contract call.
         pre_block_block_hash_contract_call(
@@ -170,129 +352,52 @@ where
             payload.payload_attributes.timestamp,
         )?;
 
-        let mut cumulative_gas_used = 0u64;
-        let mut receipts: Vec<OpReceiptEnvelope> = Vec::with_capacity(transactions.len());
-        let is_regolith = self.config.is_regolith_active(payload.payload_attributes.timestamp);
-
-        // Construct the block-scoped EVM with the given configuration.
-        // The transaction environment is set within the loop for each transaction.
-        let mut evm = {
-            let mut base = Evm::builder().with_db(&mut state).with_env_with_handler_cfg(
-                EnvWithHandlerCfg::new_with_cfg_env(
-                    initialized_cfg.clone(),
-                    initialized_block_env.clone(),
-                    Default::default(),
-                ),
-            );
+        // Construct the block-scoped EVM with the given configuration, attaching the inspector if
+        // one is set. The transaction environment is set within the loop for each transaction.
+        let evm_env = EnvWithHandlerCfg::new_with_cfg_env(
+            initialized_cfg.clone(),
+            initialized_block_env.clone(),
+            Default::default(),
+        );
+        let timestamp = payload.payload_attributes.timestamp;
+        let (receipts, cumulative_gas_used) = if let Some(inspector) = self.inspector.as_mut() {
+            let evm = Evm::builder()
+                .with_db(&mut state)
+                .with_external_context(&mut **inspector)
+                .with_env_with_handler_cfg(evm_env)
+                .append_handler_register(operator_fee_handle_register)
+                .append_handler_register(inspector_handle_register)
+                .build();
+
+            Self::execute_transactions(
+                evm,
+                decoded_txs,
+                self.config,
+                timestamp,
+                gas_limit,
+                self.tx_log_verbosity,
+            )?
+        } else {
+            let mut base = Evm::builder().with_db(&mut state).with_env_with_handler_cfg(evm_env);
+
+            // Apply the operator fee to non-deposit transactions, once Isthmus is active.
+            base = base.append_handler_register(operator_fee_handle_register);
 
             // If a handler register is provided, append it to the base EVM.
             if let Some(handler) = self.handler_register {
                 base = base.append_handler_register(handler);
             }
 
-            base.build()
+            Self::execute_transactions(
+                base.build(),
+                decoded_txs,
+                self.config,
+                timestamp,
+                gas_limit,
+                self.tx_log_verbosity,
+            )?
         };
 
-        let is_isthmus = self.config.is_isthmus_active(payload.payload_attributes.timestamp);
-
-        // Execute the transactions in the payload.
-        let decoded_txs = transactions
-            .iter()
-            .map(|raw_tx| {
-                let tx = OpTxEnvelope::decode_2718(&mut raw_tx.as_ref())
-                    .map_err(ExecutorError::RLPError)?;
-                Ok((tx, raw_tx.as_ref()))
-            })
-            .collect::<ExecutorResult<Vec<_>>>()?;
-        for (transaction, raw_transaction) in decoded_txs {
-            // The sum of the transaction’s gas limit, Tg, and the gas utilized in this block prior,
-            // must be no greater than the block’s gasLimit.
-            let block_available_gas = (gas_limit - cumulative_gas_used) as u128;
-            if (transaction.gas_limit() as u128) > block_available_gas &&
-                (is_regolith || !transaction.is_system_transaction())
-            {
-                return Err(ExecutorError::BlockGasLimitExceeded);
-            }
-
-            // Prevent EIP-7702 transactions pre-isthmus hardfork.
-            if !is_isthmus && matches!(transaction, OpTxEnvelope::Eip7702(_)) {
-                return Err(ExecutorError::UnsupportedTransactionType(transaction.tx_type() as u8));
-            }
-
-            // Modify the transaction environment with the current transaction.
-            evm = evm
-                .modify()
-                .with_tx_env(Self::prepare_tx_env(&transaction, raw_transaction)?)
-                .build();
-
-            // If the transaction is a deposit, cache the depositor account.
-            //
-            // This only needs to be done post-Regolith, as deposit nonces were not included in
-            // Bedrock. In addition, non-deposit transactions do not have deposit
-            // nonces.
-            let depositor = is_regolith
-                .then(|| {
-                    if let OpTxEnvelope::Deposit(deposit) = &transaction {
-                        evm.db_mut().load_cache_account(deposit.from).ok().cloned()
-                    } else {
-                        None
-                    }
-                })
-                .flatten();
-
-            // Execute the transaction.
-            let tx_hash = keccak256(raw_transaction);
-            debug!(
-                target: "client_executor",
-                "Executing transaction: {tx_hash}",
-            );
-            let result = evm.transact_commit().map_err(ExecutorError::ExecutionError)?;
-            debug!(
-                target: "client_executor",
-                "Transaction executed: {tx_hash} | Gas used: {gas_used} | Success: {status}",
-                gas_used = result.gas_used(),
-                status = result.is_success()
-            );
-
-            // Accumulate the gas used by the transaction.
-            cumulative_gas_used += result.gas_used();
-
-            // Create receipt envelope.
-            let receipt = OpReceiptEnvelope::<Log>::from_parts(
-                result.is_success(),
-                cumulative_gas_used,
-                result.logs(),
-                transaction.tx_type(),
-                depositor
-                    .as_ref()
-                    .map(|depositor| depositor.account_info().unwrap_or_default().nonce),
-                depositor
-                    .is_some()
-                    .then(|| {
-                        self.config
-                            .is_canyon_active(payload.payload_attributes.timestamp)
-                            .then_some(1)
-                    })
-                    .flatten(),
-            );
-            // Ensure the receipt is not an EIP-7702 receipt.
-            if matches!(receipt, OpReceiptEnvelope::Eip7702(_)) && !is_isthmus {
-                panic!(
-                    "EIP-7702 receipts are not supported by the fault proof program before Isthmus"
-                );
-            }
-            receipts.push(receipt);
-        }
-
-        info!(
-            target: "client_executor",
-            "Transaction execution complete | Cumulative gas used: {cumulative_gas_used}",
-            cumulative_gas_used = cumulative_gas_used
-        );
-
-        // Drop the EVM to free the exclusive reference to the database.
-        drop(evm);
-
         // Merge all state transitions into the cache state.
         debug!(target: "client_executor", "Merging state transitions");
         state.merge_transitions(BundleRetention::Reverts);
@@ -304,11 +409,8 @@ where
         let state_root = state.database.state_root(&bundle)?;
 
         let transactions_root = Self::compute_transactions_root(transactions.as_slice());
-        let receipts_root = Self::compute_receipts_root(
-            &receipts,
-            self.config,
-            payload.payload_attributes.timestamp,
-        );
+        let receipts_root =
+            compute_receipts_root(&receipts, self.config, payload.payload_attributes.timestamp);
         debug!(
             target: "client_executor",
             "Computed transactions root: {transactions_root} | receipts root: {receipts_root}",
@@ -324,33 +426,27 @@ where
         // If the Isthmus hardfork is active, the withdrawals root is the L2 to L1 message passer
         // account.
         if self.config.is_isthmus_active(payload.payload_attributes.timestamp) {
-            withdrawals_root = Some(Self::message_passer_account(state.database, block_number)?);
+            withdrawals_root = Some(message_passer_storage_root(
+                state.database,
+                self.message_passer_address,
+                block_number,
+            )?);
         }
 
         // Compute logs bloom filter for the block.
-        let logs_bloom = logs_bloom(receipts.iter().flat_map(|receipt| receipt.logs()));
+        let logs_bloom = compute_logs_bloom(&receipts);
 
         // Compute Cancun fields, if active.
-        let (blob_gas_used, excess_blob_gas) = self
-            .config
-            .is_ecotone_active(payload.payload_attributes.timestamp)
-            .then(|| {
-                let parent_header = state.database.parent_block_header();
-                let excess_blob_gas = if self.config.is_ecotone_active(parent_header.timestamp) {
-                    let parent_excess_blob_gas = parent_header.excess_blob_gas.unwrap_or_default();
-                    let parent_blob_gas_used = parent_header.blob_gas_used.unwrap_or_default();
-
-                    // TODO(isthmus): Consider the final field for EIP-7742. Since this EIP isn't
-                    // implemented yet, we can safely ignore it for now.
-                    calc_excess_blob_gas(parent_excess_blob_gas, parent_blob_gas_used, 0)
-                } else {
-                    // For the first post-fork block, both blob gas fields are evaluated to 0.
-                    calc_excess_blob_gas(0, 0, 0)
-                };
-
+        // Post-Ecotone headers commit to zero blob gas used, as the OP Stack does not support
+        // EIP-4844 transactions.
+        let (blob_gas_used, excess_blob_gas) =
+            if self.config.is_ecotone_active(payload.payload_attributes.timestamp) {
+                let excess_blob_gas =
+                    compute_excess_blob_gas(self.config, state.database.parent_block_header());
                 (Some(0), Some(excess_blob_gas as u128))
-            })
-            .unwrap_or_default();
+            } else {
+                (None, None)
+            };
 
         // At holocene activation, the base fee parameters from the payload are placed
         // into the Header's `extra_data` field.
@@ -409,7 +505,7 @@ where
 
         // Update the parent block hash in the state database.
         state.database.set_parent_block_header(header.clone());
-        Ok(ExecutionArtifacts { block_header: header, receipts })
+        Ok(ExecutionArtifacts { block_header: header, receipts, no_tx_pool })
     }
 
     /// Computes the current output root of the executor, based on the parent header and the
@@ -425,75 +521,267 @@ where
     /// - `Ok(output_root)`: The computed output root.
     /// - `Err(_)`: If an error occurred while computing the output root.
     pub fn compute_output_root(&mut self) -> ExecutorResult<B256> {
-        let parent_number = self.trie_db.parent_block_header().number;
-        let storage_root = Self::message_passer_account(&mut self.trie_db, parent_number)?;
-        let parent_header = self.trie_db.parent_block_header();
+        let components = self.output_root_components()?;
 
         info!(
             target: "client_executor",
             "Computing output root | Version: {version} | State root: {state_root} | Storage root: {storage_root} | Block hash: {hash}",
             version = OUTPUT_ROOT_VERSION,
-            state_root = self.trie_db.parent_block_header().state_root,
-            hash = parent_header.seal(),
+            state_root = components.state_root,
+            storage_root = components.storage_root,
+            hash = components.block_hash,
         );
 
-        // Construct the raw output.
-        let mut raw_output = [0u8; 128];
-        raw_output[31] = OUTPUT_ROOT_VERSION;
-        raw_output[32..64].copy_from_slice(parent_header.state_root.as_ref());
-        raw_output[64..96].copy_from_slice(storage_root.as_ref());
-        raw_output[96..128].copy_from_slice(parent_header.seal().as_ref());
-        let output_root = keccak256(raw_output);
+        let output_root = components.output_root();
 
         info!(
             target: "client_executor",
             "Computed output root for block # {block_number} | Output root: {output_root}",
-            block_number = parent_number,
+            block_number = self.trie_db.parent_block_header().number,
         );
 
         // Hash the output and return
         Ok(output_root)
     }
 
-    /// Computes the receipts root from the given set of receipts.
+    /// Computes the [OutputRootComponents] of the current output root of the executor, based on
+    /// the parent header and the state's underlying trie.
+    ///
+    /// ## Returns
+    /// - `Ok(components)`: The components of the current output root.
+    /// - `Err(_)`: If an error occurred while computing the withdrawal storage root.
+    pub fn output_root_components(&mut self) -> ExecutorResult<OutputRootComponents> {
+        let parent_number = self.trie_db.parent_block_header().number;
+        let storage_root = message_passer_storage_root(
+            &mut self.trie_db,
+            self.message_passer_address,
+            parent_number,
+        )?;
+        let parent_header = self.trie_db.parent_block_header();
+
+        Ok(OutputRootComponents {
+            state_root: parent_header.state_root,
+            storage_root,
+            block_hash: parent_header.seal(),
+        })
+    }
+
+    /// Diagnoses a mismatch between the current output root of the executor and a claimed output
+    /// root, by recomputing each of the committed components independently and comparing them
+    /// against the claimed components.
+    ///
+    /// ## Takes
+    /// - `claimed`: The components of the claimed output root, e.g. decoded from its preimage with
+    ///   [OutputRootComponents::decode].
+    ///
+    /// ## Returns
+    /// - `Ok(components)`: The components that differ from the claim. Empty if the output roots
+    ///   match.
+    /// - `Err(_)`: If an error occurred while computing the output root components.
+    pub fn diagnose_output_root(
+        &mut self,
+        claimed: &OutputRootComponents,
+    ) -> ExecutorResult<Vec<OutputRootComponent>> {
+        let components = self.output_root_components()?;
+        let diff = components.diff(claimed);
+        for component in &diff {
+            warn!(
+                target: "client_executor",
+                "Output root component mismatch | Component: {component} | Computed: {computed} | Claimed: {claimed}",
+                computed = components.get(*component),
+                claimed = claimed.get(*component),
+            );
+        }
+        Ok(diff)
+    }
+
+    /// Executes the given payload with [Self::execute_payload], and verifies that the output root
+    /// computed with [Self::compute_output_root] afterwards matches the expected output root.
+    ///
+    /// The executor is advanced to the produced block even if the output roots do not match.
+    ///
+    /// ## Takes
+    /// - `payload`: The payload to execute.
+    /// - `expected_output_root`: The output root expected after executing the payload.
+    ///
+    /// ## Returns
+    /// - `Ok(output_root)`: The computed output root, which matches the expected output root.
+    /// - `Err(ExecutorError::OutputRootMismatch { .. })`: The computed output root does not match
+    ///   the expected output root.
+    /// - `Err(_)`: If an error occurred while executing the payload or computing the output root.
+    pub fn execute_and_verify(
+        &mut self,
+        payload: OpPayloadAttributes,
+        expected_output_root: B256,
+    ) -> ExecutorResult<B256> {
+        self.execute_payload(payload)?;
+        let output_root = self.compute_output_root()?;
+        if output_root != expected_output_root {
+            return Err(ExecutorError::OutputRootMismatch {
+                expected: expected_output_root,
+                actual: output_root,
+            });
+        }
+        Ok(output_root)
+    }
+
+    /// Re-executes the block with the given hash, assembling its payload from the block's header
+    /// and transactions as fetched through the [TrieDBProvider].
+    ///
+    /// The block must build on the current parent header of the executor.
     ///
     /// ## Takes
-    /// - `receipts`: The receipts to compute the root for.
-    /// - `config`: The rollup config to use for the computation.
-    /// - `timestamp`: The timestamp to use for the computation.
+    /// - `block_hash`: The hash of the block to execute.
     ///
     /// ## Returns
-    /// The computed receipts root.
-    fn compute_receipts_root(
-        receipts: &[OpReceiptEnvelope],
+    /// - `Ok(artifacts)`: The [ExecutionArtifacts] of the re-executed block.
+    /// - `Err(ExecutorError::ParentHashMismatch { .. })`: The block does not build on the parent
+    ///   header of the executor.
+    /// - `Err(_)`: If an error occurred while fetching or executing the block.
+    pub fn execute_block_by_hash(
+        &mut self,
+        block_hash: B256,
+    ) -> ExecutorResult<ExecutionArtifacts> {
+        let (header, transactions) = self.trie_db.block_by_hash(block_hash)?;
+
+        let parent_hash = self.trie_db.parent_block_header().seal();
+        if header.parent_hash != parent_hash {
+            return Err(ExecutorError::ParentHashMismatch {
+                expected: parent_hash,
+                actual: header.parent_hash,
+            });
+        }
+
+        let eip_1559_params = if self.config.is_holocene_active(header.timestamp) {
+            let params = header.extra_data.get(1..).ok_or(ExecutorError::InvalidExtraData)?;
+            Some(B64::try_from(params).map_err(|_| ExecutorError::InvalidExtraData)?)
+        } else {
+            None
+        };
+
+        let payload = OpPayloadAttributes {
+            payload_attributes: PayloadAttributes {
+                timestamp: header.timestamp,
+                prev_randao: header.mix_hash,
+                suggested_fee_recipient: header.beneficiary,
+                withdrawals: self.config.is_canyon_active(header.timestamp).then(Vec::new),
+                parent_beacon_block_root: header.parent_beacon_block_root,
+            },
+            transactions: Some(transactions),
+            no_tx_pool: None,
+            gas_limit: Some(header.gas_limit),
+            eip_1559_params,
+        };
+        self.execute_payload(payload)
+    }
+
+    /// Executes the decoded transactions of a payload in the block-scoped EVM.
+    ///
+    /// ## Takes
+    /// - `evm`: The block-scoped EVM, with the block environment set.
+    /// - `decoded_txs`: The decoded transactions of the payload, paired with their raw encodings.
+    /// - `config`: The [RollupConfig] of the chain.
+    /// - `timestamp`: The timestamp of the executing block.
+    /// - `gas_limit`: The gas limit of the executing block.
+    /// - `tx_log_verbosity`: The verbosity of the per-transaction logs.
+    ///
+    /// ## Returns
+    /// The receipts of the transactions, and the cumulative gas used by them.
+    fn execute_transactions<EXT>(
+        mut evm: Evm<'_, EXT, &mut State<&mut TrieDB<F, H>>>,
+        decoded_txs: Vec<(OpTxEnvelope, &[u8])>,
         config: &RollupConfig,
         timestamp: u64,
-    ) -> B256 {
-        // There is a minor bug in op-geth and op-erigon where in the Regolith hardfork,
-        // the receipt root calculation does not inclide the deposit nonce in the
-        // receipt encoding. In the Regolith hardfork, we must strip the deposit nonce
-        // from the receipt encoding to match the receipt root calculation.
-        if config.is_regolith_active(timestamp) && !config.is_canyon_active(timestamp) {
-            let receipts = receipts
-                .iter()
-                .cloned()
-                .map(|receipt| match receipt {
-                    OpReceiptEnvelope::Deposit(mut deposit_receipt) => {
-                        deposit_receipt.receipt.deposit_nonce = None;
-                        OpReceiptEnvelope::Deposit(deposit_receipt)
+        gas_limit: u64,
+        tx_log_verbosity: TxLogVerbosity,
+    ) -> ExecutorResult<(Vec<OpReceiptEnvelope>, u64)> {
+        let mut cumulative_gas_used = 0u64;
+        let mut receipts: Vec<OpReceiptEnvelope> = Vec::with_capacity(decoded_txs.len());
+        let is_regolith = config.is_regolith_active(timestamp);
+        let is_isthmus = config.is_isthmus_active(timestamp);
+
+        // Execute the transactions in the payload.
+        for (index, (transaction, raw_transaction)) in decoded_txs.into_iter().enumerate() {
+            // The sum of the transaction’s gas limit, Tg, and the gas utilized in this block prior,
+            // must be no greater than the block’s gasLimit. Pre-Regolith system deposits are
+            // exempt from this check.
+            let block_available_gas = (gas_limit - cumulative_gas_used) as u128;
+            if (transaction.gas_limit() as u128) > block_available_gas &&
+                !is_block_gas_limit_exempt(&transaction, is_regolith)
+            {
+                return Err(ExecutorError::BlockGasLimitExceeded);
+            }
+
+            // Modify the transaction environment with the current transaction.
+            evm = evm
+                .modify()
+                .with_tx_env(Self::prepare_tx_env(&transaction, raw_transaction)?)
+                .build();
+
+            // If the transaction is a deposit, cache the depositor account.
+            //
+            // This only needs to be done post-Regolith, as deposit nonces were not included in
+            // Bedrock. In addition, non-deposit transactions do not have deposit
+            // nonces.
+            let depositor = is_regolith
+                .then(|| {
+                    if let OpTxEnvelope::Deposit(deposit) = &transaction {
+                        evm.db_mut().load_cache_account(deposit.from).ok().cloned()
+                    } else {
+                        None
                     }
-                    _ => receipt,
                 })
-                .collect::<Vec<_>>();
+                .flatten();
 
-            ordered_trie_with_encoder(receipts.as_ref(), |receipt, mut buf| {
-                receipt.encode_2718(&mut buf)
-            })
-            .root()
-        } else {
-            ordered_trie_with_encoder(receipts, |receipt, mut buf| receipt.encode_2718(&mut buf))
-                .root()
+            // Execute the transaction.
+            let tx_hash = keccak256(raw_transaction);
+            let logged = tx_log_verbosity.is_logged(index);
+            if logged {
+                debug!(
+                    target: "client_executor",
+                    "Executing transaction: {tx_hash}",
+                );
+            }
+            let result = evm.transact_commit().map_err(ExecutorError::ExecutionError)?;
+            if logged {
+                debug!(
+                    target: "client_executor",
+                    "Transaction executed: {tx_hash} | Gas used: {gas_used} | Success: {status}",
+                    gas_used = result.gas_used(),
+                    status = result.is_success()
+                );
+            }
+
+            // Accumulate the gas used by the transaction.
+            cumulative_gas_used += result.gas_used();
+
+            // Create receipt envelope.
+            let receipt = TxExecutionResult {
+                tx_type: transaction.tx_type(),
+                success: result.is_success(),
+                gas_used: result.gas_used(),
+                logs: result.into_logs(),
+                deposit_nonce: depositor
+                    .as_ref()
+                    .map(|depositor| depositor.account_info().unwrap_or_default().nonce),
+            }
+            .into_receipt(cumulative_gas_used, config, timestamp);
+            // Ensure the receipt is not an EIP-7702 receipt.
+            if matches!(receipt, OpReceiptEnvelope::Eip7702(_)) && !is_isthmus {
+                panic!(
+                    "EIP-7702 receipts are not supported by the fault proof program before Isthmus"
+                );
+            }
+            receipts.push(receipt);
         }
+
+        info!(
+            target: "client_executor",
+            "Transaction execution complete | Cumulative gas used: {cumulative_gas_used}",
+            cumulative_gas_used = cumulative_gas_used
+        );
+
+        Ok((receipts, cumulative_gas_used))
     }
 
     /// Computes the transactions root from the given set of encoded transactions.
@@ -510,9 +798,39 @@ where
 
 #[cfg(test)]
 mod test {
-    use crate::test_utils::run_test_fixture;
+    use super::*;
+    use crate::{
+        constants::{GAS_PRICE_ORACLE, L1_BLOCK_CONTRACT, L2_TO_L1_BRIDGE, OPERATOR_FEE_VAULT},
+        errors::TrieDBError,
+        test_utils::{
+            create_deposit, empty_parent_header, load_test_fixture, run_test_fixture,
+            synthetic_payload, BlockTrieNodeProvider, MapTrieDBProvider, RecordingTrieHinter,
+        },
+        NoopTrieDBProvider,
+    };
+    use alloy_consensus::{SignableTransaction, TxEip1559, TxEip7702, TxLegacy};
+    use alloy_eips::{eip2718::Encodable2718, eip4788::BEACON_ROOTS_ADDRESS};
+    use alloy_primitives::{address, PrimitiveSignature, TxKind};
+    use alloy_rlp::Encodable;
+    use alloy_trie::TrieAccount;
+    use kona_mpt::{Nibbles, NoopTrieHinter, TrieNode};
+    use op_alloy_consensus::TxDeposit;
+    use revm::{
+        handler::register::EvmHandler,
+        interpreter::{CallInputs, CallOutcome, CreateInputs, CreateOutcome},
+        primitives::{EVMError, InvalidTransaction},
+        Database, EvmContext, Inspector,
+    };
     use rstest::rstest;
-    use std::path::PathBuf;
+    use std::{
+        path::PathBuf,
+        sync::{Arc, Mutex},
+    };
+    use tracing::{Event, Subscriber};
+    use tracing_subscriber::{
+        layer::{Context, SubscriberExt},
+        Layer,
+    };
 
     // To create new test fixtures, uncomment the following test and run it with parameters filled.
     //
@@ -542,4 +860,1089 @@ mod test {
 
         run_test_fixture(fixture_dir).await;
     }
+
+    #[tokio::test]
+    async fn test_execute_block_by_hash() {
+        let fixture_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("testdata")
+            .join("block-22884230.tar.gz");
+        let (_fixture_dir, fixture, provider) = load_test_fixture(fixture_path.clone()).await;
+        let parent_header = fixture.parent_header.seal_slow();
+        let transactions = fixture.executing_payload.transactions.clone().unwrap();
+
+        let mut executor =
+            StatelessL2BlockExecutor::builder(&fixture.rollup_config, provider, NoopTrieHinter)
+                .with_parent_header(parent_header.clone())
+                .build();
+        let direct = executor.execute_payload(fixture.executing_payload).unwrap();
+        assert_eq!(direct.block_header.hash(), fixture.expected_block_hash);
+
+        // Re-execute the block given only its hash, serving its header and transactions trie from
+        // the provider.
+        let (_fixture_dir, _, provider) = load_test_fixture(fixture_path).await;
+        let provider =
+            BlockTrieNodeProvider::new(provider, direct.block_header.clone(), &transactions);
+        let mut executor =
+            StatelessL2BlockExecutor::builder(&fixture.rollup_config, provider, NoopTrieHinter)
+                .with_parent_header(parent_header.clone())
+                .build();
+        let by_hash = executor.execute_block_by_hash(fixture.expected_block_hash).unwrap();
+        assert_eq!(by_hash, direct);
+
+        // The block does not build on the new parent header.
+        assert!(matches!(
+            executor.execute_block_by_hash(fixture.expected_block_hash),
+            Err(ExecutorError::ParentHashMismatch { expected, actual })
+                if expected == fixture.expected_block_hash && actual == parent_header.seal()
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_trie_db_stats() {
+        let fixture_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("testdata")
+            .join("block-22884230.tar.gz");
+        let (_fixture_dir, fixture, provider) = load_test_fixture(fixture_path).await;
+
+        let mut executor =
+            StatelessL2BlockExecutor::builder(&fixture.rollup_config, provider, NoopTrieHinter)
+                .with_parent_header(fixture.parent_header.seal_slow())
+                .with_trie_db_stats(true)
+                .build();
+        assert_eq!(executor.trie_db_stats(), None);
+
+        let exec_artifacts = executor.execute_payload(fixture.executing_payload).unwrap();
+        assert_eq!(exec_artifacts.block_header.hash(), fixture.expected_block_hash);
+
+        // Executing the block opens at least the root and a path to each touched account, and every
+        // node opened along the way is either re-hashed or discarded by the state root computation.
+        let stats = executor.trie_db_stats().unwrap();
+        assert!(stats.opened > 1, "{stats:?}");
+        assert!(stats.blinded > 0, "{stats:?}");
+        assert!(stats.discarded > 0, "{stats:?}");
+    }
+
+    #[tokio::test]
+    async fn test_touched_keys() {
+        let fixture_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("testdata")
+            .join("block-22884230.tar.gz");
+        let (_fixture_dir, fixture, provider) = load_test_fixture(fixture_path.clone()).await;
+        let (_parent_dir, _, parent_provider) = load_test_fixture(fixture_path).await;
+        let parent_header = fixture.parent_header.seal_slow();
+        let fee_recipient = fixture.executing_payload.payload_attributes.suggested_fee_recipient;
+
+        let mut executor =
+            StatelessL2BlockExecutor::builder(&fixture.rollup_config, provider, NoopTrieHinter)
+                .with_parent_header(parent_header.clone())
+                .with_touched_keys(true)
+                .build();
+        assert!(executor.touched_keys().unwrap().is_empty());
+
+        let exec_artifacts = executor.execute_payload(fixture.executing_payload).unwrap();
+        assert_eq!(exec_artifacts.block_header.hash(), fixture.expected_block_hash);
+        let touched = executor.touched_keys().unwrap();
+        assert!(touched.contains(&parent_header.hash()));
+
+        // Every trie node on the path to the fee recipient in the parent state was touched.
+        let mut parent_trie = TrieDB::new(
+            parent_header.state_root,
+            parent_header.clone(),
+            parent_provider,
+            NoopTrieHinter,
+        );
+        parent_trie.enable_touched_keys();
+        parent_trie.reset_touched_keys();
+        assert!(parent_trie
+            .get_trie_account(&fee_recipient, parent_header.number)
+            .unwrap()
+            .is_some());
+        let fee_recipient_keys = parent_trie.touched_keys().unwrap();
+        assert!(fee_recipient_keys.len() > 2);
+        assert!(fee_recipient_keys.is_subset(touched), "{fee_recipient_keys:?}");
+    }
+
+    #[test]
+    fn test_unsupported_tx_type_rejected_before_execution() {
+        let config = RollupConfig { ecotone_time: Some(0), ..Default::default() };
+        let mut provider = MapTrieDBProvider::default();
+        let parent_header = Header {
+            gas_limit: 30_000_000,
+            state_root: state_root(&mut provider, &[(L2_TO_L1_BRIDGE, TrieAccount::default())]),
+            ..Default::default()
+        }
+        .seal_slow();
+        let mut executor = StatelessL2BlockExecutor::builder(&config, provider, NoopTrieHinter)
+            .with_parent_header(parent_header.clone())
+            .with_touched_keys(true)
+            .build();
+
+        // A valid deposit, followed by an EIP-7702 transaction, which is not supported before
+        // the Isthmus hardfork.
+        let eip7702 = OpTxEnvelope::Eip7702(
+            TxEip7702 { chain_id: config.l2_chain_id, gas_limit: 21_000, ..Default::default() }
+                .into_signed(PrimitiveSignature::test_signature()),
+        );
+        let payload = OpPayloadAttributes {
+            payload_attributes: PayloadAttributes {
+                timestamp: 0,
+                parent_beacon_block_root: Some(B256::ZERO),
+                ..Default::default()
+            },
+            ..synthetic_payload(vec![create_deposit(), eip7702.encoded_2718().into()])
+        };
+
+        let err = executor.execute_payload(payload).unwrap_err();
+        assert!(matches!(err, ExecutorError::UnsupportedTransactionType { tx_type: 4, index: 1 }));
+
+        // The payload was rejected before the deposit was executed, which would have read the
+        // parent state, and the parent header was not advanced.
+        assert!(executor.touched_keys().unwrap().is_empty());
+        assert_eq!(*executor.trie_db.parent_block_header(), parent_header);
+    }
+
+    #[test]
+    fn test_spec_override() {
+        let config = RollupConfig::default();
+        let parent_header = empty_parent_header();
+
+        // A contract creation whose init code uses `PUSH0`, which is only valid from Shanghai
+        // (Canyon) onwards.
+        let deposit = OpTxEnvelope::Deposit(Sealed::new_unchecked(
+            TxDeposit {
+                gas_limit: 100_000,
+                to: TxKind::Create,
+                input: alloy_primitives::bytes!("5f5ff3"),
+                ..Default::default()
+            },
+            B256::ZERO,
+        ));
+        let payload = synthetic_payload(vec![deposit.encoded_2718().into()]);
+
+        let execute = |spec_id| {
+            StatelessL2BlockExecutor::builder(&config, NoopTrieDBProvider, NoopTrieHinter)
+                .with_parent_header(parent_header.clone())
+                .with_spec_override(spec_id)
+                .build()
+                .execute_payload(payload.clone())
+                .unwrap()
+        };
+        let bedrock = execute(SpecId::BEDROCK);
+        let canyon = execute(SpecId::CANYON);
+
+        // The creation fails under Bedrock rules, and succeeds under Canyon rules.
+        assert!(!bedrock.receipts[0].is_success());
+        assert!(canyon.receipts[0].is_success());
+        assert_ne!(bedrock.block_header.receipts_root, canyon.block_header.receipts_root);
+        assert_ne!(bedrock.block_header.gas_used, canyon.block_header.gas_used);
+
+        // Fields derived from the rollup config's hardfork schedule are unaffected.
+        assert_eq!(bedrock.block_header.transactions_root, canyon.block_header.transactions_root);
+        assert_eq!(bedrock.block_header.withdrawals_root, canyon.block_header.withdrawals_root);
+        assert_eq!(bedrock.block_header.extra_data, canyon.block_header.extra_data);
+    }
+
+    /// A [Layer] that records the per-transaction logs emitted by the executor.
+    #[derive(Debug, Default, Clone)]
+    struct TxLogCollector(Arc<Mutex<Vec<String>>>);
+
+    impl TxLogCollector {
+        fn len(&self) -> usize {
+            self.0.lock().unwrap().len()
+        }
+    }
+
+    impl<S: Subscriber> Layer<S> for TxLogCollector {
+        fn on_event(&self, event: &Event<'_>, _: Context<'_, S>) {
+            let message = format!("{event:?}");
+            if message.contains("Executing transaction:") ||
+                message.contains("Transaction executed:")
+            {
+                self.0.lock().unwrap().push(message);
+            }
+        }
+    }
+
+    #[test]
+    fn test_tx_log_verbosity() {
+        let config = RollupConfig::default();
+        let parent_header = empty_parent_header();
+        let deposit = |nonce: u8| -> Bytes {
+            OpTxEnvelope::Deposit(Sealed::new_unchecked(
+                TxDeposit {
+                    gas_limit: 100_000,
+                    to: TxKind::Call(Address::repeat_byte(0xBB)),
+                    input: Bytes::from(vec![nonce]),
+                    ..Default::default()
+                },
+                B256::ZERO,
+            ))
+            .encoded_2718()
+            .into()
+        };
+        let payload = synthetic_payload(vec![deposit(0), deposit(1), deposit(2)]);
+
+        let tx_logs = |tx_log_verbosity| {
+            let collector = TxLogCollector::default();
+            let subscriber = tracing_subscriber::Registry::default().with(collector.clone());
+            let _guard = tracing::subscriber::set_default(subscriber);
+
+            StatelessL2BlockExecutor::builder(&config, NoopTrieDBProvider, NoopTrieHinter)
+                .with_parent_header(parent_header.clone())
+                .with_tx_log_verbosity(tx_log_verbosity)
+                .build()
+                .execute_payload(payload.clone())
+                .unwrap();
+            collector.len()
+        };
+
+        // Two events are emitted for each logged transaction.
+        assert_eq!(tx_logs(TxLogVerbosity::Full), 6);
+        assert_eq!(tx_logs(TxLogVerbosity::Sampled(2)), 4);
+        assert_eq!(tx_logs(TxLogVerbosity::Sampled(0)), 0);
+        assert_eq!(tx_logs(TxLogVerbosity::Off), 0);
+    }
+
+    #[test]
+    fn test_execute_default_parent_header() {
+        let config = RollupConfig::default();
+        let mut executor =
+            StatelessL2BlockExecutor::builder(&config, NoopTrieDBProvider, NoopTrieHinter).build();
+
+        let payload = synthetic_payload(vec![]);
+
+        assert!(matches!(
+            executor.execute_payload(payload),
+            Err(ExecutorError::InvalidParentHeader)
+        ));
+    }
+
+    #[test]
+    fn test_execute_empty_block() {
+        let config = RollupConfig::default();
+        let parent_header = empty_parent_header();
+        let payload = synthetic_payload(vec![]);
+
+        // Empty blocks are rejected by default.
+        let mut executor =
+            StatelessL2BlockExecutor::builder(&config, NoopTrieDBProvider, NoopTrieHinter)
+                .with_parent_header(parent_header.clone())
+                .build();
+        assert!(matches!(
+            executor.execute_payload(payload.clone()),
+            Err(ExecutorError::EmptyTransactions)
+        ));
+
+        // An absent transaction list is always malformed.
+        let mut executor =
+            StatelessL2BlockExecutor::builder(&config, NoopTrieDBProvider, NoopTrieHinter)
+                .with_parent_header(parent_header.clone())
+                .with_allow_empty_blocks(true)
+                .build();
+        assert!(matches!(
+            executor.execute_payload(OpPayloadAttributes { transactions: None, ..payload.clone() }),
+            Err(ExecutorError::MissingTransactions)
+        ));
+
+        let artifacts = executor.execute_payload(payload).unwrap();
+        assert!(artifacts.receipts.is_empty());
+        assert_eq!(artifacts.block_header.transactions_root, EMPTY_ROOT_HASH);
+        assert_eq!(artifacts.block_header.receipts_root, EMPTY_ROOT_HASH);
+        assert_eq!(artifacts.block_header.gas_used, 0);
+        assert_eq!(artifacts.block_header.state_root, parent_header.state_root);
+    }
+
+    #[test]
+    fn test_no_tx_pool() {
+        let config = RollupConfig::default();
+        let parent_header = empty_parent_header();
+
+        let user_tx = OpTxEnvelope::Eip1559(
+            TxEip1559 { chain_id: config.l2_chain_id, gas_limit: 21_000, ..Default::default() }
+                .into_signed(PrimitiveSignature::test_signature()),
+        );
+        let payload = OpPayloadAttributes {
+            no_tx_pool: Some(true),
+            ..synthetic_payload(vec![create_deposit()])
+        };
+        let executor = |enforce_no_tx_pool| {
+            StatelessL2BlockExecutor::builder(&config, NoopTrieDBProvider, NoopTrieHinter)
+                .with_parent_header(parent_header.clone())
+                .with_enforce_no_tx_pool(enforce_no_tx_pool)
+                .build()
+        };
+
+        // The flag is surfaced in the execution artifacts.
+        let artifacts = executor(true).execute_payload(payload.clone()).unwrap();
+        assert!(artifacts.no_tx_pool);
+        let artifacts = executor(true)
+            .execute_payload(OpPayloadAttributes { no_tx_pool: None, ..payload.clone() })
+            .unwrap();
+        assert!(!artifacts.no_tx_pool);
+
+        // With enforcement enabled, user transactions are rejected before execution.
+        let with_user_tx = OpPayloadAttributes {
+            transactions: Some(vec![create_deposit(), user_tx.encoded_2718().into()]),
+            ..payload
+        };
+        let mut enforcing = executor(true);
+        assert!(matches!(
+            enforcing.execute_payload(with_user_tx.clone()),
+            Err(ExecutorError::UnexpectedUserTransaction { index: 1 })
+        ));
+        assert_eq!(*enforcing.trie_db.parent_block_header(), parent_header);
+
+        // Without enforcement, the payload is not rejected for containing a user transaction.
+        assert!(!matches!(
+            executor(false).execute_payload(with_user_tx),
+            Err(ExecutorError::UnexpectedUserTransaction { .. })
+        ));
+    }
+
+    #[test]
+    fn test_max_transactions_per_block() {
+        let config = RollupConfig::default();
+        let parent_header = empty_parent_header();
+        let payload = synthetic_payload(vec![create_deposit(); 3]);
+        let executor = |max_transactions: Option<usize>| {
+            let mut builder =
+                StatelessL2BlockExecutor::builder(&config, NoopTrieDBProvider, NoopTrieHinter)
+                    .with_parent_header(parent_header.clone());
+            if let Some(max_transactions) = max_transactions {
+                builder = builder.with_max_transactions_per_block(max_transactions);
+            }
+            builder.build()
+        };
+
+        // Payloads exceeding the limit are rejected before execution.
+        let mut limited = executor(Some(2));
+        assert!(matches!(
+            limited.execute_payload(payload.clone()),
+            Err(ExecutorError::TooManyTransactions { count: 3, max: 2 })
+        ));
+        assert_eq!(*limited.trie_db.parent_block_header(), parent_header);
+
+        executor(Some(3)).execute_payload(payload.clone()).unwrap();
+        executor(None).execute_payload(payload).unwrap();
+    }
+
+    #[test]
+    fn test_reject_duplicate_transactions() {
+        let config = RollupConfig::default();
+        let parent_header = empty_parent_header();
+        let deposit = create_deposit();
+        let payload = synthetic_payload(vec![deposit.clone(), deposit.clone()]);
+        let executor = |reject_duplicate_transactions| {
+            StatelessL2BlockExecutor::builder(&config, NoopTrieDBProvider, NoopTrieHinter)
+                .with_parent_header(parent_header.clone())
+                .with_reject_duplicate_transactions(reject_duplicate_transactions)
+                .build()
+        };
+
+        // The duplicated transaction is rejected before execution.
+        let mut rejecting = executor(true);
+        assert!(matches!(
+            rejecting.execute_payload(payload.clone()),
+            Err(ExecutorError::DuplicateTransaction(hash)) if hash == keccak256(&deposit)
+        ));
+        assert_eq!(*rejecting.trie_db.parent_block_header(), parent_header);
+
+        let artifacts = executor(false).execute_payload(payload).unwrap();
+        assert_eq!(artifacts.receipts.len(), 2);
+    }
+
+    #[test]
+    fn test_expected_fee_recipient() {
+        let config = RollupConfig::default();
+        let parent_header = empty_parent_header();
+        let fee_vault = Address::repeat_byte(0xFE);
+        let payload = |suggested_fee_recipient| {
+            let mut payload = synthetic_payload(vec![]);
+            payload.payload_attributes.suggested_fee_recipient = suggested_fee_recipient;
+            payload
+        };
+        let executor = || {
+            StatelessL2BlockExecutor::builder(&config, NoopTrieDBProvider, NoopTrieHinter)
+                .with_parent_header(parent_header.clone())
+                .with_allow_empty_blocks(true)
+        };
+
+        // A mismatched fee recipient is rejected when enforcement is enabled.
+        let mut enforced = executor().with_expected_fee_recipient(fee_vault).build();
+        assert!(matches!(
+            enforced.execute_payload(payload(Address::ZERO)),
+            Err(ExecutorError::FeeRecipientMismatch { expected, actual })
+                if expected == fee_vault && actual == Address::ZERO
+        ));
+        assert_eq!(*enforced.trie_db.parent_block_header(), parent_header);
+
+        let artifacts = enforced.execute_payload(payload(fee_vault)).unwrap();
+        assert_eq!(artifacts.block_header.beneficiary, fee_vault);
+        executor().build().execute_payload(payload(Address::ZERO)).unwrap();
+    }
+
+    #[test]
+    fn test_require_l1_info_tx() {
+        let config = RollupConfig::default();
+        let parent_header = empty_parent_header();
+        let deposit = |from: Address, to: Address| -> Bytes {
+            OpTxEnvelope::Deposit(Sealed::new_unchecked(
+                TxDeposit { from, gas_limit: 100_000, to: TxKind::Call(to), ..Default::default() },
+                B256::ZERO,
+            ))
+            .encoded_2718()
+            .into()
+        };
+        let executor = |require_l1_info_tx| {
+            StatelessL2BlockExecutor::builder(&config, NoopTrieDBProvider, NoopTrieHinter)
+                .with_parent_header(parent_header.clone())
+                .with_allow_empty_blocks(true)
+                .with_require_l1_info_tx(require_l1_info_tx)
+                .build()
+        };
+
+        // Payloads lacking the L1 info deposit, or beginning with a deposit to the `L1Block`
+        // predeploy from another account, are rejected before execution.
+        for transactions in [
+            vec![],
+            vec![deposit(L1_INFO_DEPOSITOR, Address::repeat_byte(0xBB))],
+            vec![deposit(Address::repeat_byte(0xBB), L1_BLOCK_CONTRACT)],
+        ] {
+            let mut requiring = executor(true);
+            assert!(matches!(
+                requiring.execute_payload(synthetic_payload(transactions.clone())),
+                Err(ExecutorError::InvalidL1InfoTx)
+            ));
+            assert_eq!(*requiring.trie_db.parent_block_header(), parent_header);
+            executor(false).execute_payload(synthetic_payload(transactions)).unwrap();
+        }
+
+        // A payload beginning with the L1 info depositor's deposit to the `L1Block` predeploy is
+        // accepted.
+        executor(true)
+            .execute_payload(synthetic_payload(vec![deposit(L1_INFO_DEPOSITOR, L1_BLOCK_CONTRACT)]))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_parent_header() {
+        let config = RollupConfig::default();
+        let parent_header = empty_parent_header();
+        let mut executor =
+            StatelessL2BlockExecutor::builder(&config, NoopTrieDBProvider, NoopTrieHinter)
+                .with_parent_header(parent_header.clone())
+                .with_allow_empty_blocks(true)
+                .build();
+        assert_eq!(*executor.parent_header(), parent_header);
+
+        for timestamp in [2, 4] {
+            let mut payload = synthetic_payload(vec![]);
+            payload.payload_attributes.timestamp = timestamp;
+            let parent_hash = executor.parent_header().seal();
+            let artifacts = executor.execute_payload(payload).unwrap();
+
+            assert_eq!(artifacts.block_header.parent_hash, parent_hash);
+            assert_eq!(*executor.parent_header(), artifacts.block_header);
+        }
+        assert_eq!(executor.parent_header().number, 2);
+    }
+
+    #[test]
+    fn test_custom_message_passer_address() {
+        let config = RollupConfig::default();
+        let message_passer_address = Address::repeat_byte(0x42);
+        let storage_root = B256::repeat_byte(0xAA);
+
+        // A state trie holding only the relocated message passer account.
+        let mut account = Vec::new();
+        TrieAccount { storage_root, ..Default::default() }.encode(&mut account);
+        let mut trie = TrieNode::Empty;
+        trie.insert(
+            &Nibbles::unpack(keccak256(message_passer_address)),
+            account.into(),
+            &NoopTrieDBProvider,
+        )
+        .unwrap();
+        let mut provider = MapTrieDBProvider::default();
+        provider.insert_trie(&trie);
+        trie.blind();
+
+        let parent_header = Header {
+            number: 1,
+            state_root: trie.blinded_commitment().unwrap(),
+            ..Default::default()
+        }
+        .seal_slow();
+        let executor = |address| {
+            StatelessL2BlockExecutor::builder(&config, provider.clone(), NoopTrieHinter)
+                .with_parent_header(parent_header.clone())
+                .with_message_passer_address(address)
+                .build()
+        };
+
+        let components = OutputRootComponents {
+            state_root: parent_header.state_root,
+            storage_root,
+            block_hash: parent_header.seal(),
+        };
+        assert_eq!(
+            executor(message_passer_address).compute_output_root().unwrap(),
+            components.output_root()
+        );
+
+        // The canonical message passer is absent from the state.
+        let mut canonical = executor(address!("4200000000000000000000000000000000000016"));
+        assert!(matches!(
+            canonical.compute_output_root(),
+            Err(ExecutorError::TrieDBError(TrieDBError::MissingAccountInfo))
+        ));
+    }
+
+    #[test]
+    fn test_beacon_roots_call_disabled() {
+        let config = RollupConfig { ecotone_time: Some(0), ..Default::default() };
+        let parent_header = Header {
+            number: 1,
+            gas_limit: 30_000_000,
+            state_root: EMPTY_ROOT_HASH,
+            ..Default::default()
+        }
+        .seal_slow();
+        let mut executor =
+            StatelessL2BlockExecutor::builder(&config, NoopTrieDBProvider, NoopTrieHinter)
+                .with_parent_header(parent_header.clone())
+                .with_allow_empty_blocks(true)
+                .with_beacon_roots_call(false)
+                .build();
+
+        let mut payload = synthetic_payload(vec![]);
+        payload.payload_attributes.parent_beacon_block_root = Some(B256::repeat_byte(0xAA));
+        let artifacts = executor.execute_payload(payload).unwrap();
+
+        // The beacon roots contract's storage is never touched.
+        assert!(!executor.trie_db.storage_roots().contains_key(&BEACON_ROOTS_ADDRESS));
+        assert_eq!(artifacts.block_header.state_root, parent_header.state_root);
+
+        // Ecotone blocks commit to zero blob gas used.
+        assert_eq!(artifacts.block_header.blob_gas_used, Some(0));
+    }
+
+    #[test]
+    fn test_min_base_fee() {
+        let config = RollupConfig {
+            base_fee_params: alloy_eips::eip1559::BaseFeeParams::optimism(),
+            ..Default::default()
+        };
+        let parent_header = Header {
+            number: 1,
+            gas_limit: 30_000_000,
+            base_fee_per_gas: Some(1_000_000_000),
+            state_root: EMPTY_ROOT_HASH,
+            ..Default::default()
+        }
+        .seal_slow();
+        let payload = synthetic_payload(vec![]);
+
+        let base_fee = |min_base_fee: Option<u64>| {
+            let mut builder =
+                StatelessL2BlockExecutor::builder(&config, NoopTrieDBProvider, NoopTrieHinter)
+                    .with_parent_header(parent_header.clone())
+                    .with_allow_empty_blocks(true);
+            if let Some(min_base_fee) = min_base_fee {
+                builder = builder.with_min_base_fee(min_base_fee);
+            }
+            builder.build().execute_payload(payload.clone()).unwrap().block_header.base_fee_per_gas
+        };
+
+        // The empty parent block lowers the base fee by 1/50th, unless the floor prevents it.
+        assert_eq!(base_fee(None), Some(980_000_000));
+        assert_eq!(base_fee(Some(1_000_000_000)), Some(1_000_000_000));
+        assert_eq!(base_fee(Some(1)), Some(980_000_000));
+    }
+
+    /// Builds a state trie holding the given accounts, storing its preimages in the provider, and
+    /// returns its root.
+    fn state_root(provider: &mut MapTrieDBProvider, accounts: &[(Address, TrieAccount)]) -> B256 {
+        let mut trie = TrieNode::Empty;
+        for (address, account) in accounts {
+            let mut buf = Vec::new();
+            account.encode(&mut buf);
+            trie.insert(&Nibbles::unpack(keccak256(address)), buf.into(), &NoopTrieDBProvider)
+                .unwrap();
+        }
+        provider.insert_trie(&trie);
+        trie.blind();
+        trie.blinded_commitment().unwrap()
+    }
+
+    #[test]
+    fn test_prewarm_addresses() {
+        let config = RollupConfig::default();
+        let parent_header = empty_parent_header();
+        let payload = synthetic_payload(vec![]);
+        let hints = |prewarm_addresses: Option<Vec<Address>>| {
+            let hinter = RecordingTrieHinter::default();
+            let mut builder =
+                StatelessL2BlockExecutor::builder(&config, NoopTrieDBProvider, hinter.clone())
+                    .with_parent_header(parent_header.clone())
+                    .with_allow_empty_blocks(true);
+            if let Some(prewarm_addresses) = prewarm_addresses {
+                builder = builder.with_prewarm_addresses(prewarm_addresses);
+            }
+            builder.build().execute_payload(payload.clone()).unwrap();
+            hinter.0.lock().unwrap().clone()
+        };
+
+        // The standard predeploys are hinted before any account is loaded during execution.
+        assert!(hints(None).starts_with(&[L1_BLOCK_CONTRACT, GAS_PRICE_ORACLE, L2_TO_L1_BRIDGE]));
+
+        let custom = Address::repeat_byte(0x42);
+        assert!(hints(Some(vec![custom])).starts_with(&[custom]));
+        assert!(!hints(Some(vec![])).contains(&L1_BLOCK_CONTRACT));
+    }
+
+    #[test]
+    fn test_execute_and_verify() {
+        let config = RollupConfig::default();
+        let mut provider = MapTrieDBProvider::default();
+        let message_passer =
+            TrieAccount { storage_root: B256::repeat_byte(0xAA), ..Default::default() };
+        let parent_header = Header {
+            number: 1,
+            gas_limit: 30_000_000,
+            state_root: state_root(&mut provider, &[(L2_TO_L1_BRIDGE, message_passer)]),
+            ..Default::default()
+        }
+        .seal_slow();
+        let payload = synthetic_payload(vec![]);
+        let executor = || {
+            StatelessL2BlockExecutor::builder(&config, provider.clone(), NoopTrieHinter)
+                .with_parent_header(parent_header.clone())
+                .with_allow_empty_blocks(true)
+                .build()
+        };
+
+        let mut reference = executor();
+        reference.execute_payload(payload.clone()).unwrap();
+        let output_root = reference.compute_output_root().unwrap();
+
+        assert_eq!(
+            executor().execute_and_verify(payload.clone(), output_root).unwrap(),
+            output_root
+        );
+        assert!(matches!(
+            executor().execute_and_verify(payload, B256::ZERO),
+            Err(ExecutorError::OutputRootMismatch { expected: B256::ZERO, actual })
+                if actual == output_root
+        ));
+    }
+
+    #[test]
+    fn test_verify_starting_state() {
+        let config = RollupConfig::default();
+        let mut provider = MapTrieDBProvider::default();
+        let accounts = [
+            (L2_TO_L1_BRIDGE, TrieAccount::default()),
+            (L1_BLOCK_CONTRACT, TrieAccount { nonce: 1, ..Default::default() }),
+        ];
+        let parent_header = Header {
+            number: 1,
+            state_root: state_root(&mut provider, &accounts),
+            ..Default::default()
+        }
+        .seal_slow();
+        let executor = |provider: MapTrieDBProvider| {
+            StatelessL2BlockExecutor::builder(&config, provider, NoopTrieHinter)
+                .with_parent_header(parent_header.clone())
+                .build()
+        };
+
+        executor(provider.clone()).verify_starting_state().unwrap();
+
+        // A witness serving a different root node for the parent state root is rejected.
+        let mut wrong_witness = MapTrieDBProvider::default();
+        let wrong_root = state_root(&mut wrong_witness, &accounts[..1]);
+        let wrong_preimage = wrong_witness.0[&wrong_root].clone();
+        wrong_witness.0.insert(parent_header.state_root, wrong_preimage);
+        assert!(matches!(
+            executor(wrong_witness).verify_starting_state(),
+            Err(ExecutorError::StartingStateMismatch { expected, actual })
+                if expected == parent_header.state_root && actual == wrong_root
+        ));
+    }
+
+    #[test]
+    fn test_withdrawals_root_from_state() {
+        let config = RollupConfig { isthmus_time: Some(0), ..Default::default() };
+        let mut provider = MapTrieDBProvider::default();
+        let storage_root = B256::repeat_byte(0xAA);
+        let message_passer = TrieAccount { storage_root, ..Default::default() };
+        let parent_header = Header {
+            number: 1,
+            gas_limit: 30_000_000,
+            state_root: state_root(&mut provider, &[(L2_TO_L1_BRIDGE, message_passer)]),
+            ..Default::default()
+        }
+        .seal_slow();
+        let new_trie_db = || {
+            TrieDB::new(
+                parent_header.state_root,
+                parent_header.clone(),
+                provider.clone(),
+                NoopTrieHinter,
+            )
+        };
+
+        assert_eq!(
+            withdrawals_root_from_state(&mut new_trie_db(), L2_TO_L1_BRIDGE),
+            Ok(storage_root)
+        );
+        assert!(matches!(
+            withdrawals_root_from_state(&mut new_trie_db(), Address::repeat_byte(0x42)),
+            Err(TrieDBError::MissingAccountInfo)
+        ));
+
+        // The root matches the withdrawals root of an executed Isthmus block.
+        let mut executor =
+            StatelessL2BlockExecutor::builder(&config, provider.clone(), NoopTrieHinter)
+                .with_parent_header(parent_header.clone())
+                .with_allow_empty_blocks(true)
+                .build();
+        let artifacts = executor.execute_payload(synthetic_payload(vec![])).unwrap();
+        assert_eq!(artifacts.block_header.withdrawals_root, Some(storage_root));
+        assert_eq!(
+            withdrawals_root_from_state(&mut executor.trie_db, L2_TO_L1_BRIDGE),
+            Ok(storage_root)
+        );
+    }
+
+    #[test]
+    fn test_custom_message_passer_withdrawals_root() {
+        let config = RollupConfig { isthmus_time: Some(0), ..Default::default() };
+        let mut provider = MapTrieDBProvider::default();
+        let message_passer_address = Address::repeat_byte(0x42);
+        let storage_root = B256::repeat_byte(0xAA);
+        let message_passer = TrieAccount { storage_root, ..Default::default() };
+        let parent_header = Header {
+            number: 1,
+            gas_limit: 30_000_000,
+            state_root: state_root(&mut provider, &[(message_passer_address, message_passer)]),
+            ..Default::default()
+        }
+        .seal_slow();
+
+        // The withdrawals root of an Isthmus block is the storage root of the relocated message
+        // passer account.
+        let mut executor = StatelessL2BlockExecutor::builder(&config, provider, NoopTrieHinter)
+            .with_parent_header(parent_header)
+            .with_allow_empty_blocks(true)
+            .with_message_passer_address(message_passer_address)
+            .build();
+        let artifacts = executor.execute_payload(synthetic_payload(vec![])).unwrap();
+        assert_eq!(artifacts.block_header.withdrawals_root, Some(storage_root));
+    }
+
+    #[test]
+    fn test_diagnose_output_root() {
+        let config = RollupConfig::default();
+        let mut provider = MapTrieDBProvider::default();
+        let storage_root = B256::repeat_byte(0xAA);
+        let message_passer = TrieAccount { storage_root, ..Default::default() };
+        let parent_header = Header {
+            number: 1,
+            state_root: state_root(&mut provider, &[(L2_TO_L1_BRIDGE, message_passer)]),
+            ..Default::default()
+        }
+        .seal_slow();
+        let mut executor = StatelessL2BlockExecutor::builder(&config, provider, NoopTrieHinter)
+            .with_parent_header(parent_header.clone())
+            .build();
+
+        let components = executor.output_root_components().unwrap();
+        assert_eq!(
+            components,
+            OutputRootComponents {
+                state_root: parent_header.state_root,
+                storage_root,
+                block_hash: parent_header.seal(),
+            }
+        );
+        assert_eq!(executor.compute_output_root().unwrap(), components.output_root());
+        assert!(executor.diagnose_output_root(&components).unwrap().is_empty());
+
+        // A claim that only differs in the withdrawal storage root.
+        let claimed = OutputRootComponents { storage_root: B256::repeat_byte(0xBB), ..components };
+        assert_eq!(
+            executor.diagnose_output_root(&claimed).unwrap(),
+            vec![OutputRootComponent::StorageRoot]
+        );
+    }
+
+    #[test]
+    fn test_operator_fee() {
+        let config = RollupConfig::default();
+        let mut provider = MapTrieDBProvider::default();
+
+        // An operator fee scalar of 2 (scaled by 1e6) and constant of 1_000 wei, packed into the
+        // `L1Block` storage slot set by the Isthmus L1 info deposit.
+        let mut scalars = [0u8; 32];
+        scalars[20..24].copy_from_slice(&2_000_000u32.to_be_bytes());
+        scalars[24..32].copy_from_slice(&1_000u64.to_be_bytes());
+        let mut slot_value = Vec::new();
+        U256::from_be_bytes(scalars).encode(&mut slot_value);
+        let mut l1_block_storage = TrieNode::Empty;
+        l1_block_storage
+            .insert(
+                &Nibbles::unpack(keccak256(U256::from(8).to_be_bytes::<32>())),
+                slot_value.into(),
+                &NoopTrieDBProvider,
+            )
+            .unwrap();
+        provider.insert_trie(&l1_block_storage);
+        l1_block_storage.blind();
+        let l1_block = TrieAccount {
+            storage_root: l1_block_storage.blinded_commitment().unwrap(),
+            ..Default::default()
+        };
+
+        let tx = TxEip1559 {
+            chain_id: config.l2_chain_id,
+            gas_limit: 30_000,
+            to: TxKind::Call(Address::repeat_byte(0xBB)),
+            ..Default::default()
+        }
+        .into_signed(PrimitiveSignature::test_signature());
+        let caller = tx.recover_signer().unwrap();
+
+        let parent_header = Header {
+            number: 1,
+            gas_limit: 30_000_000,
+            state_root: state_root(
+                &mut provider,
+                &[
+                    (caller, TrieAccount { balance: U256::from(1_000_000), ..Default::default() }),
+                    (L1_BLOCK_CONTRACT, l1_block),
+                ],
+            ),
+            ..Default::default()
+        }
+        .seal_slow();
+
+        // The caller is charged the operator fee for the 30_000 gas limit up front, and refunded
+        // the overcharge for the 9_000 unused gas. The vault receives the fee for the gas used:
+        // 21_000 * 2_000_000 / 1e6 + 1_000 = 43_000 wei.
+        let expected_state_root = state_root(
+            &mut provider,
+            &[
+                (
+                    caller,
+                    TrieAccount { nonce: 1, balance: U256::from(957_000), ..Default::default() },
+                ),
+                (L1_BLOCK_CONTRACT, l1_block),
+                (
+                    OPERATOR_FEE_VAULT,
+                    TrieAccount { balance: U256::from(43_000), ..Default::default() },
+                ),
+            ],
+        );
+
+        let mut executor = StatelessL2BlockExecutor::builder(&config, provider, NoopTrieHinter)
+            .with_parent_header(parent_header)
+            .with_spec_override(SpecId::ISTHMUS)
+            .build();
+        let payload = synthetic_payload(vec![OpTxEnvelope::Eip1559(tx).encoded_2718().into()]);
+        let artifacts = executor.execute_payload(payload).unwrap();
+
+        assert!(artifacts.receipts[0].is_success());
+        assert_eq!(artifacts.block_header.gas_used, 21_000);
+        assert_eq!(artifacts.block_header.state_root, expected_state_root);
+        let vault = executor.trie_db.get_trie_account(&OPERATOR_FEE_VAULT, 2).unwrap().unwrap();
+        assert_eq!(vault.balance, U256::from(43_000));
+    }
+
+    #[test]
+    fn test_eip7623_calldata_floor() {
+        let mut provider = MapTrieDBProvider::default();
+        let tx = TxEip1559 {
+            gas_limit: 100_000,
+            to: TxKind::Call(Address::repeat_byte(0xBB)),
+            input: vec![0xFF; 1_000].into(),
+            ..Default::default()
+        }
+        .into_signed(PrimitiveSignature::test_signature());
+        let caller = tx.recover_signer().unwrap();
+        let parent_header = Header {
+            number: 1,
+            gas_limit: 30_000_000,
+            state_root: state_root(
+                &mut provider,
+                &[
+                    (caller, TrieAccount { balance: U256::from(1_000_000), ..Default::default() }),
+                    (L1_BLOCK_CONTRACT, TrieAccount::default()),
+                    (L2_TO_L1_BRIDGE, TrieAccount::default()),
+                ],
+            ),
+            ..Default::default()
+        }
+        .seal_slow();
+        let payload = synthetic_payload(vec![OpTxEnvelope::Eip1559(tx).encoded_2718().into()]);
+        let gas_used = |config: &RollupConfig| {
+            let mut executor =
+                StatelessL2BlockExecutor::builder(config, provider.clone(), NoopTrieHinter)
+                    .with_parent_header(parent_header.clone())
+                    .build();
+            let artifacts = executor.execute_payload(payload.clone()).unwrap();
+            assert!(artifacts.receipts[0].is_success());
+            (executor.spec_id(2), artifacts.block_header.gas_used)
+        };
+
+        // The call to an account without code only pays the intrinsic gas: 21_000 plus 16 per
+        // non-zero calldata byte.
+        let (spec_id, standard) = gas_used(&RollupConfig::default());
+        assert!(!spec_id.is_enabled_in(SpecId::PRAGUE));
+        assert_eq!(standard, 21_000 + 16 * 1_000);
+
+        // Isthmus selects a spec with EIP-7623 active, raising the gas used to the calldata floor:
+        // 21_000 plus 10 per token, where each non-zero byte counts as 4 tokens.
+        let isthmus = RollupConfig { isthmus_time: Some(0), ..Default::default() };
+        let (spec_id, floor) = gas_used(&isthmus);
+        assert!(spec_id.is_enabled_in(SpecId::PRAGUE));
+        assert_eq!(floor, 21_000 + 10 * 4 * 1_000);
+    }
+
+    #[test]
+    fn test_chain_id_override() {
+        let config = RollupConfig { l2_chain_id: 1, ..Default::default() };
+        let mut provider = MapTrieDBProvider::default();
+
+        // A legacy transaction with EIP-155 replay protection for chain `10`.
+        let tx = TxLegacy {
+            chain_id: Some(10),
+            gas_limit: 21_000,
+            to: TxKind::Call(Address::repeat_byte(0xBB)),
+            ..Default::default()
+        }
+        .into_signed(PrimitiveSignature::test_signature());
+        let caller = tx.recover_signer().unwrap();
+        let parent_header = Header {
+            number: 1,
+            gas_limit: 30_000_000,
+            state_root: state_root(
+                &mut provider,
+                &[(caller, TrieAccount::default()), (L1_BLOCK_CONTRACT, TrieAccount::default())],
+            ),
+            ..Default::default()
+        }
+        .seal_slow();
+        let payload = synthetic_payload(vec![OpTxEnvelope::Legacy(tx).encoded_2718().into()]);
+        let builder = || {
+            StatelessL2BlockExecutor::builder(&config, provider.clone(), NoopTrieHinter)
+                .with_parent_header(parent_header.clone())
+        };
+
+        // Under the rollup config's chain id, the replay protected transaction is rejected.
+        assert!(matches!(
+            builder().build().execute_payload(payload.clone()),
+            Err(ExecutorError::ExecutionError(EVMError::Transaction(
+                InvalidTransaction::InvalidChainId
+            )))
+        ));
+
+        // Under the overridden chain id, the transaction executes from the recovered signer.
+        let mut executor = builder().with_chain_id(10).build();
+        let artifacts = executor.execute_payload(payload).unwrap();
+        assert!(artifacts.receipts[0].is_success());
+        let caller_account = executor.trie_db.get_trie_account(&caller, 2).unwrap().unwrap();
+        assert_eq!(caller_account.nonce, 1);
+    }
+
+    /// An [Inspector] that counts the calls and creations it observes.
+    #[derive(Debug, Default, Clone)]
+    struct CountingInspector {
+        calls: usize,
+        creates: usize,
+    }
+
+    impl<DB: Database> Inspector<DB> for CountingInspector {
+        fn call(&mut self, _: &mut EvmContext<DB>, _: &mut CallInputs) -> Option<CallOutcome> {
+            self.calls += 1;
+            None
+        }
+
+        fn create(
+            &mut self,
+            _: &mut EvmContext<DB>,
+            _: &mut CreateInputs,
+        ) -> Option<CreateOutcome> {
+            self.creates += 1;
+            None
+        }
+    }
+
+    /// A [KonaHandleRegister] that leaves the handler unchanged.
+    fn noop_handle_register<F, H>(_: &mut EvmHandler<'_, (), &mut State<&mut TrieDB<F, H>>>)
+    where
+        F: TrieDBProvider,
+        H: TrieHinter,
+    {
+    }
+
+    #[test]
+    fn test_inspector() {
+        let config = RollupConfig::default();
+        let mut provider = MapTrieDBProvider::default();
+
+        // A contract creation whose initcode calls `0xCC` with all remaining gas.
+        let tx = TxLegacy {
+            gas_limit: 100_000,
+            to: TxKind::Create,
+            input: alloy_primitives::bytes!("6000600060006000600060cc5af100"),
+            ..Default::default()
+        }
+        .into_signed(PrimitiveSignature::test_signature());
+        let caller = tx.recover_signer().unwrap();
+        let parent_header = Header {
+            number: 1,
+            gas_limit: 30_000_000,
+            state_root: state_root(
+                &mut provider,
+                &[(caller, TrieAccount::default()), (L1_BLOCK_CONTRACT, TrieAccount::default())],
+            ),
+            ..Default::default()
+        }
+        .seal_slow();
+        let payload = synthetic_payload(vec![OpTxEnvelope::Legacy(tx).encoded_2718().into()]);
+
+        let mut executor = StatelessL2BlockExecutor::builder(&config, provider, NoopTrieHinter)
+            .with_parent_header(parent_header)
+            .with_inspector(CountingInspector::default())
+            .build();
+        let artifacts = executor.execute_payload(payload).unwrap();
+        assert!(artifacts.receipts[0].is_success());
+
+        // The inspector can only be retrieved as its concrete type.
+        assert!(executor.inspector_mut::<revm::inspectors::NoOpInspector>().is_none());
+        assert_eq!(executor.inspector_mut::<CountingInspector>().unwrap().creates, 1);
+        let inspector = executor.into_inspector::<CountingInspector>().unwrap();
+        assert_eq!(inspector.creates, 1);
+        assert_eq!(inspector.calls, 1);
+    }
+
+    #[test]
+    fn test_inspector_with_handle_register() {
+        let config = RollupConfig::default();
+        let mut executor =
+            StatelessL2BlockExecutor::builder(&config, NoopTrieDBProvider, NoopTrieHinter)
+                .with_parent_header(empty_parent_header())
+                .with_inspector(CountingInspector::default())
+                .with_handle_register(noop_handle_register)
+                .build();
+        let payload = synthetic_payload(vec![]);
+
+        // The handle register cannot be applied in full to the inspected EVM.
+        assert!(matches!(
+            executor.execute_payload(payload),
+            Err(ExecutorError::InspectorWithHandleRegister)
+        ));
+    }
 }
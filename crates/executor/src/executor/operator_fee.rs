@@ -0,0 +1,157 @@
+//! Accounting of the Isthmus operator fee.
+
+use crate::constants::{L1_BLOCK_CONTRACT, OPERATOR_FEE_VAULT};
+use alloc::{boxed::Box, sync::Arc};
+use alloy_primitives::U256;
+use revm::{
+    handler::register::EvmHandler,
+    interpreter::Gas,
+    primitives::{EVMError, InvalidTransaction, SpecId},
+    Context, Database,
+};
+
+/// The storage slot of the `L1Block` predeploy holding the operator fee scalar and constant.
+const OPERATOR_FEE_SCALARS_SLOT: U256 = U256::from_limbs([8, 0, 0, 0]);
+
+/// The divisor of the operator fee scalar.
+const OPERATOR_FEE_SCALAR_DIVISOR: u64 = 1_000_000;
+
+/// The operator fee parameters, as set in the `L1Block` predeploy from the system config by the
+/// L1 info deposit.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct OperatorFeeParams {
+    /// The operator fee scalar, scaled by `1e6`.
+    pub(crate) scalar: u32,
+    /// The operator fee constant, in wei.
+    pub(crate) constant: u64,
+}
+
+impl OperatorFeeParams {
+    /// Decodes the [OperatorFeeParams] from the packed `L1Block` storage slot, which holds the
+    /// scalar in bytes `20..24` and the constant in bytes `24..32`.
+    pub(crate) fn from_slot(slot: U256) -> Self {
+        let bytes = slot.to_be_bytes::<32>();
+        Self {
+            scalar: u32::from_be_bytes(bytes[20..24].try_into().expect("4 bytes")),
+            constant: u64::from_be_bytes(bytes[24..32].try_into().expect("8 bytes")),
+        }
+    }
+
+    /// Returns the operator fee charged for the given amount of gas.
+    ///
+    /// `operator_fee = gas * scalar / 1e6 + constant`
+    pub(crate) fn charge(&self, gas: u64) -> U256 {
+        U256::from(gas) * U256::from(self.scalar) / U256::from(OPERATOR_FEE_SCALAR_DIVISOR) +
+            U256::from(self.constant)
+    }
+
+    /// Fetches the [OperatorFeeParams] for the transaction in the given [Context].
+    ///
+    /// Returns `None` for deposit transactions, which are not charged the operator fee.
+    fn fetch<EXT, DB: Database>(
+        context: &mut Context<EXT, DB>,
+    ) -> Result<Option<Self>, EVMError<DB::Error>> {
+        if context.evm.inner.env.tx.optimism.source_hash.is_some() {
+            return Ok(None);
+        }
+
+        // Ensure that the `L1Block` account is loaded into the cache prior to reading its storage.
+        let db = &mut context.evm.inner.db;
+        db.basic(L1_BLOCK_CONTRACT).map_err(EVMError::Database)?;
+        let slot =
+            db.storage(L1_BLOCK_CONTRACT, OPERATOR_FEE_SCALARS_SLOT).map_err(EVMError::Database)?;
+        Ok(Some(Self::from_slot(slot)))
+    }
+}
+
+/// Returns the gas used by a transaction, net of refunds.
+const fn gas_used(gas: &Gas) -> u64 {
+    gas.spent() - gas.refunded() as u64
+}
+
+/// A handle register that applies the operator fee to non-deposit transactions once Isthmus is
+/// active.
+///
+/// The operator fee for the transaction's gas limit is deducted from the caller up front, and the
+/// portion for unused gas is reimbursed after execution. The operator fee for the gas used is
+/// credited to the [OPERATOR_FEE_VAULT].
+pub(crate) fn operator_fee_handle_register<EXT, DB: Database>(
+    handler: &mut EvmHandler<'_, EXT, DB>,
+) {
+    if !handler.cfg.spec_id.is_enabled_in(SpecId::ISTHMUS) {
+        return;
+    }
+
+    let deduct_caller = handler.pre_execution.deduct_caller.clone();
+    handler.pre_execution.deduct_caller = Arc::new(move |context| {
+        deduct_caller(context)?;
+
+        let Some(params) = OperatorFeeParams::fetch(context)? else {
+            return Ok(());
+        };
+        let charge = params.charge(context.evm.inner.env.tx.gas_limit);
+        let caller = context.evm.inner.env.tx.caller;
+        let account =
+            context.evm.inner.journaled_state.load_account(caller, &mut context.evm.inner.db)?.data;
+        if account.info.balance < charge {
+            return Err(EVMError::Transaction(InvalidTransaction::LackOfFundForMaxFee {
+                fee: Box::new(charge),
+                balance: Box::new(account.info.balance),
+            }));
+        }
+        account.info.balance -= charge;
+        Ok(())
+    });
+
+    let reimburse_caller = handler.post_execution.reimburse_caller.clone();
+    handler.post_execution.reimburse_caller = Arc::new(move |context, gas| {
+        reimburse_caller(context, gas)?;
+
+        let Some(params) = OperatorFeeParams::fetch(context)? else {
+            return Ok(());
+        };
+        let refund =
+            params.charge(context.evm.inner.env.tx.gas_limit) - params.charge(gas_used(gas));
+        let caller = context.evm.inner.env.tx.caller;
+        let account =
+            context.evm.inner.journaled_state.load_account(caller, &mut context.evm.inner.db)?.data;
+        account.info.balance = account.info.balance.saturating_add(refund);
+        Ok(())
+    });
+
+    let reward_beneficiary = handler.post_execution.reward_beneficiary.clone();
+    handler.post_execution.reward_beneficiary = Arc::new(move |context, gas| {
+        reward_beneficiary(context, gas)?;
+
+        let Some(params) = OperatorFeeParams::fetch(context)? else {
+            return Ok(());
+        };
+        let vault = context
+            .evm
+            .inner
+            .journaled_state
+            .load_account(OPERATOR_FEE_VAULT, &mut context.evm.inner.db)?
+            .data;
+        vault.mark_touch();
+        vault.info.balance = vault.info.balance.saturating_add(params.charge(gas_used(gas)));
+        Ok(())
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_operator_fee_params_from_slot() {
+        let mut slot = [0u8; 32];
+        slot[20..24].copy_from_slice(&2_000_000u32.to_be_bytes());
+        slot[24..32].copy_from_slice(&1_000u64.to_be_bytes());
+        let params = OperatorFeeParams::from_slot(U256::from_be_bytes(slot));
+        assert_eq!(params, OperatorFeeParams { scalar: 2_000_000, constant: 1_000 });
+
+        // 21_000 * 2_000_000 / 1e6 + 1_000
+        assert_eq!(params.charge(21_000), U256::from(43_000));
+        assert_eq!(OperatorFeeParams::default().charge(21_000), U256::ZERO);
+    }
+}
@@ -0,0 +1,140 @@
+//! Contains the components of an output root, and diagnostics for mismatched output roots.
+
+use crate::constants::OUTPUT_ROOT_VERSION;
+use alloc::vec::Vec;
+use alloy_primitives::{keccak256, B256};
+
+/// The size of an output root preimage, in bytes.
+const OUTPUT_ROOT_PREIMAGE_SIZE: usize = 128;
+
+/// The components committed to by an output root.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct OutputRootComponents {
+    /// The state root of the L2 block.
+    pub state_root: B256,
+    /// The storage root of the L2 to L1 message passer.
+    pub storage_root: B256,
+    /// The hash of the L2 block.
+    pub block_hash: B256,
+}
+
+impl OutputRootComponents {
+    /// Decodes the [OutputRootComponents] from an output root preimage.
+    ///
+    /// Returns `None` if the preimage is not `128` bytes long or has an unknown version.
+    pub fn decode(preimage: &[u8]) -> Option<Self> {
+        if preimage.len() != OUTPUT_ROOT_PREIMAGE_SIZE ||
+            preimage[..32] != B256::left_padding_from(&[OUTPUT_ROOT_VERSION])[..]
+        {
+            return None;
+        }
+        Some(Self {
+            state_root: B256::from_slice(&preimage[32..64]),
+            storage_root: B256::from_slice(&preimage[64..96]),
+            block_hash: B256::from_slice(&preimage[96..128]),
+        })
+    }
+
+    /// Encodes the output root preimage.
+    ///
+    /// **CONSTRUCTION:**
+    /// ```text
+    /// preimage = version_byte .. state_root .. withdrawal_storage_root .. latest_block_hash
+    /// ```
+    pub fn encode(&self) -> [u8; OUTPUT_ROOT_PREIMAGE_SIZE] {
+        let mut raw_output = [0u8; OUTPUT_ROOT_PREIMAGE_SIZE];
+        raw_output[31] = OUTPUT_ROOT_VERSION;
+        raw_output[32..64].copy_from_slice(self.state_root.as_ref());
+        raw_output[64..96].copy_from_slice(self.storage_root.as_ref());
+        raw_output[96..128].copy_from_slice(self.block_hash.as_ref());
+        raw_output
+    }
+
+    /// Returns the output root committing to the components.
+    pub fn output_root(&self) -> B256 {
+        keccak256(self.encode())
+    }
+
+    /// Returns the value of the given component.
+    pub const fn get(&self, component: OutputRootComponent) -> B256 {
+        match component {
+            OutputRootComponent::StateRoot => self.state_root,
+            OutputRootComponent::StorageRoot => self.storage_root,
+            OutputRootComponent::BlockHash => self.block_hash,
+        }
+    }
+
+    /// Returns the components that differ between `self` and `other`, in the order they are
+    /// committed to by the output root.
+    pub fn diff(&self, other: &Self) -> Vec<OutputRootComponent> {
+        OutputRootComponent::ALL
+            .into_iter()
+            .filter(|component| self.get(*component) != other.get(*component))
+            .collect()
+    }
+}
+
+/// A component of an output root.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputRootComponent {
+    /// The state root of the L2 block.
+    StateRoot,
+    /// The storage root of the L2 to L1 message passer.
+    StorageRoot,
+    /// The hash of the L2 block.
+    BlockHash,
+}
+
+impl OutputRootComponent {
+    /// All components, in the order they are committed to by the output root.
+    pub const ALL: [Self; 3] = [Self::StateRoot, Self::StorageRoot, Self::BlockHash];
+}
+
+impl core::fmt::Display for OutputRootComponent {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::StateRoot => write!(f, "state root"),
+            Self::StorageRoot => write!(f, "withdrawal storage root"),
+            Self::BlockHash => write!(f, "block hash"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_output_root_components_roundtrip() {
+        let components = OutputRootComponents {
+            state_root: B256::repeat_byte(0x01),
+            storage_root: B256::repeat_byte(0x02),
+            block_hash: B256::repeat_byte(0x03),
+        };
+        let preimage = components.encode();
+        assert_eq!(OutputRootComponents::decode(&preimage), Some(components));
+        assert_eq!(components.output_root(), keccak256(preimage));
+
+        assert_eq!(OutputRootComponents::decode(&preimage[..96]), None);
+        let mut unknown_version = preimage;
+        unknown_version[31] = 0x01;
+        assert_eq!(OutputRootComponents::decode(&unknown_version), None);
+    }
+
+    #[test]
+    fn test_output_root_components_diff() {
+        let components = OutputRootComponents::default();
+        assert!(components.diff(&components).is_empty());
+
+        let other = OutputRootComponents {
+            state_root: B256::repeat_byte(0x01),
+            block_hash: B256::repeat_byte(0x03),
+            ..components
+        };
+        assert_eq!(
+            components.diff(&other),
+            vec![OutputRootComponent::StateRoot, OutputRootComponent::BlockHash]
+        );
+    }
+}
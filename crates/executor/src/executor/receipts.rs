@@ -0,0 +1,217 @@
+//! Construction of receipts and the receipts root from per-transaction execution results.
+
+use crate::{ExecutorError, ExecutorResult};
+use alloc::vec::Vec;
+use alloy_eips::eip2718::Encodable2718;
+use alloy_primitives::{Log, B256};
+use kona_mpt::ordered_trie_with_encoder;
+use maili_genesis::RollupConfig;
+use op_alloy_consensus::{OpReceiptEnvelope, OpTxType};
+
+/// The result of executing a single transaction within a block, holding the fields committed to by
+/// its receipt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxExecutionResult {
+    /// The EIP-2718 type of the transaction.
+    pub tx_type: OpTxType,
+    /// Whether the transaction succeeded.
+    pub success: bool,
+    /// The gas used by the transaction.
+    pub gas_used: u64,
+    /// The logs emitted by the transaction.
+    pub logs: Vec<Log>,
+    /// The nonce of the depositor prior to a deposit transaction, post-Regolith. `None` for
+    /// non-deposit transactions.
+    pub deposit_nonce: Option<u64>,
+}
+
+impl TxExecutionResult {
+    /// Builds the receipt of the transaction.
+    ///
+    /// ## Takes
+    /// - `cumulative_gas_used`: The gas used in the block up to and including the transaction.
+    /// - `config`: The [RollupConfig] of the chain.
+    /// - `timestamp`: The timestamp of the block.
+    pub fn into_receipt(
+        self,
+        cumulative_gas_used: u64,
+        config: &RollupConfig,
+        timestamp: u64,
+    ) -> OpReceiptEnvelope {
+        let deposit_receipt_version = self
+            .deposit_nonce
+            .is_some()
+            .then(|| config.is_canyon_active(timestamp).then_some(1))
+            .flatten();
+        OpReceiptEnvelope::<Log>::from_parts(
+            self.success,
+            cumulative_gas_used,
+            &self.logs,
+            self.tx_type,
+            self.deposit_nonce,
+            deposit_receipt_version,
+        )
+    }
+}
+
+/// Computes the receipts root of a block from the execution results of its transactions, without
+/// re-executing them.
+///
+/// ## Takes
+/// - `results`: The [TxExecutionResult]s of the block's transactions, in order.
+/// - `config`: The [RollupConfig] of the chain.
+/// - `timestamp`: The timestamp of the block.
+///
+/// ## Returns
+/// The receipts root of the block.
+pub fn compute_receipts_root_from_results(
+    results: &[TxExecutionResult],
+    config: &RollupConfig,
+    timestamp: u64,
+) -> B256 {
+    let mut cumulative_gas_used = 0u64;
+    let receipts = results
+        .iter()
+        .cloned()
+        .map(|result| {
+            cumulative_gas_used += result.gas_used;
+            result.into_receipt(cumulative_gas_used, config, timestamp)
+        })
+        .collect::<Vec<_>>();
+    compute_receipts_root(&receipts, config, timestamp)
+}
+
+/// Computes the gas used by a block from its receipts, without re-executing it.
+///
+/// ## Takes
+/// - `receipts`: The receipts of the block's transactions, in order.
+///
+/// ## Returns
+/// - `Ok(gas_used)` - The cumulative gas used of the last receipt, or `0` if there are none.
+/// - `Err(_)` - If the cumulative gas used decreases between two receipts.
+pub fn gas_used_from_receipts(receipts: &[OpReceiptEnvelope]) -> ExecutorResult<u64> {
+    receipts.iter().enumerate().try_fold(0, |previous, (index, receipt)| {
+        let current = receipt.cumulative_gas_used();
+        if current < previous {
+            return Err(ExecutorError::NonMonotonicCumulativeGasUsed { index, previous, current });
+        }
+        Ok(current)
+    })
+}
+
+/// Computes the receipts root from the given set of receipts.
+///
+/// ## Takes
+/// - `receipts`: The receipts to compute the root for.
+/// - `config`: The rollup config to use for the computation.
+/// - `timestamp`: The timestamp to use for the computation.
+///
+/// ## Returns
+/// The computed receipts root.
+pub(crate) fn compute_receipts_root(
+    receipts: &[OpReceiptEnvelope],
+    config: &RollupConfig,
+    timestamp: u64,
+) -> B256 {
+    // There is a minor bug in op-geth and op-erigon where in the Regolith hardfork,
+    // the receipt root calculation does not inclide the deposit nonce in the
+    // receipt encoding. In the Regolith hardfork, we must strip the deposit nonce
+    // from the receipt encoding to match the receipt root calculation.
+    if config.is_regolith_active(timestamp) && !config.is_canyon_active(timestamp) {
+        let receipts = receipts
+            .iter()
+            .cloned()
+            .map(|receipt| match receipt {
+                OpReceiptEnvelope::Deposit(mut deposit_receipt) => {
+                    deposit_receipt.receipt.deposit_nonce = None;
+                    OpReceiptEnvelope::Deposit(deposit_receipt)
+                }
+                _ => receipt,
+            })
+            .collect::<Vec<_>>();
+
+        ordered_trie_with_encoder(receipts.as_ref(), |receipt, mut buf| {
+            receipt.encode_2718(&mut buf)
+        })
+        .root()
+    } else {
+        ordered_trie_with_encoder(receipts, |receipt, mut buf| receipt.encode_2718(&mut buf)).root()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_utils::load_test_fixture, StatelessL2BlockExecutor};
+    use alloy_consensus::{Header, Sealable};
+    use kona_mpt::NoopTrieHinter;
+    use std::path::PathBuf;
+
+    #[tokio::test]
+    async fn test_compute_receipts_root_from_results() {
+        let fixture_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("testdata")
+            .join("block-22884230.tar.gz");
+        let (_fixture_dir, fixture, provider) = load_test_fixture(fixture_path).await;
+        let timestamp = fixture.executing_payload.payload_attributes.timestamp;
+
+        let mut executor =
+            StatelessL2BlockExecutor::builder(&fixture.rollup_config, provider, NoopTrieHinter)
+                .with_parent_header(fixture.parent_header.seal_slow())
+                .build();
+        let artifacts = executor.execute_payload(fixture.executing_payload).unwrap();
+
+        // Recover the per-transaction results from the receipts.
+        let mut prior_gas_used = 0;
+        let results = artifacts
+            .receipts
+            .iter()
+            .map(|receipt| {
+                let gas_used = receipt.cumulative_gas_used() - prior_gas_used;
+                prior_gas_used += gas_used;
+                TxExecutionResult {
+                    tx_type: receipt.tx_type(),
+                    success: receipt.status(),
+                    gas_used,
+                    logs: receipt.logs().to_vec(),
+                    deposit_nonce: receipt.as_deposit_receipt().and_then(|r| r.deposit_nonce),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // The fixture's canonical block hash commits to the canonical receipts root, so
+        // substituting the computed root into the executed header must reproduce it.
+        let receipts_root =
+            compute_receipts_root_from_results(&results, &fixture.rollup_config, timestamp);
+        let header = Header { receipts_root, ..artifacts.block_header.inner().clone() };
+        assert_eq!(header.hash_slow(), fixture.expected_block_hash);
+    }
+
+    #[test]
+    fn test_gas_used_from_receipts() {
+        let receipt = |cumulative_gas_used| {
+            TxExecutionResult {
+                tx_type: OpTxType::Eip1559,
+                success: true,
+                gas_used: 0,
+                logs: Vec::new(),
+                deposit_nonce: None,
+            }
+            .into_receipt(cumulative_gas_used, &RollupConfig::default(), 0)
+        };
+
+        assert_eq!(gas_used_from_receipts(&[]).unwrap(), 0);
+        let receipts = [receipt(21_000), receipt(21_000), receipt(50_000)];
+        assert_eq!(gas_used_from_receipts(&receipts).unwrap(), 50_000);
+
+        let receipts = [receipt(21_000), receipt(50_000), receipt(30_000)];
+        assert!(matches!(
+            gas_used_from_receipts(&receipts),
+            Err(ExecutorError::NonMonotonicCumulativeGasUsed {
+                index: 2,
+                previous: 50_000,
+                current: 30_000
+            })
+        ));
+    }
+}
@@ -1,12 +1,71 @@
 //! Contains utilities for the L2 executor.
 
-use crate::{constants::HOLOCENE_EXTRA_DATA_VERSION, ExecutorError, ExecutorResult};
+use crate::{
+    constants::HOLOCENE_EXTRA_DATA_VERSION, db::TrieDB, errors::TrieDBError, ExecutorError,
+    ExecutorResult, TrieDBProvider, TrieDBResult,
+};
 use alloc::vec::Vec;
 use alloy_consensus::Header;
-use alloy_eips::eip1559::BaseFeeParams;
-use alloy_primitives::{Bytes, B64};
+use alloy_eips::{eip1559::BaseFeeParams, eip4844::TARGET_DATA_GAS_PER_BLOCK};
+use alloy_primitives::{logs_bloom, Address, Bloom, Bytes, B256, B64};
+use kona_mpt::TrieHinter;
 use maili_genesis::RollupConfig;
+use op_alloy_consensus::{OpReceiptEnvelope, OpTxEnvelope};
 use op_alloy_rpc_types_engine::OpPayloadAttributes;
+use revm::primitives::{calc_excess_blob_gas, OptimismFields};
+
+/// Returns whether or not the transaction is exempt from the block gas limit check.
+///
+/// Prior to Regolith, system deposit transactions were not metered against the block's gas limit.
+/// After Regolith, system deposits are no longer special-cased, and all transactions count
+/// towards the block's gas limit. Only deposits that explicitly set the `isSystemTx` flag are
+/// considered system transactions.
+///
+/// ## Takes
+/// - `transaction`: The transaction to check.
+/// - `is_regolith`: Whether or not the Regolith hardfork is active for the executing block.
+///
+/// ## Returns
+/// - `true` if the transaction is a pre-Regolith system deposit.
+/// - `false` otherwise.
+pub(crate) fn is_block_gas_limit_exempt(transaction: &OpTxEnvelope, is_regolith: bool) -> bool {
+    !is_regolith && matches!(transaction, OpTxEnvelope::Deposit(tx) if tx.is_system_transaction)
+}
+
+/// Returns the [OptimismFields] of the transaction environment for the given transaction.
+///
+/// Only deposit transactions carry a source hash, a mint value, and the system transaction flag.
+/// The flag is passed through unchanged, as the per-fork handling of system deposits is performed
+/// by the EVM's optimism handler:
+/// - Pre-Regolith, system deposits are exempt from gas accounting.
+/// - Post-Regolith, system deposits are no longer permitted, and are executed as failed deposits.
+///
+/// ## Takes
+/// - `transaction`: The transaction to build the fields for.
+/// - `encoded_transaction`: The EIP-2718 encoding of the transaction.
+///
+/// ## Returns
+/// - The [OptimismFields] for the transaction.
+pub(crate) fn deposit_context(
+    transaction: &OpTxEnvelope,
+    encoded_transaction: &[u8],
+) -> OptimismFields {
+    let enveloped_tx = Some(Bytes::copy_from_slice(encoded_transaction));
+    match transaction {
+        OpTxEnvelope::Deposit(tx) => OptimismFields {
+            source_hash: Some(tx.source_hash),
+            mint: tx.mint,
+            is_system_transaction: Some(tx.is_system_transaction),
+            enveloped_tx,
+        },
+        _ => OptimismFields {
+            source_hash: None,
+            mint: None,
+            is_system_transaction: Some(false),
+            enveloped_tx,
+        },
+    }
+}
 
 /// Parse Holocene [Header] extra data.
 ///
@@ -82,17 +141,153 @@ pub(crate) fn encode_canyon_base_fee_params(config: &RollupConfig) -> B64 {
     buf
 }
 
+/// Returns the target blob gas of the parent block, used as the final argument to
+/// [calc_excess_blob_gas] when computing the excess blob gas of its child.
+///
+/// Once Isthmus is active in the parent block, the EIP-4844 target blob gas per block,
+/// [TARGET_DATA_GAS_PER_BLOCK], is subtracted from the parent's accumulated blob gas. EIP-7742
+/// would instead source the target from a per-block target blob count, which the [Header] does
+/// not carry. Before Isthmus, no target is subtracted.
+///
+/// ## Takes
+/// - `config`: The [RollupConfig] of the chain.
+/// - `parent_header`: The [Header] of the parent block.
+///
+/// ## Returns
+/// - The target blob gas of the parent block.
+pub(crate) fn parent_target_blob_gas(config: &RollupConfig, parent_header: &Header) -> u64 {
+    if config.is_isthmus_active(parent_header.timestamp) {
+        TARGET_DATA_GAS_PER_BLOCK
+    } else {
+        0
+    }
+}
+
+/// Computes the excess blob gas of the block built on top of `parent_header`.
+///
+/// For the first post-Ecotone block, both of the parent's blob gas fields are evaluated to `0`.
+///
+/// ## Takes
+/// - `config`: The [RollupConfig] of the chain.
+/// - `parent_header`: The [Header] of the parent block.
+///
+/// ## Returns
+/// - The excess blob gas of the child block.
+pub(crate) fn compute_excess_blob_gas(config: &RollupConfig, parent_header: &Header) -> u64 {
+    if !config.is_ecotone_active(parent_header.timestamp) {
+        return calc_excess_blob_gas(0, 0, 0);
+    }
+
+    calc_excess_blob_gas(
+        parent_header.excess_blob_gas.unwrap_or_default(),
+        parent_header.blob_gas_used.unwrap_or_default(),
+        parent_target_blob_gas(config, parent_header),
+    )
+}
+
+/// Computes the logs bloom of a block from the receipts of its transactions.
+pub(crate) fn compute_logs_bloom(receipts: &[OpReceiptEnvelope]) -> Bloom {
+    logs_bloom(receipts.iter().flat_map(|receipt| receipt.logs()))
+}
+
+/// Verifies the logs bloom of a block [Header] against the receipts of its transactions.
+///
+/// The bloom is recomputed from the logs of the receipts, which allows for receipt tampering to be
+/// detected independently of the state root.
+///
+/// ## Takes
+/// - `header`: The [Header] of the block.
+/// - `receipts`: The receipts of the block's transactions, in order.
+///
+/// ## Returns
+/// - `true` if the recomputed bloom matches the header's `logs_bloom` field, `false` otherwise.
+pub fn verify_logs_bloom(header: &Header, receipts: &[OpReceiptEnvelope]) -> bool {
+    compute_logs_bloom(receipts) == header.logs_bloom
+}
+
+/// Computes the withdrawals root of a block from the state of the [TrieDB], independently of
+/// block execution.
+///
+/// From Isthmus onwards, the withdrawals root in the block header is the storage root of the L2 to
+/// L1 message passer account. Account proofs are hinted at the [TrieDB]'s parent block number.
+///
+/// ## Takes
+/// - `db`: The [TrieDB] holding the state of the block.
+/// - `message_passer_address`: The address of the L2 to L1 message passer account.
+///
+/// ## Returns
+/// - `Ok(withdrawals_root)`: The storage root of the message passer account.
+/// - `Err(_)`: The account does not exist, or its storage root could not be computed.
+pub fn withdrawals_root_from_state<F, H>(
+    db: &mut TrieDB<F, H>,
+    message_passer_address: Address,
+) -> TrieDBResult<B256>
+where
+    F: TrieDBProvider,
+    H: TrieHinter,
+{
+    let block_number = db.parent_block_header().number;
+    message_passer_storage_root(db, message_passer_address, block_number)
+}
+
+/// Fetches the storage root of the L2 to L1 message passer account at the given address from the
+/// cache or underlying trie, hinting account proofs at the given block number.
+pub(crate) fn message_passer_storage_root<F, H>(
+    db: &mut TrieDB<F, H>,
+    message_passer_address: Address,
+    block_number: u64,
+) -> TrieDBResult<B256>
+where
+    F: TrieDBProvider,
+    H: TrieHinter,
+{
+    match db.storage_roots().get(&message_passer_address) {
+        Some(storage_root) => storage_root.blinded_commitment().ok_or(TrieDBError::RootNotBlinded),
+        None => Ok(db
+            .get_trie_account(&message_passer_address, block_number)?
+            .ok_or(TrieDBError::MissingAccountInfo)?
+            .storage_root),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::decode_holocene_eip_1559_params;
-    use crate::executor::util::{encode_canyon_base_fee_params, encode_holocene_eip_1559_params};
-    use alloy_consensus::Header;
-    use alloy_eips::eip1559::BaseFeeParams;
-    use alloy_primitives::{b64, hex, B64};
+    use crate::executor::util::{
+        compute_excess_blob_gas, compute_logs_bloom, deposit_context,
+        encode_canyon_base_fee_params, encode_holocene_eip_1559_params, is_block_gas_limit_exempt,
+        verify_logs_bloom,
+    };
+    use alloy_consensus::{Header, Sealed};
+    use alloy_eips::{eip1559::BaseFeeParams, eip4844::TARGET_DATA_GAS_PER_BLOCK};
+    use alloy_primitives::{b64, hex, Address, Bytes, Log, B256, B64};
     use alloy_rpc_types_engine::PayloadAttributes;
     use maili_genesis::RollupConfig;
+    use op_alloy_consensus::{OpReceiptEnvelope, OpTxEnvelope, OpTxType, TxDeposit};
     use op_alloy_rpc_types_engine::OpPayloadAttributes;
 
+    fn mock_deposit(is_system_transaction: bool) -> OpTxEnvelope {
+        let deposit =
+            TxDeposit { is_system_transaction, gas_limit: 1_000_000, ..Default::default() };
+        OpTxEnvelope::Deposit(Sealed::new_unchecked(deposit, B256::ZERO))
+    }
+
+    #[test]
+    fn test_system_deposit_gas_limit_exempt_pre_regolith() {
+        assert!(is_block_gas_limit_exempt(&mock_deposit(true), false));
+    }
+
+    #[test]
+    fn test_system_deposit_gas_limit_metered_post_regolith() {
+        assert!(!is_block_gas_limit_exempt(&mock_deposit(true), true));
+    }
+
+    #[test]
+    fn test_user_deposit_gas_limit_metered() {
+        assert!(!is_block_gas_limit_exempt(&mock_deposit(false), false));
+        assert!(!is_block_gas_limit_exempt(&mock_deposit(false), true));
+    }
+
     fn mock_payload(eip_1559_params: Option<B64>) -> OpPayloadAttributes {
         OpPayloadAttributes {
             payload_attributes: PayloadAttributes {
@@ -199,4 +394,92 @@ mod test {
         };
         assert_eq!(encode_canyon_base_fee_params(&cfg), b64!("0000002000000040"));
     }
+
+    #[test]
+    fn test_compute_excess_blob_gas_isthmus_boundary() {
+        let config =
+            RollupConfig { ecotone_time: Some(0), isthmus_time: Some(10), ..Default::default() };
+        let parent_excess_blob_gas = 2 * TARGET_DATA_GAS_PER_BLOCK;
+        let parent_blob_gas_used = TARGET_DATA_GAS_PER_BLOCK / 2;
+        let parent_header = |timestamp| Header {
+            timestamp,
+            excess_blob_gas: Some(parent_excess_blob_gas),
+            blob_gas_used: Some(parent_blob_gas_used),
+            ..Default::default()
+        };
+
+        // The first Isthmus block builds on a pre-Isthmus parent, so no target is subtracted.
+        assert_eq!(
+            compute_excess_blob_gas(&config, &parent_header(8)),
+            parent_excess_blob_gas + parent_blob_gas_used
+        );
+
+        // Once the parent is an Isthmus block, its target blob gas is subtracted.
+        assert_eq!(
+            compute_excess_blob_gas(&config, &parent_header(10)),
+            parent_excess_blob_gas + parent_blob_gas_used - TARGET_DATA_GAS_PER_BLOCK
+        );
+
+        // The excess blob gas saturates at zero.
+        let parent = Header { timestamp: 10, blob_gas_used: Some(1), ..Default::default() };
+        assert_eq!(compute_excess_blob_gas(&config, &parent), 0);
+    }
+
+    #[test]
+    fn test_compute_excess_blob_gas_first_ecotone_block() {
+        let config = RollupConfig { ecotone_time: Some(10), ..Default::default() };
+        let parent = Header {
+            timestamp: 8,
+            excess_blob_gas: Some(TARGET_DATA_GAS_PER_BLOCK),
+            blob_gas_used: Some(TARGET_DATA_GAS_PER_BLOCK),
+            ..Default::default()
+        };
+        assert_eq!(compute_excess_blob_gas(&config, &parent), 0);
+    }
+
+    #[test]
+    fn test_verify_logs_bloom() {
+        let log = |i: u8| {
+            Log::new_unchecked(
+                Address::repeat_byte(i),
+                alloc::vec![B256::repeat_byte(i)],
+                Bytes::default(),
+            )
+        };
+        let receipt = |logs: &[Log]| {
+            OpReceiptEnvelope::<Log>::from_parts(true, 21_000, logs, OpTxType::Eip1559, None, None)
+        };
+
+        let receipts = [receipt(&[log(1), log(2)]), receipt(&[]), receipt(&[log(3)])];
+        let header = Header { logs_bloom: compute_logs_bloom(&receipts), ..Default::default() };
+        assert!(verify_logs_bloom(&header, &receipts));
+
+        // Removing a log from one of the receipts invalidates the bloom.
+        let tampered = [receipt(&[log(1)]), receipt(&[]), receipt(&[log(3)])];
+        assert!(!verify_logs_bloom(&header, &tampered));
+    }
+
+    #[test]
+    fn test_deposit_context_system_deposit() {
+        let deposit = TxDeposit {
+            source_hash: B256::repeat_byte(0x01),
+            mint: Some(1),
+            is_system_transaction: true,
+            ..Default::default()
+        };
+        let tx = OpTxEnvelope::Deposit(Sealed::new_unchecked(deposit, B256::ZERO));
+
+        let fields = deposit_context(&tx, &[0x7E]);
+        assert_eq!(fields.source_hash, Some(B256::repeat_byte(0x01)));
+        assert_eq!(fields.mint, Some(1));
+        assert_eq!(fields.is_system_transaction, Some(true));
+        assert_eq!(fields.enveloped_tx, Some(Bytes::from_static(&[0x7E])));
+    }
+
+    #[test]
+    fn test_deposit_context_user_deposit() {
+        let fields = deposit_context(&mock_deposit(false), &[]);
+        assert_eq!(fields.source_hash, Some(B256::ZERO));
+        assert_eq!(fields.is_system_transaction, Some(false));
+    }
 }
@@ -0,0 +1,259 @@
+//! Computation of the L1 data fee of a payload's transactions.
+
+use crate::{ExecutorError, ExecutorResult};
+use alloy_eips::eip2718::Decodable2718;
+use alloy_primitives::U256;
+use maili_genesis::SystemConfig;
+use op_alloy_consensus::{OpTxEnvelope, DEPOSIT_TX_TYPE_ID};
+use op_alloy_rpc_types_engine::OpPayloadAttributes;
+use revm::{optimism::L1BlockInfo, primitives::SpecId};
+
+/// The selector of the Bedrock `setL1BlockValues` L1 info deposit.
+const L1_INFO_BEDROCK_SELECTOR: [u8; 4] = [0x01, 0x5d, 0x8e, 0xb9];
+
+/// The selector of the Ecotone `setL1BlockValuesEcotone` L1 info deposit.
+const L1_INFO_ECOTONE_SELECTOR: [u8; 4] = [0x44, 0x0a, 0x5e, 0x20];
+
+/// The selector of the Isthmus `setL1BlockValuesIsthmus` L1 info deposit.
+const L1_INFO_ISTHMUS_SELECTOR: [u8; 4] = [0x09, 0x89, 0x99, 0xbe];
+
+/// Computes the total L1 data fee incurred by the non-deposit transactions of a payload.
+///
+/// The L1 base fee and blob base fee are read from the L1 info deposit at the start of the
+/// payload, while the fee scalars are taken from the [SystemConfig]. The Bedrock, Ecotone, or
+/// Fjord fee formula is selected by the given [SpecId]. From Fjord onwards, the size of each
+/// transaction is estimated from the length of its FastLZ compression, and is at least 100 bytes.
+///
+/// ## Takes
+/// - `payload`: The payload, beginning with its L1 info deposit transaction.
+/// - `system_config`: The [SystemConfig] holding the L1 fee overhead and scalars.
+/// - `spec_id`: The [SpecId] active for the payload.
+///
+/// ## Returns
+/// - `Ok(cost)`: The sum of the L1 data fees of the payload's transactions, in wei.
+/// - `Err(_)`: The payload has no valid L1 info deposit, or the system config's scalar is of an
+///   unknown version.
+pub fn l1_data_cost(
+    payload: &OpPayloadAttributes,
+    system_config: &SystemConfig,
+    spec_id: SpecId,
+) -> ExecutorResult<U256> {
+    let transactions = payload.transactions.as_ref().ok_or(ExecutorError::MissingTransactions)?;
+
+    // Extract the L1 fees from the L1 info deposit at the start of the payload.
+    let l1_info_tx = transactions
+        .first()
+        .map(|tx| OpTxEnvelope::decode_2718(&mut tx.as_ref()))
+        .transpose()
+        .map_err(ExecutorError::RLPError)?;
+    let (l1_base_fee, l1_blob_base_fee, is_bedrock_info) = match l1_info_tx {
+        Some(OpTxEnvelope::Deposit(deposit)) => {
+            l1_info_fees(deposit.input.as_ref()).ok_or(ExecutorError::InvalidL1InfoTx)?
+        }
+        _ => return Err(ExecutorError::InvalidL1InfoTx),
+    };
+
+    // At the Ecotone activation block, the L1 info deposit is still in the Bedrock format, and the
+    // Bedrock fee formula remains in effect.
+    let bedrock_formula = !spec_id.is_enabled_in(SpecId::ECOTONE) ||
+        (is_bedrock_info && !spec_id.is_enabled_in(SpecId::FJORD));
+
+    // `L1BlockInfo` has crate-private fields, so it cannot be built with a struct literal.
+    let mut l1_block_info = L1BlockInfo::default();
+    l1_block_info.l1_base_fee = l1_base_fee;
+    l1_block_info.l1_fee_overhead = Some(system_config.overhead);
+    l1_block_info.l1_base_fee_scalar = system_config.scalar;
+    l1_block_info.l1_blob_base_fee = Some(l1_blob_base_fee);
+    if !bedrock_formula {
+        let (base_fee_scalar, blob_base_fee_scalar) = ecotone_scalars(system_config.scalar)?;
+        l1_block_info.l1_base_fee_scalar = U256::from(base_fee_scalar);
+        l1_block_info.l1_blob_base_fee_scalar = Some(U256::from(blob_base_fee_scalar));
+    }
+
+    Ok(transactions
+        .iter()
+        .filter(|tx| tx.first() != Some(&DEPOSIT_TX_TYPE_ID))
+        .map(|tx| {
+            if bedrock_formula {
+                // (data gas + overhead) * l1 base fee * scalar / 1e6
+                l1_block_info
+                    .data_gas(tx.as_ref(), spec_id)
+                    .saturating_add(system_config.overhead)
+                    .saturating_mul(l1_base_fee)
+                    .saturating_mul(system_config.scalar)
+                    .wrapping_div(U256::from(1_000_000))
+            } else {
+                l1_block_info.clear_tx_l1_cost();
+                l1_block_info.calculate_tx_l1_cost(tx.as_ref(), spec_id)
+            }
+        })
+        .fold(U256::ZERO, |acc, cost| acc.saturating_add(cost)))
+}
+
+/// Extracts the L1 base fee and L1 blob base fee from the calldata of an L1 info deposit, along
+/// with whether the deposit is in the Bedrock format, which carries no blob base fee.
+fn l1_info_fees(calldata: &[u8]) -> Option<(U256, U256, bool)> {
+    let selector: [u8; 4] = calldata.get(..4)?.try_into().ok()?;
+    let data = &calldata[4..];
+    match selector {
+        // Bedrock: 8 ABI-encoded words, with the base fee as the third.
+        L1_INFO_BEDROCK_SELECTOR => {
+            let base_fee = data.get(64..96)?;
+            Some((U256::from_be_slice(base_fee), U256::ZERO, true))
+        }
+        // Ecotone and later: packed scalars, sequence number, timestamp, and number, followed by
+        // the base fee and blob base fee.
+        L1_INFO_ECOTONE_SELECTOR | L1_INFO_ISTHMUS_SELECTOR => {
+            let base_fee = data.get(32..64)?;
+            let blob_base_fee = data.get(64..96)?;
+            Some((U256::from_be_slice(base_fee), U256::from_be_slice(blob_base_fee), false))
+        }
+        _ => None,
+    }
+}
+
+/// Decodes the base fee scalar and blob base fee scalar from the system config's `scalar`.
+///
+/// Version `0` scalars hold only the base fee scalar, while version `1` scalars pack the blob base
+/// fee scalar and base fee scalar into their last 8 bytes.
+fn ecotone_scalars(scalar: U256) -> ExecutorResult<(u32, u32)> {
+    let bytes = scalar.to_be_bytes::<32>();
+    let base_fee_scalar = u32::from_be_bytes(bytes[28..32].try_into().expect("4 bytes"));
+    let blob_base_fee_scalar = u32::from_be_bytes(bytes[24..28].try_into().expect("4 bytes"));
+    match bytes[0] {
+        0 => Ok((base_fee_scalar, 0)),
+        1 => Ok((base_fee_scalar, blob_base_fee_scalar)),
+        version => Err(ExecutorError::UnsupportedScalarVersion(version)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloy_consensus::Sealed;
+    use alloy_eips::eip2718::Encodable2718;
+    use alloy_primitives::{Bytes, B256};
+    use op_alloy_consensus::TxDeposit;
+
+    const L1_BASE_FEE: u64 = 1_000_000_000;
+    const L1_BLOB_BASE_FEE: u64 = 2;
+    const BASE_FEE_SCALAR: u32 = 1368;
+    const BLOB_BASE_FEE_SCALAR: u32 = 810949;
+
+    /// Constructs a payload with an Ecotone L1 info deposit, followed by two user transactions.
+    fn mock_payload() -> OpPayloadAttributes {
+        let mut calldata = L1_INFO_ECOTONE_SELECTOR.to_vec();
+        calldata.extend_from_slice(&BASE_FEE_SCALAR.to_be_bytes());
+        calldata.extend_from_slice(&BLOB_BASE_FEE_SCALAR.to_be_bytes());
+        calldata.extend_from_slice(&[0u8; 24]);
+        calldata.extend_from_slice(&U256::from(L1_BASE_FEE).to_be_bytes::<32>());
+        calldata.extend_from_slice(&U256::from(L1_BLOB_BASE_FEE).to_be_bytes::<32>());
+        calldata.extend_from_slice(&[0u8; 64]);
+
+        let l1_info = OpTxEnvelope::Deposit(Sealed::new_unchecked(
+            TxDeposit { input: calldata.into(), ..Default::default() },
+            B256::ZERO,
+        ));
+
+        // 3 non-zero and 2 zero bytes, followed by 6 non-zero and 10 zero bytes.
+        let tx_a = Bytes::from_static(&[0x02, 0x01, 0x00, 0x00, 0xFF]);
+        let mut tx_b = vec![0x02];
+        tx_b.extend_from_slice(&[0x00; 10]);
+        tx_b.extend_from_slice(&[0xAB; 5]);
+
+        OpPayloadAttributes {
+            transactions: Some(vec![l1_info.encoded_2718().into(), tx_a, tx_b.into()]),
+            ..Default::default()
+        }
+    }
+
+    fn mock_system_config() -> SystemConfig {
+        let mut scalar = [0u8; 32];
+        scalar[0] = 1;
+        scalar[24..28].copy_from_slice(&BLOB_BASE_FEE_SCALAR.to_be_bytes());
+        scalar[28..32].copy_from_slice(&BASE_FEE_SCALAR.to_be_bytes());
+        SystemConfig { scalar: U256::from_be_bytes(scalar), ..Default::default() }
+    }
+
+    #[test]
+    fn test_l1_data_cost_ecotone() {
+        // fee_scaled = 16 * 1368 * 1e9 + 810949 * 2 = 21888001621898
+        // cost = fee_scaled * (56 + 136 gas) / 16e6, rounded down per transaction.
+        let cost = l1_data_cost(&mock_payload(), &mock_system_config(), SpecId::ECOTONE).unwrap();
+        assert_eq!(cost, U256::from(262_656_018u64));
+    }
+
+    #[test]
+    fn test_l1_data_cost_fjord() {
+        // Both transactions are clamped to the minimum estimated size of 100 bytes.
+        // cost = 2 * (100e6 * fee_scaled / 1e12)
+        let cost = l1_data_cost(&mock_payload(), &mock_system_config(), SpecId::FJORD).unwrap();
+        assert_eq!(cost, U256::from(4_377_600_324u64));
+    }
+
+    #[test]
+    fn test_l1_data_cost_fjord_fastlz() {
+        // Deterministic, incompressible bytes from a 64-bit LCG.
+        let noise = |len: usize| {
+            let mut state = 1u64;
+            (0..len)
+                .map(|_| {
+                    state =
+                        state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                    (state >> 56) as u8
+                })
+                .collect::<Vec<_>>()
+        };
+        let cost_of = |tx: Vec<u8>| {
+            let mut payload = mock_payload();
+            payload.transactions.as_mut().unwrap().truncate(1);
+            payload.transactions.as_mut().unwrap().push(tx.into());
+            l1_data_cost(&payload, &mock_system_config(), SpecId::FJORD).unwrap()
+        };
+
+        // estimated_size = max(100e6, 836500 * fastlz_size - 42585600)
+        // cost = estimated_size * fee_scaled / 1e12
+        //
+        // 513 incompressible bytes: fastlz_size = 530, estimated_size = 400759400.
+        let tx = [[0x02].as_slice(), noise(512).as_slice()].concat();
+        assert_eq!(cost_of(tx), U256::from(8_771_822_397u64));
+
+        // 1025 bytes, repeating a 256 byte pattern: fastlz_size = 282,
+        // estimated_size = 193307400.
+        let tx = [[0x02].as_slice(), noise(256).repeat(4).as_slice()].concat();
+        assert_eq!(cost_of(tx), U256::from(4_231_112_684u64));
+
+        // 2001 mostly zero bytes: fastlz_size = 34, clamped to the minimum estimated size.
+        let tx = [[0x02].as_slice(), &[0u8; 2000]].concat();
+        assert_eq!(cost_of(tx), U256::from(2_188_800_162u64));
+    }
+
+    #[test]
+    fn test_l1_data_cost_missing_l1_info() {
+        let payload =
+            OpPayloadAttributes { transactions: Some(vec![Bytes::new()]), ..Default::default() };
+        assert!(l1_data_cost(&payload, &mock_system_config(), SpecId::ECOTONE).is_err());
+
+        let payload = OpPayloadAttributes { transactions: Some(vec![]), ..Default::default() };
+        assert!(matches!(
+            l1_data_cost(&payload, &mock_system_config(), SpecId::ECOTONE),
+            Err(ExecutorError::InvalidL1InfoTx)
+        ));
+    }
+
+    #[test]
+    fn test_ecotone_scalars() {
+        assert_eq!(
+            ecotone_scalars(mock_system_config().scalar).unwrap(),
+            (BASE_FEE_SCALAR, BLOB_BASE_FEE_SCALAR)
+        );
+        assert_eq!(ecotone_scalars(U256::from(684_000)).unwrap(), (684_000, 0));
+
+        let mut scalar = [0u8; 32];
+        scalar[0] = 2;
+        assert!(matches!(
+            ecotone_scalars(U256::from_be_bytes(scalar)),
+            Err(ExecutorError::UnsupportedScalarVersion(2))
+        ));
+    }
+}
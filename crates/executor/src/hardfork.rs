@@ -0,0 +1,126 @@
+//! Detection of the OP Stack hardfork active at a given timestamp.
+
+use maili_genesis::RollupConfig;
+
+/// An OP Stack hardfork, in activation order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Hardfork {
+    /// The Bedrock network upgrade, active from genesis.
+    Bedrock,
+    /// The Regolith hardfork.
+    Regolith,
+    /// The Canyon hardfork.
+    Canyon,
+    /// The Delta hardfork.
+    Delta,
+    /// The Ecotone hardfork.
+    Ecotone,
+    /// The Fjord hardfork.
+    Fjord,
+    /// The Granite hardfork.
+    Granite,
+    /// The Holocene hardfork.
+    Holocene,
+    /// The Isthmus hardfork.
+    Isthmus,
+    /// The Interop hardfork.
+    Interop,
+}
+
+impl core::fmt::Display for Hardfork {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let name = match self {
+            Self::Bedrock => "Bedrock",
+            Self::Regolith => "Regolith",
+            Self::Canyon => "Canyon",
+            Self::Delta => "Delta",
+            Self::Ecotone => "Ecotone",
+            Self::Fjord => "Fjord",
+            Self::Granite => "Granite",
+            Self::Holocene => "Holocene",
+            Self::Isthmus => "Isthmus",
+            Self::Interop => "Interop",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Returns the latest [Hardfork] active at the given timestamp under the [RollupConfig].
+///
+/// ## Takes
+/// - `config`: The [RollupConfig] holding the hardfork activation times.
+/// - `timestamp`: The timestamp of the block.
+///
+/// ## Returns
+/// The latest active [Hardfork], or [Hardfork::Bedrock] if no later hardfork is active.
+pub fn active_fork(config: &RollupConfig, timestamp: u64) -> Hardfork {
+    if config.is_interop_active(timestamp) {
+        Hardfork::Interop
+    } else if config.is_isthmus_active(timestamp) {
+        Hardfork::Isthmus
+    } else if config.is_holocene_active(timestamp) {
+        Hardfork::Holocene
+    } else if config.is_granite_active(timestamp) {
+        Hardfork::Granite
+    } else if config.is_fjord_active(timestamp) {
+        Hardfork::Fjord
+    } else if config.is_ecotone_active(timestamp) {
+        Hardfork::Ecotone
+    } else if config.is_delta_active(timestamp) {
+        Hardfork::Delta
+    } else if config.is_canyon_active(timestamp) {
+        Hardfork::Canyon
+    } else if config.is_regolith_active(timestamp) {
+        Hardfork::Regolith
+    } else {
+        Hardfork::Bedrock
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_active_fork_at_boundaries() {
+        let config = RollupConfig {
+            regolith_time: Some(10),
+            canyon_time: Some(20),
+            delta_time: Some(30),
+            ecotone_time: Some(40),
+            fjord_time: Some(50),
+            granite_time: Some(60),
+            holocene_time: Some(70),
+            isthmus_time: Some(80),
+            interop_time: Some(90),
+            ..Default::default()
+        };
+
+        assert_eq!(active_fork(&config, 0), Hardfork::Bedrock);
+        let forks = [
+            (10, Hardfork::Bedrock, Hardfork::Regolith),
+            (20, Hardfork::Regolith, Hardfork::Canyon),
+            (30, Hardfork::Canyon, Hardfork::Delta),
+            (40, Hardfork::Delta, Hardfork::Ecotone),
+            (50, Hardfork::Ecotone, Hardfork::Fjord),
+            (60, Hardfork::Fjord, Hardfork::Granite),
+            (70, Hardfork::Granite, Hardfork::Holocene),
+            (80, Hardfork::Holocene, Hardfork::Isthmus),
+            (90, Hardfork::Isthmus, Hardfork::Interop),
+        ];
+        for (activation, before, after) in forks {
+            assert_eq!(active_fork(&config, activation - 1), before);
+            assert_eq!(active_fork(&config, activation), after);
+        }
+        assert_eq!(active_fork(&config, u64::MAX), Hardfork::Interop);
+    }
+
+    #[test]
+    fn test_active_fork_unscheduled() {
+        assert_eq!(active_fork(&RollupConfig::default(), u64::MAX), Hardfork::Bedrock);
+
+        let config = RollupConfig { ecotone_time: Some(0), ..Default::default() };
+        assert_eq!(active_fork(&config, 0), Hardfork::Ecotone);
+        assert_eq!(Hardfork::Ecotone.to_string(), "Ecotone");
+    }
+}
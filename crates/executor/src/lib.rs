@@ -17,12 +17,20 @@ pub use errors::{ExecutorError, ExecutorResult, TrieDBError, TrieDBResult};
 
 mod executor;
 pub use executor::{
-    ExecutionArtifacts, KonaHandleRegister, StatelessL2BlockExecutor,
-    StatelessL2BlockExecutorBuilder,
+    compute_receipts_root_from_results, gas_used_from_receipts, verify_logs_bloom,
+    withdrawals_root_from_state, ExecutionArtifacts, KonaHandleRegister, KonaInspector,
+    OutputRootComponent, OutputRootComponents, StatelessL2BlockExecutor,
+    StatelessL2BlockExecutorBuilder, TxExecutionResult, TxLogVerbosity,
 };
 
+mod fees;
+pub use fees::l1_data_cost;
+
+mod hardfork;
+pub use hardfork::{active_fork, Hardfork};
+
 mod db;
-pub use db::{NoopTrieDBProvider, TrieDB, TrieDBProvider};
+pub use db::{NoopTrieDBProvider, RetryingTrieDBProvider, TrieDB, TrieDBProvider, TrieDBStats};
 
 mod constants;
 mod syscalls;
@@ -7,7 +7,7 @@ use crate::{
     TrieDBProvider,
 };
 use alloc::boxed::Box;
-use alloy_primitives::B256;
+use alloy_primitives::{Address, B256};
 use kona_mpt::TrieHinter;
 use maili_genesis::RollupConfig;
 use op_alloy_rpc_types_engine::OpPayloadAttributes;
@@ -17,10 +17,12 @@ use revm::{
     DatabaseCommit, Evm,
 };
 
-/// Execute the EIP-4788 pre-block beacon root contract call.
+/// Execute the EIP-4788 pre-block beacon root contract call against the beacon roots contract at
+/// `beacon_roots_address`.
 pub(crate) fn pre_block_beacon_root_contract_call<F, H>(
     db: &mut State<&mut TrieDB<F, H>>,
     config: &RollupConfig,
+    beacon_roots_address: Address,
     block_number: u64,
     initialized_cfg: &CfgEnvWithHandlerCfg,
     initialized_block_env: &BlockEnv,
@@ -43,6 +45,7 @@ where
     // initialize a block from the env, because the pre block call needs the block itself
     apply_beacon_root_contract_call(
         config,
+        beacon_roots_address,
         payload.payload_attributes.timestamp,
         block_number,
         payload.payload_attributes.parent_beacon_block_root,
@@ -53,6 +56,7 @@ where
 /// Apply the EIP-4788 pre-block beacon root contract call to a given EVM instance.
 fn apply_beacon_root_contract_call<F, H>(
     config: &RollupConfig,
+    beacon_roots_address: Address,
     timestamp: u64,
     block_number: u64,
     parent_beacon_block_root: Option<B256>,
@@ -85,7 +89,7 @@ where
     fill_tx_env_for_contract_call(
         &mut evm.context.evm.env,
         alloy_eips::eip4788::SYSTEM_ADDRESS,
-        alloy_eips::eip4788::BEACON_ROOTS_ADDRESS,
+        beacon_roots_address,
         parent_beacon_block_root.0.into(),
     );
 
@@ -107,3 +111,87 @@ where
 
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::NoopTrieDBProvider;
+    use alloy_consensus::{Header, Sealable};
+    use alloy_eips::eip4788::{BEACON_ROOTS_ADDRESS, BEACON_ROOTS_CODE};
+    use alloy_primitives::{address, keccak256, U256};
+    use alloy_rpc_types_engine::PayloadAttributes;
+    use kona_mpt::NoopTrieHinter;
+    use revm::{
+        primitives::{AccountInfo, Bytecode, CfgEnv, SpecId},
+        Database,
+    };
+
+    /// The length of the beacon roots contract's ring buffer.
+    const HISTORY_BUFFER_LENGTH: u64 = 8191;
+
+    #[test]
+    fn test_beacon_root_contract_call_custom_address() {
+        const TIMESTAMP: u64 = 1_000;
+        let custom_address = address!("1111111111111111111111111111111111111111");
+        let parent_beacon_block_root = B256::repeat_byte(0xAA);
+
+        let config = RollupConfig { ecotone_time: Some(0), ..Default::default() };
+        let mut trie_db = TrieDB::new(
+            B256::ZERO,
+            Header::default().seal_slow(),
+            NoopTrieDBProvider,
+            NoopTrieHinter,
+        );
+        let mut state = State::builder().with_database(&mut trie_db).with_bundle_update().build();
+
+        // Deploy the beacon roots contract at the relocated address.
+        let code = Bytecode::new_raw(BEACON_ROOTS_CODE.clone());
+        state.insert_account(
+            custom_address,
+            AccountInfo {
+                code_hash: keccak256(BEACON_ROOTS_CODE.as_ref()),
+                code: Some(code),
+                ..Default::default()
+            },
+        );
+
+        let mut cfg = CfgEnvWithHandlerCfg::new_with_spec_id(CfgEnv::default(), SpecId::ECOTONE);
+        cfg.enable_optimism();
+        let block_env = BlockEnv {
+            number: U256::from(1),
+            timestamp: U256::from(TIMESTAMP),
+            ..Default::default()
+        };
+        let payload = OpPayloadAttributes {
+            payload_attributes: PayloadAttributes {
+                timestamp: TIMESTAMP,
+                parent_beacon_block_root: Some(parent_beacon_block_root),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        pre_block_beacon_root_contract_call(
+            &mut state,
+            &config,
+            custom_address,
+            1,
+            &cfg,
+            &block_env,
+            &payload,
+        )
+        .unwrap();
+
+        // The timestamp and root are stored in the ring buffer of the relocated contract.
+        let timestamp_slot = U256::from(TIMESTAMP % HISTORY_BUFFER_LENGTH);
+        let root_slot = timestamp_slot + U256::from(HISTORY_BUFFER_LENGTH);
+        assert_eq!(state.storage(custom_address, timestamp_slot).unwrap(), U256::from(TIMESTAMP));
+        assert_eq!(
+            state.storage(custom_address, root_slot).unwrap(),
+            U256::from_be_bytes(parent_beacon_block_root.0)
+        );
+
+        // The canonical beacon roots contract is never touched.
+        assert!(!state.cache.accounts.contains_key(&BEACON_ROOTS_ADDRESS));
+    }
+}
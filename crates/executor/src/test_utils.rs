@@ -2,24 +2,33 @@
 
 #![allow(missing_docs, unused)]
 
-use crate::{constants::FEE_RECIPIENT, StatelessL2BlockExecutor, TrieDBProvider};
-use alloy_consensus::Header;
-use alloy_primitives::{Bytes, Sealable, B256};
+use crate::{
+    constants::FEE_RECIPIENT, NoopTrieDBProvider, StatelessL2BlockExecutor, TrieDBProvider,
+};
+use alloy_consensus::{Header, Sealed};
+use alloy_eips::eip2718::Encodable2718;
+use alloy_primitives::{keccak256, map::HashMap, Address, Bytes, Sealable, TxKind, B256, U256};
 use alloy_provider::{
     network::primitives::{BlockTransactions, BlockTransactionsKind},
     Provider, RootProvider,
 };
-use alloy_rlp::Decodable;
+use alloy_rlp::{Decodable, Encodable};
 use alloy_rpc_client::RpcClient;
 use alloy_rpc_types_engine::PayloadAttributes;
 use alloy_transport_http::{Client, Http};
 use kona_host::{DiskKeyValueStore, KeyValueStore};
-use kona_mpt::{NoopTrieHinter, TrieNode, TrieProvider};
+use kona_mpt::{Nibbles, NoopTrieHinter, TrieNode, TrieProvider};
 use maili_genesis::RollupConfig;
 use maili_registry::ROLLUP_CONFIGS;
+use op_alloy_consensus::{OpTxEnvelope, TxDeposit};
 use op_alloy_rpc_types_engine::OpPayloadAttributes;
+use revm::{
+    db::{states::StorageSlot, AccountStatus, BundleAccount, BundleState},
+    primitives::{AccountInfo, Bytecode},
+};
 use serde::{Deserialize, Serialize};
-use std::{env::temp_dir, path::PathBuf, sync::Arc};
+use std::{collections::BTreeSet, env::temp_dir, path::PathBuf, sync::Arc};
+use tempfile::TempDir;
 use tokio::{fs, runtime::Handle, sync::Mutex};
 
 #[derive(Debug, thiserror::Error)]
@@ -32,6 +41,82 @@ pub(crate) enum TestTrieNodeProviderError {
     KVStore,
 }
 
+/// A [TrieDBProvider] serving trie node preimages from an in-memory map.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct MapTrieDBProvider(pub(crate) HashMap<B256, Vec<u8>>);
+
+impl MapTrieDBProvider {
+    /// Stores the preimages of all nodes within the open trie.
+    pub(crate) fn insert_trie(&mut self, node: &TrieNode) {
+        match node {
+            TrieNode::Branch { stack } => stack.iter().for_each(|n| self.insert_trie(n)),
+            TrieNode::Extension { node, .. } => self.insert_trie(node),
+            _ => {}
+        }
+
+        let mut buf = Vec::with_capacity(node.length());
+        node.encode(&mut buf);
+        if buf.len() >= B256::ZERO.len() {
+            self.0.insert(keccak256(&buf), buf);
+        }
+    }
+}
+
+impl TrieProvider for MapTrieDBProvider {
+    type Error = String;
+
+    fn trie_node_by_hash(&self, key: B256) -> Result<TrieNode, Self::Error> {
+        let preimage = self.0.get(&key).ok_or_else(|| format!("Missing trie node {key}"))?;
+        TrieNode::decode(&mut preimage.as_slice()).map_err(|e| e.to_string())
+    }
+}
+
+impl TrieDBProvider for MapTrieDBProvider {
+    fn bytecode_by_hash(&self, _code_hash: B256) -> Result<Bytes, Self::Error> {
+        Ok(Bytes::default())
+    }
+
+    fn header_by_hash(&self, _hash: B256) -> Result<Header, Self::Error> {
+        Ok(Header::default())
+    }
+}
+
+/// A [TrieHinter] recording the addresses of the hinted account proofs, in order.
+///
+/// [TrieHinter]: kona_mpt::TrieHinter
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RecordingTrieHinter(pub(crate) Arc<std::sync::Mutex<Vec<Address>>>);
+
+impl kona_mpt::TrieHinter for RecordingTrieHinter {
+    type Error = String;
+
+    fn hint_trie_node(&self, _hash: B256) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn hint_account_proof(&self, address: Address, _block_number: u64) -> Result<(), Self::Error> {
+        self.0.lock().unwrap().push(address);
+        Ok(())
+    }
+
+    fn hint_storage_proof(
+        &self,
+        _address: Address,
+        _slot: U256,
+        _block_number: u64,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn hint_ancestor_headers(
+        &self,
+        _head_hash: B256,
+        _block_number: u64,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub(crate) struct ExecutorTestFixture {
     /// The rollup configuration for the executing chain.
@@ -268,7 +353,7 @@ impl TrieDBProvider for ExecutorTestFixtureCreator {
     }
 }
 
-struct DiskTrieNodeProvider {
+pub(crate) struct DiskTrieNodeProvider {
     kv_store: DiskKeyValueStore,
 }
 
@@ -313,9 +398,108 @@ impl TrieDBProvider for DiskTrieNodeProvider {
     }
 }
 
+/// A [TrieDBProvider] serving the header and transactions trie of a single block on top of a
+/// [DiskTrieNodeProvider].
+pub(crate) struct BlockTrieNodeProvider {
+    inner: DiskTrieNodeProvider,
+    header: Sealed<Header>,
+    transactions: MapTrieDBProvider,
+}
+
+impl BlockTrieNodeProvider {
+    pub(crate) fn new(
+        inner: DiskTrieNodeProvider,
+        header: Sealed<Header>,
+        transactions: &[Bytes],
+    ) -> Self {
+        let mut trie = TrieNode::Empty;
+        for (index, transaction) in transactions.iter().enumerate() {
+            let mut key = Vec::new();
+            index.encode(&mut key);
+            trie.insert(&Nibbles::unpack(key), transaction.clone(), &NoopTrieDBProvider).unwrap();
+        }
+        let mut provider = MapTrieDBProvider::default();
+        provider.insert_trie(&trie);
+        trie.blind();
+        assert_eq!(trie.blinded_commitment(), Some(header.transactions_root));
+
+        Self { inner, header, transactions: provider }
+    }
+}
+
+impl TrieProvider for BlockTrieNodeProvider {
+    type Error = TestTrieNodeProviderError;
+
+    fn trie_node_by_hash(&self, key: B256) -> Result<TrieNode, Self::Error> {
+        self.transactions.trie_node_by_hash(key).or_else(|_| self.inner.trie_node_by_hash(key))
+    }
+}
+
+impl TrieDBProvider for BlockTrieNodeProvider {
+    fn bytecode_by_hash(&self, code_hash: B256) -> Result<Bytes, Self::Error> {
+        self.inner.bytecode_by_hash(code_hash)
+    }
+
+    fn header_by_hash(&self, hash: B256) -> Result<Header, Self::Error> {
+        if hash == self.header.seal() {
+            return Ok(self.header.inner().clone());
+        }
+        self.inner.header_by_hash(hash)
+    }
+}
+
+/// Returns an empty parent header with a 30M gas limit, on top of which synthetic payloads are
+/// executed.
+pub(crate) fn empty_parent_header() -> Sealed<Header> {
+    Header { gas_limit: 30_000_000, ..Default::default() }.seal_slow()
+}
+
+/// Returns an encoded deposit transaction that creates an empty contract, which executes against
+/// an empty state.
+pub(crate) fn create_deposit() -> Bytes {
+    OpTxEnvelope::Deposit(Sealed::new_unchecked(
+        TxDeposit { gas_limit: 100_000, to: TxKind::Create, ..Default::default() },
+        B256::ZERO,
+    ))
+    .encoded_2718()
+    .into()
+}
+
+/// Returns the attributes of a payload at timestamp `2` holding the given transactions, built on
+/// top of [empty_parent_header].
+pub(crate) fn synthetic_payload(transactions: Vec<Bytes>) -> OpPayloadAttributes {
+    OpPayloadAttributes {
+        payload_attributes: PayloadAttributes { timestamp: 2, ..Default::default() },
+        gas_limit: Some(30_000_000),
+        transactions: Some(transactions),
+        ..Default::default()
+    }
+}
+
 /// Executes a [ExecutorTestFixture] stored at the passed `fixture_path` and asserts that the
 /// produced block hash matches the expected block hash.
 pub(crate) async fn run_test_fixture(fixture_path: PathBuf) {
+    let (_fixture_dir, fixture, provider) = load_test_fixture(fixture_path).await;
+
+    let mut executor =
+        StatelessL2BlockExecutor::builder(&fixture.rollup_config, provider, NoopTrieHinter)
+            .with_parent_header(fixture.parent_header.seal_slow())
+            .build();
+
+    let exec_artifacts = executor.execute_payload(fixture.executing_payload).unwrap();
+
+    assert_eq!(
+        exec_artifacts.block_header.hash(),
+        fixture.expected_block_hash,
+        "Produced header does not match the expected header"
+    );
+}
+
+/// Untars the executor test fixture at the given path, returning the temporary directory holding
+/// its contents along with the fixture and a [DiskTrieNodeProvider] over its key-value store.
+pub(crate) async fn load_test_fixture(
+    fixture_path: PathBuf,
+) -> (TempDir, ExecutorTestFixture, DiskTrieNodeProvider) {
     // First, untar the fixture.
     let mut fixture_dir = tempfile::tempdir().expect("Failed to create temporary directory");
     let untar = tokio::process::Command::new("tar")
@@ -334,16 +518,306 @@ pub(crate) async fn run_test_fixture(fixture_path: PathBuf) {
         serde_json::from_slice(&fs::read(fixture_dir.path().join("fixture.json")).await.unwrap())
             .expect("Failed to deserialize fixture");
 
-    let mut executor =
-        StatelessL2BlockExecutor::builder(&fixture.rollup_config, provider, NoopTrieHinter)
-            .with_parent_header(fixture.parent_header.seal_slow())
-            .build();
+    (fixture_dir, fixture, provider)
+}
 
-    let exec_artifacts = executor.execute_payload(fixture.executing_payload).unwrap();
+/// A divergent account between two [BundleState]s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct AccountDiff {
+    /// The address of the account.
+    pub(crate) address: Address,
+    /// The account info in the first bundle.
+    pub(crate) a: Option<AccountInfo>,
+    /// The account info in the second bundle.
+    pub(crate) b: Option<AccountInfo>,
+}
 
-    assert_eq!(
-        exec_artifacts.block_header.hash(),
-        fixture.expected_block_hash,
-        "Produced header does not match the expected header"
-    );
+/// A divergent storage slot between two [BundleState]s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct StorageSlotDiff {
+    /// The address of the account that owns the slot.
+    pub(crate) address: Address,
+    /// The index of the storage slot.
+    pub(crate) slot: U256,
+    /// The present value of the slot in the first bundle.
+    pub(crate) a: Option<U256>,
+    /// The present value of the slot in the second bundle.
+    pub(crate) b: Option<U256>,
+}
+
+/// The difference between two [BundleState]s, as produced by [diff_bundles].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct BundleDiff {
+    /// Accounts that differ in balance, nonce, or code hash.
+    pub(crate) accounts: Vec<AccountDiff>,
+    /// Storage slots that differ in their present value.
+    pub(crate) storage: Vec<StorageSlotDiff>,
+}
+
+impl BundleDiff {
+    /// Returns `true` if the two bundles are equivalent.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.accounts.is_empty() && self.storage.is_empty()
+    }
+}
+
+/// Diffs two [BundleState]s, reporting the accounts whose balance, nonce, or code hash diverge as
+/// well as the storage slots whose present values diverge. Results are sorted by address and slot.
+pub(crate) fn diff_bundles(a: &BundleState, b: &BundleState) -> BundleDiff {
+    let mut diff = BundleDiff::default();
+
+    let addresses = a.state().keys().chain(b.state().keys()).copied().collect::<BTreeSet<_>>();
+    for address in addresses {
+        let (acc_a, acc_b) = (a.account(&address), b.account(&address));
+
+        let info_a = acc_a.and_then(|acc| acc.account_info());
+        let info_b = acc_b.and_then(|acc| acc.account_info());
+        let info_matches = match (&info_a, &info_b) {
+            (Some(x), Some(y)) => {
+                x.balance == y.balance && x.nonce == y.nonce && x.code_hash == y.code_hash
+            }
+            (None, None) => true,
+            _ => false,
+        };
+        if !info_matches {
+            diff.accounts.push(AccountDiff { address, a: info_a, b: info_b });
+        }
+
+        let slots = acc_a
+            .into_iter()
+            .chain(acc_b)
+            .flat_map(|acc| acc.storage.keys())
+            .copied()
+            .collect::<BTreeSet<_>>();
+        for slot in slots {
+            let value_a = acc_a.and_then(|acc| acc.storage_slot(slot));
+            let value_b = acc_b.and_then(|acc| acc.storage_slot(slot));
+            if value_a != value_b {
+                diff.storage.push(StorageSlotDiff { address, slot, a: value_a, b: value_b });
+            }
+        }
+    }
+
+    diff
+}
+
+/// The [AccountStatus]es, indexed by their tag in the compact bundle encoding.
+const ACCOUNT_STATUSES: [AccountStatus; 8] = [
+    AccountStatus::LoadedNotExisting,
+    AccountStatus::Loaded,
+    AccountStatus::LoadedEmptyEIP161,
+    AccountStatus::InMemoryChange,
+    AccountStatus::Changed,
+    AccountStatus::Destroyed,
+    AccountStatus::DestroyedChanged,
+    AccountStatus::DestroyedAgain,
+];
+
+/// Serializes the accounts, storage, and contracts of a [BundleState] into a compact binary
+/// format, for snapshotting a post-state to disk. Reverts are not retained.
+///
+/// **FORMAT:**
+/// ```text
+/// bundle   = u32(len) .. account* .. u32(len) .. contract*
+/// account  = address .. u8(status) .. info? .. info? .. u32(len) .. (slot .. original .. present)*
+/// info?    = 0x00 | 0x01 .. balance .. u64(nonce) .. code_hash
+/// contract = code_hash .. u32(len) .. code
+/// ```
+///
+/// Integers are big-endian. Account code is stored once in the contracts section, so the decoded
+/// [AccountInfo]s carry no code.
+pub(crate) fn serialize_bundle(bundle: &BundleState) -> Vec<u8> {
+    fn put_info(buf: &mut Vec<u8>, info: Option<&AccountInfo>) {
+        let Some(info) = info else {
+            buf.push(0);
+            return;
+        };
+        buf.push(1);
+        buf.extend_from_slice(&info.balance.to_be_bytes::<32>());
+        buf.extend_from_slice(&info.nonce.to_be_bytes());
+        buf.extend_from_slice(info.code_hash.as_slice());
+    }
+
+    // Sort accounts, slots, and contracts so that equal bundles serialize identically.
+    let mut buf = Vec::new();
+    let accounts = bundle.state().iter().collect::<std::collections::BTreeMap<_, _>>();
+    buf.extend_from_slice(&(accounts.len() as u32).to_be_bytes());
+    for (address, account) in accounts {
+        let status = ACCOUNT_STATUSES.iter().position(|s| *s == account.status).unwrap_or_default();
+        buf.extend_from_slice(address.as_slice());
+        buf.push(status as u8);
+        put_info(&mut buf, account.info.as_ref());
+        put_info(&mut buf, account.original_info.as_ref());
+
+        let storage = account.storage.iter().collect::<std::collections::BTreeMap<_, _>>();
+        buf.extend_from_slice(&(storage.len() as u32).to_be_bytes());
+        for (slot, value) in storage {
+            buf.extend_from_slice(&slot.to_be_bytes::<32>());
+            buf.extend_from_slice(&value.previous_or_original_value.to_be_bytes::<32>());
+            buf.extend_from_slice(&value.present_value.to_be_bytes::<32>());
+        }
+    }
+
+    let contracts = bundle.contracts.iter().collect::<std::collections::BTreeMap<_, _>>();
+    buf.extend_from_slice(&(contracts.len() as u32).to_be_bytes());
+    for (code_hash, bytecode) in contracts {
+        let code = bytecode.original_bytes();
+        buf.extend_from_slice(code_hash.as_slice());
+        buf.extend_from_slice(&(code.len() as u32).to_be_bytes());
+        buf.extend_from_slice(&code);
+    }
+    buf
+}
+
+/// Deserializes a [BundleState] produced by [serialize_bundle].
+///
+/// Returns `None` if the data is malformed or has trailing bytes.
+pub(crate) fn deserialize_bundle(mut data: &[u8]) -> Option<BundleState> {
+    fn take<'a>(data: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+        let (head, tail) = data.split_at_checked(len)?;
+        *data = tail;
+        Some(head)
+    }
+    fn take_u32(data: &mut &[u8]) -> Option<u32> {
+        take(data, 4).map(|b| u32::from_be_bytes(b.try_into().unwrap()))
+    }
+    fn take_u256(data: &mut &[u8]) -> Option<U256> {
+        take(data, 32).map(U256::from_be_slice)
+    }
+    fn take_info(data: &mut &[u8]) -> Option<Option<AccountInfo>> {
+        match take(data, 1)?[0] {
+            0 => Some(None),
+            1 => Some(Some(AccountInfo {
+                balance: take_u256(data)?,
+                nonce: u64::from_be_bytes(take(data, 8)?.try_into().unwrap()),
+                code_hash: B256::from_slice(take(data, 32)?),
+                code: None,
+            })),
+            _ => None,
+        }
+    }
+
+    let mut bundle = BundleState::default();
+    for _ in 0..take_u32(&mut data)? {
+        let address = Address::from_slice(take(&mut data, 20)?);
+        let status = *ACCOUNT_STATUSES.get(take(&mut data, 1)?[0] as usize)?;
+        let info = take_info(&mut data)?;
+        let original_info = take_info(&mut data)?;
+
+        let mut storage = HashMap::default();
+        for _ in 0..take_u32(&mut data)? {
+            let slot = take_u256(&mut data)?;
+            let original = take_u256(&mut data)?;
+            let present = take_u256(&mut data)?;
+            storage.insert(slot, StorageSlot::new_changed(original, present));
+        }
+        bundle.state.insert(address, BundleAccount::new(original_info, info, storage, status));
+    }
+
+    for _ in 0..take_u32(&mut data)? {
+        let code_hash = B256::from_slice(take(&mut data, 32)?);
+        let len = take_u32(&mut data)? as usize;
+        let code = Bytes::copy_from_slice(take(&mut data, len)?);
+        bundle.contracts.insert(code_hash, Bytecode::new_raw(code));
+    }
+
+    data.is_empty().then_some(bundle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::TrieDB;
+    use alloy_primitives::address;
+    use alloy_trie::EMPTY_ROOT_HASH;
+
+    fn mock_bundle(slot_value: U256) -> BundleState {
+        let address = address!("4200000000000000000000000000000000000016");
+        let info = AccountInfo { balance: U256::from(1), nonce: 1, ..Default::default() };
+        let storage = [
+            (U256::from(1), StorageSlot::new_changed(U256::ZERO, U256::from(0xFF))),
+            (U256::from(2), StorageSlot::new_changed(U256::ZERO, slot_value)),
+        ]
+        .into_iter()
+        .collect();
+        let account = BundleAccount::new(None, Some(info), storage, AccountStatus::Changed);
+
+        BundleState { state: [(address, account)].into_iter().collect(), ..Default::default() }
+    }
+
+    #[test]
+    fn test_diff_bundles_identical() {
+        let bundle = mock_bundle(U256::from(2));
+        assert!(diff_bundles(&bundle, &bundle).is_empty());
+    }
+
+    #[test]
+    fn test_diff_bundles_storage_slot() {
+        let a = mock_bundle(U256::from(2));
+        let b = mock_bundle(U256::from(3));
+
+        let diff = diff_bundles(&a, &b);
+        assert!(diff.accounts.is_empty());
+        assert_eq!(
+            diff.storage,
+            vec![StorageSlotDiff {
+                address: address!("4200000000000000000000000000000000000016"),
+                slot: U256::from(2),
+                a: Some(U256::from(2)),
+                b: Some(U256::from(3)),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_bundle_serialization_roundtrip() {
+        let mut bundle = mock_bundle(U256::from(2));
+        let code = Bytes::from_static(&[0x60, 0x00, 0x60, 0x00, 0xF3]);
+        let code_hash = keccak256(&code);
+        let info = AccountInfo { nonce: 1, code_hash, ..Default::default() };
+        bundle.state.insert(
+            Address::repeat_byte(0x01),
+            BundleAccount::new(None, Some(info), Default::default(), AccountStatus::InMemoryChange),
+        );
+        bundle.state.insert(
+            Address::repeat_byte(0x02),
+            BundleAccount::new(
+                Some(AccountInfo::default()),
+                None,
+                Default::default(),
+                AccountStatus::Loaded,
+            ),
+        );
+        bundle.contracts.insert(code_hash, Bytecode::new_raw(code));
+
+        // Round-trip the snapshot through disk.
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("bundle.bin");
+        std::fs::write(&path, serialize_bundle(&bundle)).unwrap();
+        let reloaded = deserialize_bundle(&std::fs::read(&path).unwrap()).unwrap();
+
+        assert!(diff_bundles(&bundle, &reloaded).is_empty());
+        assert_eq!(serialize_bundle(&reloaded), serialize_bundle(&bundle));
+        assert_eq!(
+            reloaded.contracts[&code_hash].original_bytes(),
+            bundle.contracts[&code_hash].original_bytes()
+        );
+
+        let state_root = |bundle: &BundleState| {
+            TrieDB::new(
+                EMPTY_ROOT_HASH,
+                Header::default().seal_slow(),
+                NoopTrieDBProvider,
+                NoopTrieHinter,
+            )
+            .state_root(bundle)
+            .unwrap()
+        };
+        assert_eq!(state_root(&reloaded), state_root(&bundle));
+
+        // Truncated and padded snapshots are rejected.
+        let encoded = serialize_bundle(&bundle);
+        assert!(deserialize_bundle(&encoded[..encoded.len() - 1]).is_none());
+        assert!(deserialize_bundle(&[encoded.as_slice(), &[0]].concat()).is_none());
+    }
 }
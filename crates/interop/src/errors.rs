@@ -59,3 +59,16 @@ pub enum SuperRootError {
 
 /// A [Result] alias for the [SuperRootError] type.
 pub type SuperRootResult<T> = core::result::Result<T, SuperRootError>;
+
+/// An error type for the [MessageIdentifier] struct's canonical deserialization.
+///
+/// [MessageIdentifier]: crate::MessageIdentifier
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum MessageIdentifierError {
+    /// Unexpected encoded message identifier length
+    #[error("Unexpected encoded message identifier length")]
+    UnexpectedLength,
+}
+
+/// A [Result] alias for the [MessageIdentifierError] type.
+pub type MessageIdentifierResult<T> = core::result::Result<T, MessageIdentifierError>;
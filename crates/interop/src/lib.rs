@@ -25,10 +25,13 @@ mod traits;
 pub use traits::InteropProvider;
 
 mod errors;
-pub use errors::{MessageGraphError, MessageGraphResult, SuperRootError, SuperRootResult};
+pub use errors::{
+    MessageGraphError, MessageGraphResult, MessageIdentifierError, MessageIdentifierResult,
+    SuperRootError, SuperRootResult,
+};
 
 mod super_root;
-pub use super_root::{OutputRootWithChain, SuperRoot};
+pub use super_root::{sort_output_roots, OutputRootWithChain, SuperRoot};
 
 #[cfg(test)]
 mod test_util;
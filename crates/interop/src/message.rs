@@ -3,15 +3,19 @@
 //! <https://specs.optimism.io/interop/messaging.html#messaging>
 //! <https://github.com/ethereum-optimism/optimism/blob/34d5f66ade24bd1f3ce4ce7c0a6cfc1a6540eca1/packages/contracts-bedrock/src/L2/CrossL2Inbox.sol>
 
-use crate::constants::CROSS_L2_INBOX_ADDRESS;
+use crate::{
+    constants::CROSS_L2_INBOX_ADDRESS,
+    errors::{MessageIdentifierError, MessageIdentifierResult},
+};
 use alloc::{vec, vec::Vec};
-use alloy_primitives::{keccak256, Bytes, Log};
+use alloy_primitives::{keccak256, Address, Bytes, Log, U256};
+use alloy_rlp::{Buf, BufMut};
 use alloy_sol_types::{sol, SolEvent};
 use op_alloy_consensus::OpReceiptEnvelope;
 
 sol! {
     /// @notice The struct for a pointer to a message payload in a remote (or local) chain.
-    #[derive(Default, Debug, PartialEq, Eq)]
+    #[derive(Default, Debug, PartialEq, Eq, Hash)]
     struct MessageIdentifier {
         address origin;
         uint256 blockNumber;
@@ -37,6 +41,51 @@ sol! {
     ) external;
 }
 
+impl MessageIdentifier {
+    /// The length of the canonical encoding of a [MessageIdentifier], in bytes.
+    pub const ENCODED_LENGTH: usize = 20 + 32 * 4;
+
+    /// Encodes the [MessageIdentifier] into the given buffer, in its canonical layout:
+    ///
+    /// ```text
+    /// origin (20 bytes) .. block_number .. log_index .. timestamp .. chain_id (32 bytes each)
+    /// ```
+    ///
+    /// All integers are encoded big-endian. The encoding is fixed-length and bijective, so two
+    /// identifiers are equal if and only if their canonical encodings are equal.
+    pub fn encode(&self, out: &mut dyn BufMut) {
+        out.put_slice(self.origin.as_slice());
+        out.put_slice(self.blockNumber.to_be_bytes::<32>().as_slice());
+        out.put_slice(self.logIndex.to_be_bytes::<32>().as_slice());
+        out.put_slice(self.timestamp.to_be_bytes::<32>().as_slice());
+        out.put_slice(self.chainId.to_be_bytes::<32>().as_slice());
+    }
+
+    /// Decodes a [MessageIdentifier] from its canonical layout in the given buffer. See
+    /// [MessageIdentifier::encode].
+    pub fn decode(buf: &mut &[u8]) -> MessageIdentifierResult<Self> {
+        if buf.len() < Self::ENCODED_LENGTH {
+            return Err(MessageIdentifierError::UnexpectedLength);
+        }
+
+        let origin = Address::from_slice(&buf[..20]);
+        buf.advance(20);
+
+        let mut next_u256 = || {
+            let value = U256::from_be_slice(&buf[..32]);
+            buf.advance(32);
+            value
+        };
+        Ok(Self {
+            origin,
+            blockNumber: next_u256(),
+            logIndex: next_u256(),
+            timestamp: next_u256(),
+            chainId: next_u256(),
+        })
+    }
+}
+
 /// A [RawMessagePayload] is the raw payload of an initiating message.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RawMessagePayload(Bytes);
@@ -112,3 +161,91 @@ pub fn extract_executing_messages(receipts: &[OpReceiptEnvelope]) -> Vec<Executi
         acc
     })
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloy_primitives::address;
+    use alloy_sol_types::SolCall;
+    use std::collections::HashSet;
+
+    fn mock_identifier() -> MessageIdentifier {
+        MessageIdentifier {
+            origin: address!("6887246668a3b87f54deb3b94ba47a6f63f32985"),
+            blockNumber: U256::from(0xBEEF),
+            logIndex: U256::from(3),
+            timestamp: U256::from(1_736_000_000),
+            chainId: U256::from(10),
+        }
+    }
+
+    #[test]
+    fn test_message_identifier_roundtrip() {
+        let id = mock_identifier();
+
+        let mut buf = Vec::with_capacity(MessageIdentifier::ENCODED_LENGTH);
+        id.encode(&mut buf);
+        assert_eq!(buf.len(), MessageIdentifier::ENCODED_LENGTH);
+        assert_eq!(&buf[..20], id.origin.as_slice());
+        assert_eq!(buf[20 + 31], 0xEF);
+
+        let mut slice = buf.as_slice();
+        assert_eq!(MessageIdentifier::decode(&mut slice).unwrap(), id);
+        assert!(slice.is_empty());
+    }
+
+    #[test]
+    fn test_message_identifier_roundtrip_max_values() {
+        let id = MessageIdentifier {
+            origin: Address::repeat_byte(0xFF),
+            blockNumber: U256::MAX,
+            logIndex: U256::MAX,
+            timestamp: U256::MAX,
+            chainId: U256::MAX,
+        };
+
+        let mut buf = Vec::new();
+        id.encode(&mut buf);
+        assert_eq!(buf, vec![0xFF; MessageIdentifier::ENCODED_LENGTH]);
+        assert_eq!(MessageIdentifier::decode(&mut buf.as_slice()).unwrap(), id);
+    }
+
+    #[test]
+    fn test_message_identifier_decode_short_buffer() {
+        let mut buf = Vec::new();
+        mock_identifier().encode(&mut buf);
+        buf.pop();
+
+        assert_eq!(
+            MessageIdentifier::decode(&mut buf.as_slice()),
+            Err(MessageIdentifierError::UnexpectedLength)
+        );
+    }
+
+    #[test]
+    fn test_message_identifier_eq_across_construction_paths() {
+        let id = mock_identifier();
+
+        // Decoded from an `executeMessage` call.
+        let call = executeMessageCall {
+            _id: mock_identifier(),
+            _target: Address::ZERO,
+            _message: Bytes::from_static(b"hello"),
+        };
+        let from_call = ExecutingMessage::from(
+            executeMessageCall::abi_decode(&call.abi_encode(), true).unwrap(),
+        )
+        .id;
+
+        // Decoded from the canonical encoding.
+        let mut buf = Vec::new();
+        id.encode(&mut buf);
+        let from_canonical = MessageIdentifier::decode(&mut buf.as_slice()).unwrap();
+
+        assert_eq!(id, from_call);
+        assert_eq!(id, from_canonical);
+
+        let set: HashSet<_> = [id, from_call, from_canonical].into_iter().collect();
+        assert_eq!(set.len(), 1);
+    }
+}
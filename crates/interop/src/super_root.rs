@@ -9,10 +9,13 @@ use crate::{
 use alloc::vec::Vec;
 use alloy_primitives::{keccak256, B256, U256};
 use alloy_rlp::{Buf, BufMut};
+use core::cmp::Ordering;
 
 /// The [SuperRoot] is the snapshot of the superchain at a given timestamp.
+///
+/// The output roots are held in canonical order, ascending by chain ID. [SuperRoot::new] and
+/// [SuperRoot::decode] sort them, so that the encoding and hash commit to the canonical order.
 #[derive(Debug, Clone, Eq, PartialEq)]
-#[cfg_attr(any(feature = "arbitrary", test), derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SuperRoot {
     /// The timestamp of the superchain snapshot, in seconds.
@@ -25,7 +28,7 @@ impl SuperRoot {
     /// Create a new [SuperRoot] with the given timestamp and output roots.
     pub fn new(timestamp: u64, mut output_roots: Vec<OutputRootWithChain>) -> Self {
         // Guarantee that the output roots are sorted by chain ID.
-        output_roots.sort_by_key(|r| r.chain_id);
+        sort_output_roots(&mut output_roots);
         Self { timestamp, output_roots }
     }
 
@@ -60,7 +63,7 @@ impl SuperRoot {
             output_roots.push(OutputRootWithChain::new(chain_id.to(), output_root));
         }
 
-        Ok(Self { timestamp, output_roots })
+        Ok(Self::new(timestamp, output_roots))
     }
 
     /// Encode the [SuperRoot] into the given buffer.
@@ -87,6 +90,13 @@ impl SuperRoot {
     }
 }
 
+#[cfg(any(feature = "arbitrary", test))]
+impl<'a> arbitrary::Arbitrary<'a> for SuperRoot {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::new(u.arbitrary()?, u.arbitrary()?))
+    }
+}
+
 /// A wrapper around an output root hash with the chain ID it belongs to.
 #[derive(Debug, Clone, Eq, PartialEq)]
 #[cfg_attr(any(feature = "arbitrary", test), derive(arbitrary::Arbitrary))]
@@ -105,11 +115,30 @@ impl OutputRootWithChain {
     }
 }
 
+impl Ord for OutputRootWithChain {
+    /// Orders [OutputRootWithChain]s by chain ID, falling back to the output root hash to remain
+    /// consistent with [Eq].
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.chain_id.cmp(&other.chain_id).then_with(|| self.output_root.cmp(&other.output_root))
+    }
+}
+
+impl PartialOrd for OutputRootWithChain {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Sorts the given output roots into the canonical [SuperRoot] order, ascending by chain ID.
+pub fn sort_output_roots(output_roots: &mut [OutputRootWithChain]) {
+    output_roots.sort_unstable();
+}
+
 #[cfg(test)]
 mod test {
     use crate::{errors::SuperRootError, SUPER_ROOT_VERSION};
 
-    use super::{OutputRootWithChain, SuperRoot};
+    use super::{sort_output_roots, OutputRootWithChain, SuperRoot};
     use alloy_primitives::{b256, B256};
     use arbitrary::Arbitrary;
     use rand::Rng;
@@ -128,6 +157,27 @@ mod test {
         assert!(super_root.output_roots.is_sorted_by_key(|r| r.chain_id));
     }
 
+    #[test]
+    fn test_sort_output_roots() {
+        let mut output_roots = vec![
+            OutputRootWithChain::new(3, B256::default()),
+            OutputRootWithChain::new(1, B256::default()),
+            OutputRootWithChain::new(2, B256::default()),
+        ];
+        sort_output_roots(&mut output_roots);
+
+        let expected = vec![
+            OutputRootWithChain::new(1, B256::default()),
+            OutputRootWithChain::new(2, B256::default()),
+            OutputRootWithChain::new(3, B256::default()),
+        ];
+        assert_eq!(output_roots, expected);
+
+        // Sorting an already sorted slice is a no-op.
+        sort_output_roots(&mut output_roots);
+        assert_eq!(output_roots, expected);
+    }
+
     #[test]
     fn test_super_root_empty_buf() {
         let buf: Vec<u8> = Vec::new();
@@ -179,6 +229,29 @@ mod test {
         assert_eq!(super_root.hash(), EXPECTED);
     }
 
+    #[test]
+    fn test_super_root_hash_order_independent() {
+        let a = OutputRootWithChain::new(1, B256::repeat_byte(0xAA));
+        let b = OutputRootWithChain::new(2, B256::repeat_byte(0xBB));
+
+        let sorted = SuperRoot::new(10, vec![a.clone(), b.clone()]);
+        let unsorted = SuperRoot::new(10, vec![b, a]);
+        assert_eq!(sorted.hash(), unsorted.hash());
+    }
+
+    #[test]
+    fn test_super_root_decode_sorts_outputs() {
+        let a = OutputRootWithChain::new(1, B256::repeat_byte(0xAA));
+        let b = OutputRootWithChain::new(2, B256::repeat_byte(0xBB));
+
+        // An encoding holding the output roots out of order decodes into the canonical order.
+        let unsorted = SuperRoot { timestamp: 10, output_roots: vec![b.clone(), a.clone()] };
+        let mut buf = Vec::with_capacity(unsorted.encoded_length());
+        unsorted.encode(&mut buf);
+        let decoded = SuperRoot::decode(&mut buf.as_slice()).unwrap();
+        assert_eq!(decoded, SuperRoot::new(10, vec![a, b]));
+    }
+
     #[test]
     fn test_static_super_root_roundtrip() {
         let super_root = SuperRoot::new(
@@ -23,6 +23,9 @@ pub enum TrieNodeError {
     /// Trie node is not a leaf node.
     #[error("Trie provider error: {0}")]
     Provider(String),
+    /// The maximum depth was exceeded while walking the trie.
+    #[error("Maximum trie depth exceeded")]
+    MaxDepthExceeded,
 }
 
 /// A [Result] type alias where the error is [OrderedListWalkerError].
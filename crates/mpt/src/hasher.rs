@@ -0,0 +1,20 @@
+//! Contains the default [TrieHasher] implementation, [KeccakTrieHasher].
+
+use crate::TrieHasher;
+use alloy_primitives::{keccak256, B256};
+use alloy_trie::EMPTY_ROOT_HASH;
+
+/// The default [TrieHasher], which commits to trie nodes with [keccak256] as in the Ethereum
+/// Merkle Patricia Trie.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct KeccakTrieHasher;
+
+impl TrieHasher for KeccakTrieHasher {
+    fn hash_node(&self, data: &[u8]) -> B256 {
+        keccak256(data)
+    }
+
+    fn empty_root(&self) -> B256 {
+        EMPTY_ROOT_HASH
+    }
+}
@@ -13,10 +13,13 @@ mod errors;
 pub use errors::{OrderedListWalkerError, OrderedListWalkerResult, TrieNodeError, TrieNodeResult};
 
 mod traits;
-pub use traits::{TrieHinter, TrieProvider};
+pub use traits::{TrieHasher, TrieHinter, TrieProvider};
+
+mod hasher;
+pub use hasher::KeccakTrieHasher;
 
 mod node;
-pub use node::TrieNode;
+pub use node::{TrieNode, DEFAULT_MAX_TRIE_DEPTH};
 
 mod list_walker;
 pub use list_walker::OrderedListWalker;
@@ -25,7 +28,7 @@ mod noop;
 pub use noop::{NoopTrieHinter, NoopTrieProvider};
 
 mod util;
-pub use util::ordered_trie_with_encoder;
+pub use util::{ordered_trie_root_with_hasher, ordered_trie_with_encoder};
 
 // Re-export [alloy_trie::Nibbles].
 pub use alloy_trie::Nibbles;
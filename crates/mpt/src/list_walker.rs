@@ -3,7 +3,7 @@
 
 use crate::{
     errors::{OrderedListWalkerError, OrderedListWalkerResult},
-    TrieNode, TrieNodeError, TrieProvider,
+    KeccakTrieHasher, TrieHasher, TrieNode, TrieNodeError, TrieProvider,
 };
 use alloc::{collections::VecDeque, string::ToString, vec};
 use alloy_primitives::{Bytes, B256};
@@ -15,13 +15,18 @@ use core::marker::PhantomData;
 ///
 /// Once it has ben hydrated with [Self::hydrate], the elements in the derivable list can be
 /// iterated over using the [Iterator] implementation.
+///
+/// The trie is assumed to be committed to with the [TrieHasher] `H`, which defaults to the
+/// [KeccakTrieHasher].
 #[derive(Debug, Clone, Eq, PartialEq)]
-pub struct OrderedListWalker<F: TrieProvider> {
+pub struct OrderedListWalker<F: TrieProvider, H: TrieHasher = KeccakTrieHasher> {
     /// The Merkle Patricia Trie root.
     root: B256,
     /// The leaf nodes of the derived list, in order. [None] if the tree has yet to be fully
     /// traversed with [Self::hydrate].
     inner: Option<VecDeque<(Bytes, Bytes)>>,
+    /// The [TrieHasher] that committed to the trie.
+    hasher: H,
     /// Phantom data
     _phantom: PhantomData<F>,
 }
@@ -32,13 +37,35 @@ where
 {
     /// Creates a new [OrderedListWalker], yet to be hydrated.
     pub const fn new(root: B256) -> Self {
-        Self { root, inner: None, _phantom: PhantomData }
+        Self::new_with_hasher(root, KeccakTrieHasher)
     }
 
     /// Creates a new [OrderedListWalker] and hydrates it with [Self::hydrate] and the given fetcher
     /// immediately.
     pub fn try_new_hydrated(root: B256, fetcher: &F) -> OrderedListWalkerResult<Self> {
-        let mut walker = Self { root, inner: None, _phantom: PhantomData };
+        Self::try_new_hydrated_with(root, fetcher, KeccakTrieHasher)
+    }
+}
+
+impl<F, H> OrderedListWalker<F, H>
+where
+    F: TrieProvider,
+    H: TrieHasher,
+{
+    /// Creates a new [OrderedListWalker] over a trie committed to with the given [TrieHasher], yet
+    /// to be hydrated.
+    pub const fn new_with_hasher(root: B256, hasher: H) -> Self {
+        Self { root, inner: None, hasher, _phantom: PhantomData }
+    }
+
+    /// Creates a new [OrderedListWalker] over a trie committed to with the given [TrieHasher], and
+    /// hydrates it with [Self::hydrate] and the given fetcher immediately.
+    pub fn try_new_hydrated_with(
+        root: B256,
+        fetcher: &F,
+        hasher: H,
+    ) -> OrderedListWalkerResult<Self> {
+        let mut walker = Self::new_with_hasher(root, hasher);
         walker.hydrate(fetcher)?;
         Ok(walker)
     }
@@ -52,12 +79,12 @@ where
         }
 
         // Get the preimage to the root node.
-        let root_trie_node = Self::get_trie_node(self.root, fetcher)?;
+        let root_trie_node = Self::get_trie_node(self.root, fetcher, &self.hasher)?;
 
         // With small lists the iterator seems to use 0x80 (RLP empty string, unlike the others)
         // as key for item 0, causing it to come last. We need to account for this, pulling the
         // first element into its proper position.
-        let mut ordered_list = Self::fetch_leaves(&root_trie_node, fetcher)?;
+        let mut ordered_list = Self::fetch_leaves(&root_trie_node, fetcher, &self.hasher)?;
         if !ordered_list.is_empty() {
             if ordered_list.len() <= EMPTY_STRING_CODE as usize {
                 // If the list length is < 0x80, the final element is the first element.
@@ -85,6 +112,7 @@ where
     fn fetch_leaves(
         trie_node: &TrieNode,
         fetcher: &F,
+        hasher: &H,
     ) -> OrderedListWalkerResult<VecDeque<(Bytes, Bytes)>> {
         match trie_node {
             TrieNode::Branch { stack } => {
@@ -94,14 +122,16 @@ where
                         TrieNode::Blinded { commitment } => {
                             // If the string is a hash, we need to grab the preimage for it and
                             // continue recursing.
-                            let trie_node = Self::get_trie_node(commitment.as_ref(), fetcher)?;
-                            leaf_values.append(&mut Self::fetch_leaves(&trie_node, fetcher)?);
+                            let trie_node =
+                                Self::get_trie_node(commitment.as_ref(), fetcher, hasher)?;
+                            leaf_values
+                                .append(&mut Self::fetch_leaves(&trie_node, fetcher, hasher)?);
                         }
                         TrieNode::Empty => { /* Skip over empty nodes, we're looking for values. */
                         }
                         item => {
                             // If the item is already retrieved, recurse on it.
-                            leaf_values.append(&mut Self::fetch_leaves(item, fetcher)?);
+                            leaf_values.append(&mut Self::fetch_leaves(item, fetcher, hasher)?);
                         }
                     }
                 }
@@ -115,10 +145,10 @@ where
                 // recursing. If it is already retrieved, recurse on it.
                 match node.as_ref() {
                     TrieNode::Blinded { commitment } => {
-                        let trie_node = Self::get_trie_node(commitment.as_ref(), fetcher)?;
-                        Ok(Self::fetch_leaves(&trie_node, fetcher)?)
+                        let trie_node = Self::get_trie_node(commitment.as_ref(), fetcher, hasher)?;
+                        Ok(Self::fetch_leaves(&trie_node, fetcher, hasher)?)
                     }
-                    node => Ok(Self::fetch_leaves(node, fetcher)?),
+                    node => Ok(Self::fetch_leaves(node, fetcher, hasher)?),
                 }
             }
             TrieNode::Empty => Ok(VecDeque::new()),
@@ -127,21 +157,28 @@ where
     }
 
     /// Grabs the preimage of `hash` using `fetcher`, and attempts to decode the preimage data into
-    /// a [TrieNode]. Will error if the conversion of `T` into [B256] fails.
-    fn get_trie_node<T>(hash: T, fetcher: &F) -> OrderedListWalkerResult<TrieNode>
+    /// a [TrieNode]. Will error if the conversion of `T` into [B256] fails. A commitment to the
+    /// empty trie under `hasher` is resolved to [TrieNode::Empty] without a fetch.
+    fn get_trie_node<T>(hash: T, fetcher: &F, hasher: &H) -> OrderedListWalkerResult<TrieNode>
     where
         T: Into<B256>,
     {
+        let hash = hash.into();
+        if hash == hasher.empty_root() {
+            return Ok(TrieNode::Empty);
+        }
+
         fetcher
-            .trie_node_by_hash(hash.into())
+            .trie_node_by_hash(hash)
             .map_err(|e| TrieNodeError::Provider(e.to_string()))
             .map_err(Into::into)
     }
 }
 
-impl<F> Iterator for OrderedListWalker<F>
+impl<F, H> Iterator for OrderedListWalker<F, H>
 where
     F: TrieProvider,
+    H: TrieHasher,
 {
     type Item = (Bytes, Bytes);
 
@@ -163,10 +200,10 @@ where
 mod test {
     use super::*;
     use crate::{
-        ordered_trie_with_encoder,
+        ordered_trie_root_with_hasher, ordered_trie_with_encoder,
         test_util::{
             get_live_derivable_receipts_list, get_live_derivable_transactions_list,
-            TrieNodeProvider,
+            RecordingHasher, TrieNodeProvider,
         },
         NoopTrieProvider,
     };
@@ -175,6 +212,7 @@ mod test {
     use alloy_primitives::keccak256;
     use alloy_provider::network::eip2718::Decodable2718;
     use alloy_rlp::{Decodable, Encodable};
+    use alloy_trie::EMPTY_ROOT_HASH;
 
     #[tokio::test]
     async fn test_online_list_walker_receipts() {
@@ -234,8 +272,53 @@ mod test {
 
     #[test]
     fn test_empty_list_walker() {
-        assert!(OrderedListWalker::fetch_leaves(&TrieNode::Empty, &NoopTrieProvider)
-            .expect("Failed to traverse empty trie")
-            .is_empty());
+        assert!(OrderedListWalker::fetch_leaves(
+            &TrieNode::Empty,
+            &NoopTrieProvider,
+            &KeccakTrieHasher
+        )
+        .expect("Failed to traverse empty trie")
+        .is_empty());
+    }
+
+    #[test]
+    fn test_ordered_trie_root_with_hasher() {
+        const VALUES: [&str; 3] = ["test one", "test two", "test three"];
+
+        let expected = ordered_trie_with_encoder(&VALUES, |v, buf| v.encode(buf)).root();
+        let root =
+            ordered_trie_root_with_hasher(&VALUES, |v, buf| v.encode(buf), &KeccakTrieHasher)
+                .unwrap();
+        assert_eq!(root, expected);
+
+        let root = ordered_trie_root_with_hasher(
+            &[] as &[&str],
+            |v, buf| v.encode(buf),
+            &KeccakTrieHasher,
+        )
+        .unwrap();
+        assert_eq!(root, EMPTY_ROOT_HASH);
+    }
+
+    #[test]
+    fn test_list_walker_with_hasher() {
+        const VALUES: [&str; 3] = ["test one", "test two", "test three"];
+
+        let hasher = RecordingHasher::default();
+        let root = ordered_trie_root_with_hasher(&VALUES, |v, buf| v.encode(buf), &hasher).unwrap();
+        let fetcher = TrieNodeProvider::new(hasher.preimages.take());
+
+        let list = OrderedListWalker::try_new_hydrated_with(root, &fetcher, hasher).unwrap();
+        assert_eq!(
+            list.map(|(_, v)| String::decode(&mut v.as_ref()).unwrap()).collect::<Vec<_>>(),
+            VALUES
+        );
+
+        // The commitment to the empty trie is resolved without fetching its preimage.
+        let hasher = RecordingHasher::default();
+        let fetcher = TrieNodeProvider::new(BTreeMap::default());
+        let mut list = OrderedListWalker::new_with_hasher(hasher.empty_root(), hasher);
+        list.hydrate(&fetcher).unwrap();
+        assert_eq!(list.next(), None);
     }
 }
@@ -4,10 +4,10 @@
 use crate::{
     errors::TrieNodeResult,
     util::{rlp_list_element_length, unpack_path_to_nibbles},
-    TrieHinter, TrieNodeError, TrieProvider,
+    KeccakTrieHasher, TrieHasher, TrieHinter, TrieNodeError, TrieProvider,
 };
 use alloc::{boxed::Box, string::ToString, vec, vec::Vec};
-use alloy_primitives::{keccak256, Bytes, B256};
+use alloy_primitives::{Bytes, B256};
 use alloy_rlp::{length_of_length, Buf, Decodable, Encodable, Header, EMPTY_STRING_CODE};
 use alloy_trie::{Nibbles, EMPTY_ROOT_HASH};
 
@@ -35,6 +35,12 @@ const PREFIX_LEAF_ODD: u8 = 3;
 /// Nibble bit width.
 const NIBBLE_WIDTH: usize = 4;
 
+/// The default maximum number of nodes that [TrieNode::open], [TrieNode::insert], and
+/// [TrieNode::delete] will descend through. Well-formed tries with 32 byte keys are at most 64
+/// nodes deep, but crafted preimages (e.g. chains of extension nodes with empty prefixes) can be
+/// arbitrarily deep.
+pub const DEFAULT_MAX_TRIE_DEPTH: usize = 1024;
+
 /// A [TrieNode] is a node within a standard Ethereum Merkle Patricia Trie. In this implementation,
 /// keys are expected to be fixed-size nibble sequences, and values are arbitrary byte sequences.
 ///
@@ -49,7 +55,9 @@ const NIBBLE_WIDTH: usize = 4;
 ///
 /// In the Ethereum Merkle Patricia Trie, nodes longer than an encoded 32 byte string (33 total
 /// bytes) are blinded with [keccak256] hashes. When a node is "opened", it is replaced with the
-/// [TrieNode] that is decoded from to the preimage of the hash.
+/// [TrieNode] that is decoded from to the preimage of the hash. [TrieNode::blind_with] blinds
+/// nodes with the commitments of a custom [TrieHasher] instead, and [TrieNode::open_with],
+/// [TrieNode::insert_with] and [TrieNode::delete_with] traverse such tries.
 ///
 /// The [alloy_rlp::Encodable] and [alloy_rlp::Decodable] traits are implemented for [TrieNode],
 /// allowing for RLP encoding and decoding of the types for storage and retrieval. The
@@ -60,12 +68,14 @@ const NIBBLE_WIDTH: usize = 4;
 /// As this implementation only supports uniform key sizes, the [TrieNode] data structure will fail
 /// to behave correctly if confronted with keys of varying lengths. Namely, this is because it does
 /// not support the `value` field in branch nodes, just like the Ethereum Merkle Patricia Trie.
+///
+/// [keccak256]: alloy_primitives::keccak256
 #[derive(Debug, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TrieNode {
     /// An empty [TrieNode] is represented as an [EMPTY_STRING_CODE] (0x80).
     Empty,
-    /// A blinded node is a node that has been blinded by a [keccak256] commitment.
+    /// A blinded node is a node that has been blinded by a commitment.
     Blinded {
         /// The commitment that blinds the node.
         commitment: B256,
@@ -121,18 +131,54 @@ impl TrieNode {
     /// Blinds the [TrieNode] if its encoded length is longer than an encoded [B256] string in
     /// length. Alternatively, if the [TrieNode] is a [TrieNode::Blinded] node already, it
     /// is left as-is.
+    ///
+    /// Nodes are committed to with [KeccakTrieHasher]. See [Self::blind_with] to use a custom
+    /// [TrieHasher].
     pub fn blind(&mut self) {
+        self.blind_with(&KeccakTrieHasher)
+    }
+
+    /// Blinds the [TrieNode] with the commitment computed by the given [TrieHasher], if its
+    /// encoded length is longer than an encoded [B256] string in length. Alternatively, if the
+    /// [TrieNode] is a [TrieNode::Blinded] node already, it is left as-is.
+    ///
+    /// Unlike [Self::open], [Self::insert], and [Self::delete], blinding does not take a maximum
+    /// depth. It never fetches preimages, and only recurses through the nodes already held in
+    /// memory, which were revealed by walks bounded by [DEFAULT_MAX_TRIE_DEPTH] or a custom limit.
+    ///
+    /// ## Takes
+    /// - `self` - The trie node to blind
+    /// - `hasher` - The [TrieHasher] used to commit to the trie node and its children
+    pub fn blind_with<H: TrieHasher>(&mut self, hasher: &H) {
         if self.length() >= B256::ZERO.len() && !matches!(self, Self::Blinded { .. }) {
             let mut rlp_buf = Vec::with_capacity(self.length());
-            self.encode_in_place(&mut rlp_buf);
-            *self = Self::Blinded { commitment: keccak256(rlp_buf) }
+            self.encode_in_place_with(&mut rlp_buf, hasher);
+            *self = Self::Blinded { commitment: hasher.hash_node(&rlp_buf) }
         }
     }
 
     /// Unblinds the [TrieNode] if it is a [TrieNode::Blinded] node.
+    ///
+    /// A commitment to the empty trie is recognized with [KeccakTrieHasher]. See
+    /// [Self::unblind_with] to use a custom [TrieHasher].
     pub fn unblind<F: TrieProvider>(&mut self, fetcher: &F) -> TrieNodeResult<()> {
+        self.unblind_with(fetcher, &KeccakTrieHasher)
+    }
+
+    /// Unblinds the [TrieNode] if it is a [TrieNode::Blinded] node, recognizing a commitment to
+    /// the empty trie with the given [TrieHasher].
+    ///
+    /// ## Takes
+    /// - `self` - The trie node to unblind
+    /// - `fetcher` - The preimage fetcher for the blinded node
+    /// - `hasher` - The [TrieHasher] that committed to the trie
+    pub fn unblind_with<F: TrieProvider, H: TrieHasher>(
+        &mut self,
+        fetcher: &F,
+        hasher: &H,
+    ) -> TrieNodeResult<()> {
         if let Self::Blinded { commitment } = self {
-            if *commitment == EMPTY_ROOT_HASH {
+            if *commitment == hasher.empty_root() {
                 // If the commitment is the empty root hash, the node is empty, and we don't need to
                 // reach out to the fetcher.
                 *self = Self::Empty;
@@ -149,6 +195,9 @@ impl TrieNode {
     /// nodes along the path are fetched using the `fetcher` function, and persisted in the inner
     /// [TrieNode] elements.
     ///
+    /// The walk descends through at most [DEFAULT_MAX_TRIE_DEPTH] nodes. See
+    /// [Self::open_with_max_depth] to configure the limit.
+    ///
     /// ## Takes
     /// - `self` - The root trie node
     /// - `path` - The nibbles representation of the path to the leaf node
@@ -162,12 +211,79 @@ impl TrieNode {
         path: &Nibbles,
         fetcher: &F,
     ) -> TrieNodeResult<Option<&'a mut Bytes>> {
+        self.open_with_max_depth(path, fetcher, DEFAULT_MAX_TRIE_DEPTH)
+    }
+
+    /// Alternative function to [Self::open], that unblinds the nodes along the path with
+    /// [Self::unblind_with] and the given [TrieHasher].
+    ///
+    /// ## Takes
+    /// - `self` - The root trie node
+    /// - `path` - The nibbles representation of the path to the leaf node
+    /// - `fetcher` - The preimage fetcher for intermediate blinded nodes
+    /// - `hasher` - The [TrieHasher] that committed to the trie
+    ///
+    /// ## Returns
+    /// - `Err(_)` - Could not retrieve the node with the given key from the trie.
+    /// - `Ok((_, _))` - The key and value of the node
+    pub fn open_with<'a, F: TrieProvider, H: TrieHasher>(
+        &'a mut self,
+        path: &Nibbles,
+        fetcher: &F,
+        hasher: &H,
+    ) -> TrieNodeResult<Option<&'a mut Bytes>> {
+        self.open_inner(path, fetcher, hasher, DEFAULT_MAX_TRIE_DEPTH)
+    }
+
+    /// Walks down the trie to a leaf value with the given key, if it exists, descending through at
+    /// most `max_depth` nodes. Preimages for blinded nodes along the path are fetched using the
+    /// `fetcher` function, and persisted in the inner [TrieNode] elements.
+    ///
+    /// ## Takes
+    /// - `self` - The root trie node
+    /// - `path` - The nibbles representation of the path to the leaf node
+    /// - `fetcher` - The preimage fetcher for intermediate blinded nodes
+    /// - `max_depth` - The maximum number of nodes to descend through
+    ///
+    /// ## Returns
+    /// - `Err(TrieNodeError::MaxDepthExceeded)` - The walk exceeded `max_depth` nodes.
+    /// - `Err(_)` - Could not retrieve the node with the given key from the trie.
+    /// - `Ok((_, _))` - The key and value of the node
+    pub fn open_with_max_depth<'a, F: TrieProvider>(
+        &'a mut self,
+        path: &Nibbles,
+        fetcher: &F,
+        max_depth: usize,
+    ) -> TrieNodeResult<Option<&'a mut Bytes>> {
+        self.open_inner(path, fetcher, &KeccakTrieHasher, max_depth)
+    }
+
+    /// Walks down the trie to a leaf value with the given key, descending through at most
+    /// `max_depth` nodes and unblinding the nodes along the path with the given [TrieHasher].
+    fn open_inner<'a, F: TrieProvider, H: TrieHasher>(
+        &'a mut self,
+        path: &Nibbles,
+        fetcher: &F,
+        hasher: &H,
+        max_depth: usize,
+    ) -> TrieNodeResult<Option<&'a mut Bytes>> {
+        let Some(max_depth) = max_depth.checked_sub(1) else {
+            return Err(TrieNodeError::MaxDepthExceeded);
+        };
+
         match self {
             Self::Branch { ref mut stack } => {
                 let branch_nibble = path[0] as usize;
                 stack
                     .get_mut(branch_nibble)
-                    .map(|node| node.open(&path.slice(BRANCH_NODE_NIBBLES..), fetcher))
+                    .map(|node| {
+                        node.open_inner(
+                            &path.slice(BRANCH_NODE_NIBBLES..),
+                            fetcher,
+                            hasher,
+                            max_depth,
+                        )
+                    })
                     .unwrap_or(Ok(None))
             }
             Self::Leaf { prefix, value } => {
@@ -176,15 +292,15 @@ impl TrieNode {
             Self::Extension { prefix, node } => {
                 if path.slice(..prefix.len()).as_slice() == prefix.as_slice() {
                     // Follow extension branch
-                    node.unblind(fetcher)?;
-                    node.open(&path.slice(prefix.len()..), fetcher)
+                    node.unblind_with(fetcher, hasher)?;
+                    node.open_inner(&path.slice(prefix.len()..), fetcher, hasher, max_depth)
                 } else {
                     Ok(None)
                 }
             }
             Self::Blinded { .. } => {
-                self.unblind(fetcher)?;
-                self.open(path, fetcher)
+                self.unblind_with(fetcher, hasher)?;
+                self.open_inner(path, fetcher, hasher, max_depth)
             }
             Self::Empty => Ok(None),
         }
@@ -192,6 +308,9 @@ impl TrieNode {
 
     /// Inserts a [TrieNode] at the given path into the trie rooted at Self.
     ///
+    /// The walk descends through at most [DEFAULT_MAX_TRIE_DEPTH] nodes. See
+    /// [Self::insert_with_max_depth] to configure the limit.
+    ///
     /// ## Takes
     /// - `self` - The root trie node
     /// - `path` - The nibbles representation of the path to the leaf node
@@ -207,6 +326,71 @@ impl TrieNode {
         value: Bytes,
         fetcher: &F,
     ) -> TrieNodeResult<()> {
+        self.insert_with_max_depth(path, value, fetcher, DEFAULT_MAX_TRIE_DEPTH)
+    }
+
+    /// Alternative function to [Self::insert], that unblinds the nodes along the path with
+    /// [Self::unblind_with] and the given [TrieHasher].
+    ///
+    /// ## Takes
+    /// - `self` - The root trie node
+    /// - `path` - The nibbles representation of the path to the leaf node
+    /// - `node` - The node to insert at the given path
+    /// - `fetcher` - The preimage fetcher for intermediate blinded nodes
+    /// - `hasher` - The [TrieHasher] that committed to the trie
+    ///
+    /// ## Returns
+    /// - `Err(_)` - Could not insert the node at the given path in the trie.
+    /// - `Ok(())` - The node was successfully inserted at the given path.
+    pub fn insert_with<F: TrieProvider, H: TrieHasher>(
+        &mut self,
+        path: &Nibbles,
+        value: Bytes,
+        fetcher: &F,
+        hasher: &H,
+    ) -> TrieNodeResult<()> {
+        self.insert_inner(path, value, fetcher, hasher, DEFAULT_MAX_TRIE_DEPTH)
+    }
+
+    /// Inserts a [TrieNode] at the given path into the trie rooted at Self, descending through at
+    /// most `max_depth` nodes.
+    ///
+    /// ## Takes
+    /// - `self` - The root trie node
+    /// - `path` - The nibbles representation of the path to the leaf node
+    /// - `node` - The node to insert at the given path
+    /// - `fetcher` - The preimage fetcher for intermediate blinded nodes
+    /// - `max_depth` - The maximum number of nodes to descend through
+    ///
+    /// ## Returns
+    /// - `Err(TrieNodeError::MaxDepthExceeded)` - The walk exceeded `max_depth` nodes.
+    /// - `Err(_)` - Could not insert the node at the given path in the trie.
+    /// - `Ok(())` - The node was successfully inserted at the given path.
+    pub fn insert_with_max_depth<F: TrieProvider>(
+        &mut self,
+        path: &Nibbles,
+        value: Bytes,
+        fetcher: &F,
+        max_depth: usize,
+    ) -> TrieNodeResult<()> {
+        self.insert_inner(path, value, fetcher, &KeccakTrieHasher, max_depth)
+    }
+
+    /// Inserts a [TrieNode] at the given path into the trie rooted at Self, descending through at
+    /// most `max_depth` nodes and unblinding the nodes along the path with the given
+    /// [TrieHasher].
+    fn insert_inner<F: TrieProvider, H: TrieHasher>(
+        &mut self,
+        path: &Nibbles,
+        value: Bytes,
+        fetcher: &F,
+        hasher: &H,
+        max_depth: usize,
+    ) -> TrieNodeResult<()> {
+        let Some(max_depth) = max_depth.checked_sub(1) else {
+            return Err(TrieNodeError::MaxDepthExceeded);
+        };
+
         match self {
             Self::Empty => {
                 // If the trie node is null, insert the leaf node at the current path.
@@ -255,7 +439,13 @@ impl TrieNode {
             Self::Extension { prefix, node } => {
                 let shared_extension_nibbles = path.common_prefix_length(prefix);
                 if shared_extension_nibbles == prefix.len() {
-                    node.insert(&path.slice(shared_extension_nibbles..), value, fetcher)?;
+                    node.insert_inner(
+                        &path.slice(shared_extension_nibbles..),
+                        value,
+                        fetcher,
+                        hasher,
+                        max_depth,
+                    )?;
                     return Ok(());
                 }
 
@@ -296,19 +486,28 @@ impl TrieNode {
             Self::Branch { stack } => {
                 // Follow the branch node to the next node in the path.
                 let branch_nibble = path[0] as usize;
-                stack[branch_nibble].insert(&path.slice(BRANCH_NODE_NIBBLES..), value, fetcher)
+                stack[branch_nibble].insert_inner(
+                    &path.slice(BRANCH_NODE_NIBBLES..),
+                    value,
+                    fetcher,
+                    hasher,
+                    max_depth,
+                )
             }
             Self::Blinded { .. } => {
                 // If a blinded node is approached, reveal the node and continue the insertion
                 // recursion.
-                self.unblind(fetcher)?;
-                self.insert(path, value, fetcher)
+                self.unblind_with(fetcher, hasher)?;
+                self.insert_inner(path, value, fetcher, hasher, max_depth)
             }
         }
     }
 
     /// Deletes a node in the trie at the given path.
     ///
+    /// The walk descends through at most [DEFAULT_MAX_TRIE_DEPTH] nodes. See
+    /// [Self::delete_with_max_depth] to configure the limit.
+    ///
     /// ## Takes
     /// - `self` - The root trie node
     /// - `path` - The nibbles representation of the path to the leaf node
@@ -322,6 +521,65 @@ impl TrieNode {
         fetcher: &F,
         hinter: &H,
     ) -> TrieNodeResult<()> {
+        self.delete_with_max_depth(path, fetcher, hinter, DEFAULT_MAX_TRIE_DEPTH)
+    }
+
+    /// Alternative function to [Self::delete], that unblinds the nodes along the path with
+    /// [Self::unblind_with] and the given [TrieHasher].
+    ///
+    /// ## Takes
+    /// - `self` - The root trie node
+    /// - `path` - The nibbles representation of the path to the leaf node
+    /// - `hasher` - The [TrieHasher] that committed to the trie
+    ///
+    /// ## Returns
+    /// - `Err(_)` - Could not delete the node at the given path in the trie.
+    /// - `Ok(())` - The node was successfully deleted at the given path.
+    pub fn delete_with<F: TrieProvider, H: TrieHinter, T: TrieHasher>(
+        &mut self,
+        path: &Nibbles,
+        fetcher: &F,
+        hinter: &H,
+        hasher: &T,
+    ) -> TrieNodeResult<()> {
+        self.delete_inner(path, fetcher, hinter, hasher, DEFAULT_MAX_TRIE_DEPTH)
+    }
+
+    /// Deletes a node in the trie at the given path, descending through at most `max_depth` nodes.
+    ///
+    /// ## Takes
+    /// - `self` - The root trie node
+    /// - `path` - The nibbles representation of the path to the leaf node
+    /// - `max_depth` - The maximum number of nodes to descend through
+    ///
+    /// ## Returns
+    /// - `Err(TrieNodeError::MaxDepthExceeded)` - The walk exceeded `max_depth` nodes.
+    /// - `Err(_)` - Could not delete the node at the given path in the trie.
+    /// - `Ok(())` - The node was successfully deleted at the given path.
+    pub fn delete_with_max_depth<F: TrieProvider, H: TrieHinter>(
+        &mut self,
+        path: &Nibbles,
+        fetcher: &F,
+        hinter: &H,
+        max_depth: usize,
+    ) -> TrieNodeResult<()> {
+        self.delete_inner(path, fetcher, hinter, &KeccakTrieHasher, max_depth)
+    }
+
+    /// Deletes a node in the trie at the given path, descending through at most `max_depth` nodes
+    /// and unblinding the nodes along the path with the given [TrieHasher].
+    fn delete_inner<F: TrieProvider, H: TrieHinter, T: TrieHasher>(
+        &mut self,
+        path: &Nibbles,
+        fetcher: &F,
+        hinter: &H,
+        hasher: &T,
+        max_depth: usize,
+    ) -> TrieNodeResult<()> {
+        let Some(max_depth) = max_depth.checked_sub(1) else {
+            return Err(TrieNodeError::MaxDepthExceeded);
+        };
+
         match self {
             Self::Empty => Err(TrieNodeError::KeyNotFound),
             Self::Leaf { prefix, .. } => {
@@ -341,21 +599,27 @@ impl TrieNode {
                     return Ok(());
                 }
 
-                node.delete(&path.slice(prefix.len()..), fetcher, hinter)?;
+                node.delete_inner(&path.slice(prefix.len()..), fetcher, hinter, hasher, max_depth)?;
 
                 // Simplify extension if possible after the deletion
-                self.collapse_if_possible(fetcher, hinter)
+                self.collapse_if_possible(fetcher, hinter, hasher)
             }
             Self::Branch { stack } => {
                 let branch_nibble = path[0] as usize;
-                stack[branch_nibble].delete(&path.slice(BRANCH_NODE_NIBBLES..), fetcher, hinter)?;
+                stack[branch_nibble].delete_inner(
+                    &path.slice(BRANCH_NODE_NIBBLES..),
+                    fetcher,
+                    hinter,
+                    hasher,
+                    max_depth,
+                )?;
 
                 // Simplify the branch if possible after the deletion
-                self.collapse_if_possible(fetcher, hinter)
+                self.collapse_if_possible(fetcher, hinter, hasher)
             }
             Self::Blinded { .. } => {
-                self.unblind(fetcher)?;
-                self.delete(path, fetcher, hinter)
+                self.unblind_with(fetcher, hasher)?;
+                self.delete_inner(path, fetcher, hinter, hasher, max_depth)
             }
         }
     }
@@ -368,6 +632,21 @@ impl TrieNode {
     /// - `self` - The root trie node
     /// - `out` - The buffer to write the encoded trie node to
     pub fn encode_in_place(&mut self, out: &mut dyn alloy_rlp::BufMut) {
+        self.encode_in_place_with(out, &KeccakTrieHasher)
+    }
+
+    /// Alternative function to [Self::encode_in_place], that blinds children nodes with the
+    /// commitments computed by the given [TrieHasher].
+    ///
+    /// ## Takes
+    /// - `self` - The root trie node
+    /// - `out` - The buffer to write the encoded trie node to
+    /// - `hasher` - The [TrieHasher] used to commit to the children nodes
+    pub fn encode_in_place_with<H: TrieHasher>(
+        &mut self,
+        out: &mut dyn alloy_rlp::BufMut,
+        hasher: &H,
+    ) {
         let payload_length = self.payload_length();
         match self {
             Self::Empty => out.put_u8(EMPTY_STRING_CODE),
@@ -382,8 +661,8 @@ impl TrieNode {
                 // Encode the extension node's header, prefix, and pointer node.
                 Header { list: true, payload_length }.encode(out);
                 alloy_trie::nodes::encode_path_leaf(prefix, false).as_slice().encode(out);
-                node.blind();
-                node.encode_in_place(out);
+                node.blind_with(hasher);
+                node.encode_in_place_with(out, hasher);
             }
             Self::Branch { stack } => {
                 // In branch nodes, if an element is longer than 32 bytes in length, it is blinded.
@@ -391,8 +670,8 @@ impl TrieNode {
                 // that are longer than 32 bytes in length.
                 Header { list: true, payload_length }.encode(out);
                 stack.iter_mut().for_each(|node| {
-                    node.blind();
-                    node.encode_in_place(out);
+                    node.blind_with(hasher);
+                    node.encode_in_place_with(out, hasher);
                 });
             }
         }
@@ -406,10 +685,11 @@ impl TrieNode {
     /// ## Returns
     /// - `Ok(())` - The node was successfully collapsed
     /// - `Err(_)` - Could not collapse the node
-    fn collapse_if_possible<F: TrieProvider, H: TrieHinter>(
+    fn collapse_if_possible<F: TrieProvider, H: TrieHinter, T: TrieHasher>(
         &mut self,
         fetcher: &F,
         hinter: &H,
+        hasher: &T,
     ) -> TrieNodeResult<()> {
         match self {
             Self::Extension { prefix, node } => match node.as_mut() {
@@ -476,8 +756,8 @@ impl TrieNode {
                                 .hint_trie_node(*commitment)
                                 .map_err(|e| TrieNodeError::Provider(e.to_string()))?;
 
-                            non_empty_node.unblind(fetcher)?;
-                            self.collapse_if_possible(fetcher, hinter)?;
+                            non_empty_node.unblind_with(fetcher, hasher)?;
+                            self.collapse_if_possible(fetcher, hinter, hasher)?;
                         }
                         _ => {}
                     };
@@ -667,8 +947,9 @@ impl Decodable for TrieNode {
 mod test {
     use super::*;
     use crate::{
-        ordered_trie_with_encoder, test_util::TrieNodeProvider, NoopTrieHinter, NoopTrieProvider,
-        TrieNode,
+        ordered_trie_with_encoder,
+        test_util::{RecordingHasher, TrieNodeProvider},
+        NoopTrieHinter, NoopTrieProvider, TrieNode,
     };
     use alloc::{collections::BTreeMap, vec, vec::Vec};
     use alloy_primitives::{b256, bytes, hex, keccak256};
@@ -806,6 +1087,43 @@ mod test {
         assert_eq!(commitment, root);
     }
 
+    #[test]
+    fn test_max_depth_exceeded() {
+        // A chain of extension nodes with empty prefixes, which never consume any of the path.
+        let mut preimages = BTreeMap::default();
+        let mut commitment = B256::ZERO;
+        for _ in 0..64 {
+            let node = TrieNode::Extension {
+                prefix: Nibbles::default(),
+                node: Box::new(TrieNode::new_blinded(commitment)),
+            };
+            let mut rlp_buf = Vec::with_capacity(node.length());
+            node.encode(&mut rlp_buf);
+
+            commitment = keccak256(&rlp_buf);
+            preimages.insert(commitment, rlp_buf.into());
+        }
+        let fetcher = TrieNodeProvider::new(preimages);
+
+        let path = Nibbles::unpack([0u8; 32]);
+        assert_eq!(
+            TrieNode::new_blinded(commitment).open_with_max_depth(&path, &fetcher, 32).unwrap_err(),
+            TrieNodeError::MaxDepthExceeded
+        );
+        assert_eq!(
+            TrieNode::new_blinded(commitment)
+                .insert_with_max_depth(&path, Bytes::new(), &fetcher, 32)
+                .unwrap_err(),
+            TrieNodeError::MaxDepthExceeded
+        );
+        assert_eq!(
+            TrieNode::new_blinded(commitment)
+                .delete_with_max_depth(&path, &fetcher, &NoopTrieHinter, 32)
+                .unwrap_err(),
+            TrieNodeError::MaxDepthExceeded
+        );
+    }
+
     #[test]
     fn test_insert_static() {
         let mut node = TrieNode::Empty;
@@ -841,6 +1159,116 @@ mod test {
         assert_eq!(node, expected);
     }
 
+    #[test]
+    fn test_blind_with_custom_hasher() {
+        /// A [TrieHasher] that counts its invocations, and otherwise defers to keccak.
+        #[derive(Default)]
+        struct CountingHasher(core::cell::Cell<usize>);
+
+        impl TrieHasher for CountingHasher {
+            fn hash_node(&self, data: &[u8]) -> B256 {
+                self.0.set(self.0.get() + 1);
+                keccak256(data)
+            }
+        }
+
+        // A root branch with leaves under nibbles `0..=3`, and a branch under nibble `4` holding
+        // two more leaves.
+        let mut trie = TrieNode::Empty;
+        for first_byte in [0x00, 0x10, 0x20, 0x30, 0x40, 0x41] {
+            let mut key = B256::ZERO;
+            key[0] = first_byte;
+            let value = Bytes::from(vec![first_byte; 32]);
+            trie.insert(&Nibbles::unpack(key), value, &NoopTrieProvider).unwrap();
+        }
+        let mut expected = trie.clone();
+        expected.blind();
+
+        let hasher = CountingHasher::default();
+        trie.blind_with(&hasher);
+        assert_eq!(trie, expected);
+        // All six leaves, the inner branch, and the root branch are long enough to be blinded.
+        assert_eq!(hasher.0.get(), 8);
+
+        // A node that is already blinded is not hashed again.
+        let hashes = hasher.0.get();
+        trie.blind_with(&hasher);
+        assert_eq!(hasher.0.get(), hashes);
+    }
+
+    #[test]
+    fn test_open_with_custom_hasher() {
+        let values = [0x00, 0x10, 0x20, 0x30, 0x40, 0x41].map(|first_byte| {
+            let mut key = B256::ZERO;
+            key[0] = first_byte;
+            (Nibbles::unpack(key), Bytes::from(vec![first_byte; 32]))
+        });
+
+        let mut trie = TrieNode::Empty;
+        for (path, value) in values.iter().cloned() {
+            trie.insert(&path, value, &NoopTrieProvider).unwrap();
+        }
+
+        let hasher = RecordingHasher::default();
+        trie.blind_with(&hasher);
+        let fetcher = TrieNodeProvider::new(hasher.preimages.take());
+
+        for (path, mut value) in values {
+            assert_eq!(trie.open_with(&path, &fetcher, &hasher).unwrap(), Some(&mut value));
+        }
+    }
+
+    #[test]
+    fn test_insert_delete_with_custom_hasher() {
+        let values = [0x00, 0x10, 0x20, 0x30, 0x40, 0x41].map(|first_byte| {
+            let mut key = B256::ZERO;
+            key[0] = first_byte;
+            (Nibbles::unpack(key), Bytes::from(vec![first_byte; 32]))
+        });
+        let (inserted_path, inserted_value) = {
+            let mut key = B256::ZERO;
+            key[0] = 0x42;
+            (Nibbles::unpack(key), Bytes::from(vec![0x42; 32]))
+        };
+
+        // The expected trie has the first value deleted and the new value inserted.
+        let mut expected = TrieNode::Empty;
+        for (path, value) in values.iter().skip(1).cloned() {
+            expected.insert(&path, value, &NoopTrieProvider).unwrap();
+        }
+        expected.insert(&inserted_path, inserted_value.clone(), &NoopTrieProvider).unwrap();
+        expected.blind_with(&RecordingHasher::default());
+
+        let mut trie = TrieNode::Empty;
+        for (path, value) in values.iter().cloned() {
+            trie.insert(&path, value, &NoopTrieProvider).unwrap();
+        }
+
+        let hasher = RecordingHasher::default();
+        trie.blind_with(&hasher);
+        let fetcher = TrieNodeProvider::new(hasher.preimages.take());
+
+        trie.insert_with(&inserted_path, inserted_value, &fetcher, &hasher).unwrap();
+        trie.delete_with(&values[0].0, &fetcher, &NoopTrieHinter, &hasher).unwrap();
+        trie.blind_with(&hasher);
+
+        assert_eq!(trie.blinded_commitment(), expected.blinded_commitment());
+    }
+
+    #[test]
+    fn test_insert_with_custom_empty_root() {
+        let hasher = RecordingHasher::default();
+        let path = Nibbles::unpack(B256::ZERO);
+        let value = Bytes::from(vec![0xFF; 32]);
+
+        // The empty root of the custom hasher is only recognized when unblinding with it.
+        let mut trie = TrieNode::Blinded { commitment: hasher.empty_root() };
+        assert!(trie.clone().insert(&path, value.clone(), &NoopTrieProvider).is_err());
+
+        trie.insert_with(&path, value.clone(), &NoopTrieProvider, &hasher).unwrap();
+        assert_eq!(trie.open(&path, &NoopTrieProvider).unwrap(), Some(&mut value.clone()));
+    }
+
     proptest::proptest! {
         /// Differential test for inserting an arbitrary number of keys into an empty `TrieNode` / `HashBuilder`.
         #[test]
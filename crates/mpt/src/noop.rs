@@ -40,4 +40,12 @@ impl TrieHinter for NoopTrieHinter {
     ) -> Result<(), Self::Error> {
         Ok(())
     }
+
+    fn hint_ancestor_headers(
+        &self,
+        _head_hash: B256,
+        _block_number: u64,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
 }
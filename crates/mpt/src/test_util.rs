@@ -1,12 +1,13 @@
 //! Testing utilities for `kona-mpt`
 
-use crate::{ordered_trie_with_encoder, TrieNode, TrieProvider};
+use crate::{ordered_trie_with_encoder, TrieHasher, TrieNode, TrieProvider};
 use alloc::{collections::BTreeMap, vec::Vec};
 use alloy_consensus::{Receipt, ReceiptEnvelope, ReceiptWithBloom, TxEnvelope, TxType};
 use alloy_primitives::{keccak256, Bytes, Log, B256};
 use alloy_provider::{network::eip2718::Encodable2718, Provider, ProviderBuilder};
 use alloy_rlp::Decodable;
 use alloy_rpc_types::{BlockTransactions, BlockTransactionsKind};
+use core::cell::RefCell;
 use reqwest::Url;
 
 const RPC_URL: &str = "https://docs-demo.quiknode.pro/";
@@ -148,3 +149,18 @@ impl TrieProvider for TrieNodeProvider {
         .map_err(|_| TestTrieProviderError("failed to decode trie node"))
     }
 }
+
+/// A [TrieHasher] that commits to nodes with a domain-separated keccak, and records the preimage of
+/// every commitment it computes.
+#[derive(Debug, Default)]
+pub(crate) struct RecordingHasher {
+    pub(crate) preimages: RefCell<BTreeMap<B256, Bytes>>,
+}
+
+impl TrieHasher for RecordingHasher {
+    fn hash_node(&self, data: &[u8]) -> B256 {
+        let hash = keccak256([b"recording".as_slice(), data].concat());
+        self.preimages.borrow_mut().insert(hash, Bytes::copy_from_slice(data));
+        hash
+    }
+}
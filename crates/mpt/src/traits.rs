@@ -1,9 +1,10 @@
 //! Contains the [TrieProvider] trait for fetching trie node preimages, contract bytecode, and
-//! headers.
+//! headers, as well as the [TrieHasher] trait for computing trie node commitments.
 
 use crate::TrieNode;
 use alloc::string::ToString;
 use alloy_primitives::{Address, B256, U256};
+use alloy_rlp::EMPTY_STRING_CODE;
 use core::fmt::Display;
 
 /// The [TrieProvider] trait defines the synchronous interface for fetching trie node preimages.
@@ -65,4 +66,42 @@ pub trait TrieHinter {
         slot: U256,
         block_number: u64,
     ) -> Result<(), Self::Error>;
+
+    /// Hints the host to fetch the headers of the ancestors of the given block, back to the given
+    /// block number.
+    ///
+    /// ## Takes
+    /// - `head_hash` - The hash of the block whose ancestors' headers are to be fetched.
+    /// - `block_number` - The number of the oldest ancestor whose header is to be fetched.
+    ///
+    /// ## Returns
+    /// - Ok(()): If the hint was successful.
+    /// - Err(Self::Error): If the hint was unsuccessful.
+    fn hint_ancestor_headers(&self, head_hash: B256, block_number: u64) -> Result<(), Self::Error>;
+}
+
+/// The [TrieHasher] trait defines the hash function used to commit to trie nodes when they are
+/// blinded.
+///
+/// The default [KeccakTrieHasher] computes the [keccak256] commitments of the Ethereum Merkle
+/// Patricia Trie. Alternative implementations may offload hashing to the host, or experiment with
+/// alternative state commitment schemes.
+///
+/// [KeccakTrieHasher]: crate::KeccakTrieHasher
+/// [keccak256]: alloy_primitives::keccak256
+pub trait TrieHasher {
+    /// Computes the commitment to an RLP encoded trie node.
+    ///
+    /// ## Takes
+    /// - `data`: The RLP encoded trie node.
+    ///
+    /// ## Returns
+    /// - B256: The commitment to the trie node.
+    fn hash_node(&self, data: &[u8]) -> B256;
+
+    /// Returns the commitment to the empty trie, which is the commitment to the RLP encoded empty
+    /// string.
+    fn empty_root(&self) -> B256 {
+        self.hash_node(&[EMPTY_STRING_CODE])
+    }
 }
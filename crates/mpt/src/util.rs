@@ -1,6 +1,8 @@
 //! Utilities for `kona-mpt`
 
+use crate::{NoopTrieProvider, TrieHasher, TrieNode, TrieNodeResult};
 use alloc::vec::Vec;
+use alloy_primitives::B256;
 use alloy_rlp::{Buf, BufMut, Encodable, Header};
 use alloy_trie::{proof::ProofRetainer, HashBuilder, Nibbles};
 
@@ -39,6 +41,47 @@ where
     hb
 }
 
+/// Compute the root of an ordered trie of the collection of items with a custom encoder, with its
+/// nodes committed to by the given [TrieHasher].
+///
+/// ## Takes
+/// - `items` - The items in the list
+/// - `encode` - The encoder for a single item
+/// - `hasher` - The [TrieHasher] used to commit to the trie nodes
+///
+/// ## Returns
+/// - `Ok(B256)` - The root of the ordered trie
+/// - `Err(_)` - Could not insert an item into the trie
+pub fn ordered_trie_root_with_hasher<T, F, H>(
+    items: &[T],
+    mut encode: F,
+    hasher: &H,
+) -> TrieNodeResult<B256>
+where
+    F: FnMut(&T, &mut dyn BufMut),
+    H: TrieHasher,
+{
+    let mut trie = TrieNode::Empty;
+    let mut index_buffer = Vec::new();
+    for (index, item) in items.iter().enumerate() {
+        index_buffer.clear();
+        index.encode(&mut index_buffer);
+
+        let mut value_buffer = Vec::new();
+        encode(item, &mut value_buffer);
+
+        trie.insert(&Nibbles::unpack(&index_buffer), value_buffer.into(), &NoopTrieProvider)?;
+    }
+
+    if matches!(trie, TrieNode::Empty) {
+        return Ok(hasher.empty_root());
+    }
+
+    let mut rlp_buf = Vec::with_capacity(trie.length());
+    trie.encode_in_place_with(&mut rlp_buf, hasher);
+    Ok(hasher.hash_node(&rlp_buf))
+}
+
 /// Adjust the index of an item for rlp encoding.
 pub(crate) const fn adjust_index_for_rlp(i: usize, len: usize) -> usize {
     if i > 0x7f {
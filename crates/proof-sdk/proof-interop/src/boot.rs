@@ -1,10 +1,9 @@
 //! This module contains the prologue phase of the client program, pulling in the boot information
 //! through the `PreimageOracle` ABI as local keys.
 
-use crate::{HintType, PreState};
+use crate::{HintType, PreState, PreStatePreimage};
 use alloc::{string::ToString, vec::Vec};
 use alloy_primitives::{Bytes, B256, U256};
-use alloy_rlp::Decodable;
 use kona_preimage::{
     errors::PreimageOracleError, CommsClient, HintWriterClient, PreimageKey, PreimageKeyType,
     PreimageOracleClient,
@@ -89,8 +88,7 @@ impl BootInfo {
         );
 
         let agreed_pre_state =
-            PreState::decode(&mut read_raw_pre_state(oracle, l2_pre).await?.as_ref())
-                .map_err(OracleProviderError::Rlp)?;
+            read_raw_pre_state(oracle, l2_pre).await?.decode().map_err(OracleProviderError::Rlp)?;
 
         let chain_ids: Vec<_> = match agreed_pre_state {
             PreState::SuperRoot(ref super_root) => {
@@ -128,10 +126,26 @@ impl BootInfo {
         })
     }
 
+    /// Returns the [RollupConfig] for the L2 chain with the given chain ID.
+    pub fn rollup_config(&self, chain_id: u64) -> Option<&RollupConfig> {
+        self.rollup_configs.get(&chain_id)
+    }
+
     /// Returns the [RollupConfig] corresponding to the [PreState::active_l2_chain_id].
     pub fn active_rollup_config(&self) -> Option<RollupConfig> {
         let active_l2_chain_id = self.agreed_pre_state.active_l2_chain_id()?;
-        self.rollup_configs.get(&active_l2_chain_id).cloned()
+        self.rollup_config(active_l2_chain_id).cloned()
+    }
+
+    /// Returns the hash of the active L2 chain's genesis block, if the agreed pre-state is at the
+    /// chain's genesis timestamp.
+    ///
+    /// A fresh chain has no output root preimage for its genesis block, so the safe head is taken
+    /// from the [RollupConfig] directly.
+    pub fn genesis_safe_head_hash(&self) -> Option<B256> {
+        let active_l2_chain_id = self.agreed_pre_state.active_l2_chain_id()?;
+        let genesis = &self.rollup_config(active_l2_chain_id)?.genesis;
+        (genesis.l2_time == self.agreed_pre_state.timestamp()).then_some(genesis.l2.hash)
     }
 }
 
@@ -139,7 +153,7 @@ impl BootInfo {
 pub(crate) async fn read_raw_pre_state<O>(
     caching_oracle: &O,
     agreed_pre_state_commitment: B256,
-) -> Result<Bytes, OracleProviderError>
+) -> Result<PreStatePreimage, OracleProviderError>
 where
     O: CommsClient,
 {
@@ -152,11 +166,110 @@ where
         .await
         .map_err(OracleProviderError::Preimage)?;
 
-    if pre.is_empty() {
-        return Err(OracleProviderError::Preimage(PreimageOracleError::Other(
+    PreStatePreimage::try_from(Bytes::from(pre)).map_err(|_| {
+        OracleProviderError::Preimage(PreimageOracleError::Other(
             "Invalid pre-state preimage".to_string(),
-        )));
+        ))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloy_primitives::keccak256;
+    use alloy_rlp::Encodable;
+    use kona_interop::{OutputRootWithChain, SuperRoot};
+    use kona_proof::test_utils::TestOracle;
+
+    /// Constructs a [TestOracle] serving the boot inputs for the given [PreState] and any extra
+    /// preimages.
+    fn mock_boot_oracle(
+        pre_state: &PreState,
+        extra: impl IntoIterator<Item = (PreimageKey, Vec<u8>)>,
+    ) -> TestOracle {
+        let mut raw_pre_state = Vec::new();
+        pre_state.encode(&mut raw_pre_state);
+        let pre_state_commitment = keccak256(&raw_pre_state);
+
+        let preimages = [
+            (PreimageKey::new_local(L1_HEAD_KEY.to()), B256::ZERO.to_vec()),
+            (PreimageKey::new_local(L2_AGREED_PRE_STATE_KEY.to()), pre_state_commitment.to_vec()),
+            (PreimageKey::new_local(L2_CLAIMED_POST_STATE_KEY.to()), B256::ZERO.to_vec()),
+            (PreimageKey::new_local(L2_CLAIMED_TIMESTAMP_KEY.to()), 11u64.to_be_bytes().to_vec()),
+            (PreimageKey::new(*pre_state_commitment, PreimageKeyType::Keccak256), raw_pre_state),
+        ];
+        TestOracle::new(preimages.into_iter().chain(extra))
+    }
+
+    #[tokio::test]
+    async fn test_load_fetches_agreed_pre_state_once() {
+        let pre_state =
+            PreState::SuperRoot(SuperRoot::new(10, vec![OutputRootWithChain::new(10, B256::ZERO)]));
+        let oracle = mock_boot_oracle(&pre_state, []);
+
+        let boot = BootInfo::load(&oracle).await.unwrap();
+        assert_eq!(boot.agreed_pre_state, pre_state);
+
+        let pre_state_key =
+            PreimageKey::new(*boot.agreed_pre_state_commitment, PreimageKeyType::Keccak256);
+        assert_eq!(oracle.request_count(pre_state_key), 1);
     }
 
-    Ok(Bytes::from(pre))
+    #[tokio::test]
+    async fn test_load_per_chain_rollup_configs() {
+        let pre_state = PreState::SuperRoot(SuperRoot::new(
+            10,
+            vec![
+                OutputRootWithChain::new(900, B256::ZERO),
+                OutputRootWithChain::new(901, B256::ZERO),
+            ],
+        ));
+        let configs = [900, 901]
+            .into_iter()
+            .map(|chain_id| {
+                (chain_id, RollupConfig { l2_chain_id: chain_id, ..Default::default() })
+            })
+            .collect::<HashMap<_, _>>();
+        let oracle = mock_boot_oracle(
+            &pre_state,
+            [(
+                PreimageKey::new_local(L2_ROLLUP_CONFIG_KEY.to()),
+                serde_json::to_vec(&configs).unwrap(),
+            )],
+        );
+
+        let boot = BootInfo::load(&oracle).await.unwrap();
+        assert_eq!(boot.rollup_configs.len(), 2);
+        assert_eq!(boot.rollup_config(900).unwrap().l2_chain_id, 900);
+        assert_eq!(boot.rollup_config(901).unwrap().l2_chain_id, 901);
+        assert!(boot.rollup_config(902).is_none());
+        assert_eq!(boot.active_rollup_config().unwrap().l2_chain_id, 900);
+    }
+
+    #[tokio::test]
+    async fn test_genesis_safe_head_hash() {
+        let genesis_hash = B256::repeat_byte(0x11);
+        let mut config = RollupConfig { l2_chain_id: 900, ..Default::default() };
+        config.genesis.l2_time = 10;
+        config.genesis.l2.hash = genesis_hash;
+        let configs = [(900, config)].into_iter().collect::<HashMap<_, _>>();
+
+        // At the genesis timestamp, the safe head is the genesis block of the rollup config.
+        for (timestamp, expected) in [(10, Some(genesis_hash)), (11, None)] {
+            let pre_state = PreState::SuperRoot(SuperRoot::new(
+                timestamp,
+                vec![OutputRootWithChain::new(900, B256::ZERO)],
+            ));
+            let oracle = mock_boot_oracle(
+                &pre_state,
+                [(
+                    PreimageKey::new_local(L2_ROLLUP_CONFIG_KEY.to()),
+                    serde_json::to_vec(&configs).unwrap(),
+                )],
+            );
+
+            let boot = BootInfo::load(&oracle).await.unwrap();
+            assert_eq!(boot.genesis_safe_head_hash(), expected);
+        }
+    }
 }
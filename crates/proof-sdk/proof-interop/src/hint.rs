@@ -39,6 +39,9 @@ pub enum HintType {
     L2AccountStorageProof,
     /// A hint that specifies loading the payload witness for an optimistic block.
     L2BlockData,
+    /// A hint that specifies the headers of the ancestors of a layer 2 block, back to a given
+    /// block number.
+    L2AncestorHeaders,
 }
 
 impl HintType {
@@ -74,6 +77,7 @@ impl FromStr for HintType {
             "l2-account-proof" => Ok(Self::L2AccountProof),
             "l2-account-storage-proof" => Ok(Self::L2AccountStorageProof),
             "l2-block-data" => Ok(Self::L2BlockData),
+            "l2-ancestor-headers" => Ok(Self::L2AncestorHeaders),
             _ => Err(HintParsingError(value.to_string())),
         }
     }
@@ -97,6 +101,7 @@ impl From<HintType> for &str {
             HintType::L2AccountProof => "l2-account-proof",
             HintType::L2AccountStorageProof => "l2-account-storage-proof",
             HintType::L2BlockData => "l2-block-data",
+            HintType::L2AncestorHeaders => "l2-ancestor-headers",
         }
     }
 }
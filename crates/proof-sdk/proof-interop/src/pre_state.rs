@@ -5,6 +5,7 @@ use alloy_primitives::{b256, keccak256, Bytes, B256};
 use alloy_rlp::{Buf, Decodable, Encodable, Header, RlpDecodable, RlpEncodable};
 use kona_interop::{OutputRootWithChain, SuperRoot, SUPER_ROOT_VERSION};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 /// The current [TransitionState] encoding format version.
 pub(crate) const TRANSITION_STATE_VERSION: u8 = 255;
@@ -53,6 +54,14 @@ impl PreState {
         }
     }
 
+    /// Returns the timestamp of the [SuperRoot] that the [PreState] builds on.
+    pub const fn timestamp(&self) -> u64 {
+        match self {
+            Self::SuperRoot(super_root) => super_root.timestamp,
+            Self::TransitionState(transition_state) => transition_state.pre_state.timestamp,
+        }
+    }
+
     /// Transitions to the next state, appending the [OptimisticBlock] to the pending progress.
     pub fn transition(self, optimistic_block: Option<OptimisticBlock>) -> Option<Self> {
         match self {
@@ -69,21 +78,7 @@ impl PreState {
                     transition_state.pre_state.output_roots.len()
                 {
                     if transition_state.step == TRANSITION_STATE_MAX_STEPS {
-                        let super_root = SuperRoot::new(
-                            transition_state.pre_state.timestamp + 1,
-                            transition_state
-                                .pending_progress
-                                .iter()
-                                .zip(transition_state.pre_state.output_roots.iter())
-                                .map(|(optimistic_block, pre_state_output)| {
-                                    OutputRootWithChain::new(
-                                        pre_state_output.chain_id,
-                                        optimistic_block.output_root,
-                                    )
-                                })
-                                .collect(),
-                        );
-                        return Some(Self::SuperRoot(super_root));
+                        return transition_state.into_super_root().ok().map(Self::SuperRoot);
                     } else {
                         transition_state.step += 1;
                         return Some(Self::TransitionState(transition_state));
@@ -132,6 +127,40 @@ impl Decodable for PreState {
     }
 }
 
+/// The raw preimage of an agreed [PreState] commitment, as served by the preimage oracle.
+///
+/// A [PreStatePreimage] is never empty, so its leading version byte is always present.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PreStatePreimage(Bytes);
+
+impl PreStatePreimage {
+    /// Returns the version byte of the preimage, which determines the [PreState] variant.
+    pub fn version(&self) -> u8 {
+        self.0[0]
+    }
+
+    /// Decodes the preimage into a [PreState], dispatching on its version byte.
+    pub fn decode(&self) -> alloy_rlp::Result<PreState> {
+        PreState::decode(&mut self.0.as_ref())
+    }
+
+    /// Returns the raw bytes of the preimage.
+    pub const fn as_bytes(&self) -> &Bytes {
+        &self.0
+    }
+}
+
+impl TryFrom<Bytes> for PreStatePreimage {
+    type Error = alloy_rlp::Error;
+
+    fn try_from(raw: Bytes) -> Result<Self, Self::Error> {
+        if raw.is_empty() {
+            return Err(alloy_rlp::Error::UnexpectedLength);
+        }
+        Ok(Self(raw))
+    }
+}
+
 /// The [TransitionState] is a super-structure of the [SuperRoot] that represents the progress of a
 /// pending superchain state transition from one [SuperRoot] to the next.
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -169,6 +198,89 @@ impl TransitionState {
             self.pending_progress.length() +
             self.step.length()
     }
+
+    /// Checks that the pre-state, pending progress, and step of the [TransitionState] are
+    /// mutually consistent.
+    ///
+    /// Each step appends at most one [OptimisticBlock] to the pending progress, and steps only
+    /// advance without appending once every chain in the pre-state has an [OptimisticBlock].
+    pub fn validate(&self) -> Result<(), TransitionStateError> {
+        let pending = self.pending_progress.len() as u64;
+        let chains = self.pre_state.output_roots.len() as u64;
+
+        if pending > chains {
+            return Err(TransitionStateError::PendingProgressExceedsChains { pending, chains });
+        }
+        if self.step > TRANSITION_STATE_MAX_STEPS {
+            return Err(TransitionStateError::StepExceedsMax(self.step));
+        }
+        if self.step < pending || (pending < chains && self.step != pending) {
+            return Err(TransitionStateError::StepMismatch { step: self.step, pending });
+        }
+        Ok(())
+    }
+
+    /// Converts a [TransitionState] whose pending progress covers every chain in the pre-state
+    /// into the post-state [SuperRoot] at the next timestamp.
+    ///
+    /// Returns an error if the [TransitionState] is inconsistent, or if any chain in the pre-state
+    /// has not yet progressed.
+    pub fn into_super_root(self) -> Result<SuperRoot, TransitionStateError> {
+        self.validate()?;
+
+        let pending = self.pending_progress.len() as u64;
+        let chains = self.pre_state.output_roots.len() as u64;
+        if pending != chains {
+            return Err(TransitionStateError::IncompleteProgress { pending, chains });
+        }
+
+        Ok(SuperRoot::new(
+            self.pre_state.timestamp + 1,
+            self.pending_progress
+                .into_iter()
+                .zip(self.pre_state.output_roots)
+                .map(|(optimistic_block, pre_state_output)| {
+                    OutputRootWithChain::new(
+                        pre_state_output.chain_id,
+                        optimistic_block.output_root,
+                    )
+                })
+                .collect(),
+        ))
+    }
+}
+
+/// An error returned by [TransitionState::validate] and [TransitionState::into_super_root] when a
+/// [TransitionState] is inconsistent or incomplete.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum TransitionStateError {
+    /// The pending progress contains more blocks than there are chains in the pre-state.
+    #[error("Pending progress has {pending} blocks, but the pre-state has only {chains} chains")]
+    PendingProgressExceedsChains {
+        /// The number of blocks in the pending progress.
+        pending: u64,
+        /// The number of chains in the pre-state.
+        chains: u64,
+    },
+    /// The step exceeds [TRANSITION_STATE_MAX_STEPS].
+    #[error("Step {0} exceeds the maximum number of transition steps")]
+    StepExceedsMax(u64),
+    /// The step does not agree with the number of blocks in the pending progress.
+    #[error("Step {step} is inconsistent with {pending} blocks of pending progress")]
+    StepMismatch {
+        /// The step of the transition state.
+        step: u64,
+        /// The number of blocks in the pending progress.
+        pending: u64,
+    },
+    /// Not every chain in the pre-state has progressed.
+    #[error("Pending progress covers only {pending} of {chains} chains")]
+    IncompleteProgress {
+        /// The number of blocks in the pending progress.
+        pending: u64,
+        /// The number of chains in the pre-state.
+        chains: u64,
+    },
 }
 
 impl Encodable for TransitionState {
@@ -241,11 +353,14 @@ impl OptimisticBlock {
 
 #[cfg(test)]
 mod test {
-    use super::{OptimisticBlock, SuperRoot, TransitionState};
-    use alloy_primitives::B256;
+    use super::{
+        OptimisticBlock, PreState, PreStatePreimage, SuperRoot, TransitionState,
+        TransitionStateError, TRANSITION_STATE_MAX_STEPS, TRANSITION_STATE_VERSION,
+    };
+    use alloy_primitives::{keccak256, Bytes, B256, U256};
     use alloy_rlp::{Decodable, Encodable};
     use arbitrary::Arbitrary;
-    use kona_interop::OutputRootWithChain;
+    use kona_interop::{OutputRootWithChain, SUPER_ROOT_VERSION};
     use rand::Rng;
 
     #[test]
@@ -291,4 +406,140 @@ mod test {
         transition_state.encode(&mut rlp_buf);
         assert_eq!(transition_state, TransitionState::decode(&mut rlp_buf.as_slice()).unwrap());
     }
+
+    #[test]
+    fn test_pre_state_preimage_empty() {
+        assert_eq!(
+            PreStatePreimage::try_from(Bytes::new()),
+            Err(alloy_rlp::Error::UnexpectedLength)
+        );
+    }
+
+    #[test]
+    fn test_pre_state_preimage_super_root() {
+        let super_root = SuperRoot::new(10, vec![OutputRootWithChain::new(1, B256::ZERO)]);
+        let mut rlp_buf = Vec::new();
+        super_root.encode(&mut rlp_buf);
+
+        let preimage = PreStatePreimage::try_from(Bytes::from(rlp_buf)).unwrap();
+        assert_eq!(preimage.version(), SUPER_ROOT_VERSION);
+        assert_eq!(preimage.decode().unwrap(), PreState::SuperRoot(super_root));
+    }
+
+    #[test]
+    fn test_pre_state_preimage_transition_state() {
+        let transition_state = TransitionState::new(
+            SuperRoot::new(10, vec![OutputRootWithChain::new(1, B256::ZERO)]),
+            vec![OptimisticBlock::new(B256::ZERO, B256::ZERO)],
+            1,
+        );
+        let mut rlp_buf = Vec::new();
+        transition_state.encode(&mut rlp_buf);
+
+        let preimage = PreStatePreimage::try_from(Bytes::from(rlp_buf)).unwrap();
+        assert_eq!(preimage.version(), TRANSITION_STATE_VERSION);
+        assert_eq!(preimage.decode().unwrap(), PreState::TransitionState(transition_state));
+    }
+
+    #[test]
+    fn test_pre_state_preimage_invalid_version() {
+        let preimage = PreStatePreimage::try_from(Bytes::from_static(&[0xAB])).unwrap();
+        assert_eq!(preimage.version(), 0xAB);
+        assert!(preimage.decode().is_err());
+    }
+
+    #[test]
+    fn test_transition_state_validate_consistent() {
+        let super_root = SuperRoot::new(
+            10,
+            vec![OutputRootWithChain::new(1, B256::ZERO), OutputRootWithChain::new(2, B256::ZERO)],
+        );
+
+        let mut pre_state = PreState::SuperRoot(super_root);
+        for _ in 0..TRANSITION_STATE_MAX_STEPS {
+            pre_state = pre_state.transition(Some(OptimisticBlock::default())).unwrap();
+            let PreState::TransitionState(transition_state) = &pre_state else {
+                panic!("expected transition state");
+            };
+            assert_eq!(transition_state.validate(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_transition_state_validate_pending_progress_exceeds_chains() {
+        let transition_state = TransitionState::new(
+            SuperRoot::new(10, vec![OutputRootWithChain::new(1, B256::ZERO)]),
+            vec![OptimisticBlock::default(), OptimisticBlock::default()],
+            2,
+        );
+        assert_eq!(
+            transition_state.validate(),
+            Err(TransitionStateError::PendingProgressExceedsChains { pending: 2, chains: 1 })
+        );
+    }
+
+    #[test]
+    fn test_transition_state_validate_step() {
+        let super_root = SuperRoot::new(
+            10,
+            vec![OutputRootWithChain::new(1, B256::ZERO), OutputRootWithChain::new(2, B256::ZERO)],
+        );
+
+        let transition_state =
+            TransitionState::new(super_root.clone(), vec![OptimisticBlock::default()], 2);
+        assert_eq!(
+            transition_state.validate(),
+            Err(TransitionStateError::StepMismatch { step: 2, pending: 1 })
+        );
+
+        let transition_state = TransitionState::new(
+            super_root,
+            vec![OptimisticBlock::default(), OptimisticBlock::default()],
+            TRANSITION_STATE_MAX_STEPS + 1,
+        );
+        assert_eq!(
+            transition_state.validate(),
+            Err(TransitionStateError::StepExceedsMax(TRANSITION_STATE_MAX_STEPS + 1))
+        );
+    }
+
+    #[test]
+    fn test_transition_state_into_super_root() {
+        let super_root = SuperRoot::new(
+            10,
+            vec![OutputRootWithChain::new(1, B256::ZERO), OutputRootWithChain::new(2, B256::ZERO)],
+        );
+        let pending_progress = vec![
+            OptimisticBlock::new(B256::repeat_byte(0x01), B256::repeat_byte(0xAA)),
+            OptimisticBlock::new(B256::repeat_byte(0x02), B256::repeat_byte(0xBB)),
+        ];
+
+        let transition_state = TransitionState::new(super_root.clone(), pending_progress, 2);
+        let post_state = transition_state.into_super_root().unwrap();
+        assert_eq!(
+            post_state,
+            SuperRoot::new(
+                11,
+                vec![
+                    OutputRootWithChain::new(1, B256::repeat_byte(0xAA)),
+                    OutputRootWithChain::new(2, B256::repeat_byte(0xBB)),
+                ],
+            )
+        );
+
+        let mut expected = vec![SUPER_ROOT_VERSION];
+        expected.extend_from_slice(&11u64.to_be_bytes());
+        for (chain_id, output_root) in [(1u64, 0xAA), (2, 0xBB)] {
+            expected.extend_from_slice(&U256::from(chain_id).to_be_bytes::<32>());
+            expected.extend_from_slice(B256::repeat_byte(output_root).as_slice());
+        }
+        assert_eq!(post_state.hash(), keccak256(expected));
+
+        let transition_state =
+            TransitionState::new(super_root, vec![OptimisticBlock::default()], 1);
+        assert_eq!(
+            transition_state.into_super_root(),
+            Err(TransitionStateError::IncompleteProgress { pending: 1, chains: 2 })
+        );
+    }
 }
@@ -1,13 +1,22 @@
 //! This module contains the prologue phase of the client program, pulling in the boot information
 //! through the `PreimageOracle` ABI as local keys.
 
-use crate::errors::OracleProviderError;
-use alloy_primitives::{B256, U256};
+use crate::errors::{GenesisSystemConfigError, OracleProviderError};
+use alloc::vec::Vec;
+use alloy_primitives::{Address, B256, U256};
 use kona_preimage::{PreimageKey, PreimageOracleClient};
 use maili_genesis::RollupConfig;
 use maili_registry::ROLLUP_CONFIGS;
 use serde::{Deserialize, Serialize};
 
+/// The minimum gas limit of the genesis system config, equal to the `SystemConfig` contract's
+/// minimum under the default resource config: the maximum resource limit of 20M gas plus the
+/// 1M gas reserved for system transactions.
+pub const MIN_GENESIS_GAS_LIMIT: u64 = 21_000_000;
+
+/// The maximum gas limit of the genesis system config, as enforced by the `SystemConfig` contract.
+pub const MAX_GENESIS_GAS_LIMIT: u64 = 200_000_000;
+
 /// The local key ident for the L1 head hash.
 pub const L1_HEAD_KEY: U256 = U256::from_be_slice(&[1]);
 
@@ -26,6 +35,43 @@ pub const L2_CHAIN_ID_KEY: U256 = U256::from_be_slice(&[5]);
 /// The local key ident for the L2 rollup config.
 pub const L2_ROLLUP_CONFIG_KEY: U256 = U256::from_be_slice(&[6]);
 
+/// Top-level fields of older serialized [RollupConfig]s that have since moved into the genesis
+/// system config, paired with their key within the system config.
+const LEGACY_SYSTEM_CONFIG_FIELDS: [(&str, &str); 2] =
+    [("l1_fee_overhead", "overhead"), ("l1_fee_scalar", "scalar")];
+
+/// Deserializes a [RollupConfig] from JSON, tolerating configs serialized by older hosts.
+///
+/// Hardfork timestamps missing from the config default to `None`. The legacy top-level
+/// `l1_fee_overhead` and `l1_fee_scalar` fields are moved into the genesis system config, unless
+/// it already specifies them, and are otherwise dropped.
+pub fn decode_rollup_config(ser_cfg: &[u8]) -> Result<RollupConfig, serde_json::Error> {
+    let mut value: serde_json::Value = serde_json::from_slice(ser_cfg)?;
+
+    if let Some(config) = value.as_object_mut() {
+        for (legacy_key, key) in LEGACY_SYSTEM_CONFIG_FIELDS {
+            let Some(field) = config.remove(legacy_key) else {
+                continue;
+            };
+
+            let system_config = config
+                .get_mut("genesis")
+                .and_then(|genesis| {
+                    genesis
+                        .as_object_mut()?
+                        .iter_mut()
+                        .find(|(k, _)| *k == "system_config" || *k == "systemConfig")
+                })
+                .and_then(|(_, system_config)| system_config.as_object_mut());
+            if let Some(system_config) = system_config {
+                system_config.entry(key).or_insert(field);
+            }
+        }
+    }
+
+    serde_json::from_value(value)
+}
+
 /// The boot information for the client program.
 ///
 /// **Verified inputs:**
@@ -54,15 +100,35 @@ pub struct BootInfo {
 }
 
 impl BootInfo {
-    /// Load the boot information from the preimage oracle.
+    /// Load the boot information from the preimage oracle, and validate the loaded rollup config.
     ///
     /// ## Takes
     /// - `oracle`: The preimage oracle reader.
     ///
     /// ## Returns
     /// - `Ok(BootInfo)`: The boot information.
-    /// - `Err(_)`: Failed to load the boot information.
+    /// - `Err(_)`: Failed to load or validate the boot information.
     pub async fn load<O>(oracle: &O) -> Result<Self, OracleProviderError>
+    where
+        O: PreimageOracleClient + Send,
+    {
+        let boot = Self::load_unchecked(oracle).await?;
+        boot.validate()?;
+        Ok(boot)
+    }
+
+    /// Load the boot information from the preimage oracle, without validating the rollup config.
+    ///
+    /// This should only be used in test modes, where the rollup config is intentionally
+    /// incomplete (e.g. a zero batch inbox address).
+    ///
+    /// ## Takes
+    /// - `oracle`: The preimage oracle reader.
+    ///
+    /// ## Returns
+    /// - `Ok(BootInfo)`: The boot information.
+    /// - `Err(_)`: Failed to load the boot information.
+    pub async fn load_unchecked<O>(oracle: &O) -> Result<Self, OracleProviderError>
     where
         O: PreimageOracleClient + Send,
     {
@@ -117,7 +183,7 @@ impl BootInfo {
                 .get(PreimageKey::new_local(L2_ROLLUP_CONFIG_KEY.to()))
                 .await
                 .map_err(OracleProviderError::Preimage)?;
-            serde_json::from_slice(&ser_cfg).map_err(OracleProviderError::Serde)?
+            decode_rollup_config(&ser_cfg).map_err(OracleProviderError::Serde)?
         };
 
         Ok(Self {
@@ -129,4 +195,238 @@ impl BootInfo {
             rollup_config,
         })
     }
+
+    /// Returns the local preimages read by [BootInfo::load], paired with their [PreimageKey]s.
+    ///
+    /// Hosts may serve the boot information by iterating over these pairs, rather than encoding
+    /// each local key individually.
+    pub fn local_key_value_pairs(&self) -> Vec<(PreimageKey, Vec<u8>)> {
+        let rollup_config =
+            serde_json::to_vec(&self.rollup_config).expect("RollupConfig serialization failed");
+        [
+            (L1_HEAD_KEY, self.l1_head.to_vec()),
+            (L2_OUTPUT_ROOT_KEY, self.agreed_l2_output_root.to_vec()),
+            (L2_CLAIM_KEY, self.claimed_l2_output_root.to_vec()),
+            (L2_CLAIM_BLOCK_NUMBER_KEY, self.claimed_l2_block_number.to_be_bytes().to_vec()),
+            (L2_CHAIN_ID_KEY, self.chain_id.to_be_bytes().to_vec()),
+            (L2_ROLLUP_CONFIG_KEY, rollup_config),
+        ]
+        .into_iter()
+        .map(|(key, value)| (PreimageKey::new_local(key.to()), value))
+        .collect()
+    }
+
+    /// Validates the rollup config within the boot information.
+    ///
+    /// ## Returns
+    /// - `Ok(())`: The rollup config is valid.
+    /// - `Err(_)`: The rollup config is misconfigured.
+    pub fn validate(&self) -> Result<(), OracleProviderError> {
+        // A zero batch inbox address would silently match no batcher transactions.
+        if self.rollup_config.batch_inbox_address == Address::ZERO {
+            return Err(OracleProviderError::ZeroBatchInboxAddress);
+        }
+
+        self.validate_genesis_system_config()?;
+        Ok(())
+    }
+
+    /// Validates the genesis system config of the rollup config within the boot information.
+    ///
+    /// Only fields that make derivation or execution impossible are rejected. A missing system
+    /// config, or a gas limit outside of the `SystemConfig` contract's bounds, is logged as a
+    /// warning, as custom rollup configs may legitimately deviate from them.
+    ///
+    /// ## Returns
+    /// - `Ok(())`: The genesis system config is valid.
+    /// - `Err(_)`: The first invalid field of the genesis system config.
+    fn validate_genesis_system_config(&self) -> Result<(), GenesisSystemConfigError> {
+        let Some(system_config) = self.rollup_config.genesis.system_config.as_ref() else {
+            warn!(target: "boot-loader", "Rollup config has no genesis system config");
+            return Ok(());
+        };
+
+        // A zero batcher address would silently reject all batcher transactions.
+        if system_config.batcher_address == Address::ZERO {
+            return Err(GenesisSystemConfigError::ZeroBatcherAddress);
+        }
+
+        // A zero gas limit produces empty blocks, and divides by zero in the base fee computation.
+        let gas_limit = system_config.gas_limit;
+        if gas_limit == 0 {
+            return Err(GenesisSystemConfigError::ZeroGasLimit);
+        }
+        if !(MIN_GENESIS_GAS_LIMIT..=MAX_GENESIS_GAS_LIMIT).contains(&gas_limit) {
+            warn!(
+                target: "boot-loader",
+                "Genesis gas limit {} is outside of the protocol bounds [{}, {}]",
+                gas_limit,
+                MIN_GENESIS_GAS_LIMIT,
+                MAX_GENESIS_GAS_LIMIT
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{block_on, test_utils::TestOracle};
+    use alloc::string::ToString;
+    use alloy_primitives::address;
+    use maili_genesis::SystemConfig;
+
+    fn mock_boot_info(batch_inbox_address: Address) -> BootInfo {
+        let mut rollup_config = RollupConfig { batch_inbox_address, ..Default::default() };
+        rollup_config.genesis.system_config = Some(SystemConfig {
+            batcher_address: address!("6887246668a3b87f54deb3b94ba47a6f63f32985"),
+            gas_limit: 30_000_000,
+            ..Default::default()
+        });
+        BootInfo {
+            l1_head: B256::ZERO,
+            agreed_l2_output_root: B256::ZERO,
+            claimed_l2_output_root: B256::ZERO,
+            claimed_l2_block_number: 0,
+            chain_id: 0,
+            rollup_config,
+        }
+    }
+
+    #[test]
+    fn test_local_key_value_pairs_roundtrip() {
+        let mut boot = mock_boot_info(address!("ff00000000000000000000000000000000000010"));
+        boot.l1_head = B256::repeat_byte(0x01);
+        boot.agreed_l2_output_root = B256::repeat_byte(0x02);
+        boot.claimed_l2_output_root = B256::repeat_byte(0x03);
+        boot.claimed_l2_block_number = 42;
+        // A chain ID absent from the registry, so the rollup config is loaded from the oracle.
+        boot.chain_id = u64::MAX;
+        boot.rollup_config.l2_chain_id = u64::MAX;
+
+        let pairs = boot.local_key_value_pairs();
+        assert_eq!(pairs.len(), 6);
+        let oracle = TestOracle::new(pairs);
+        assert_eq!(block_on(BootInfo::load(&oracle)).unwrap(), boot);
+    }
+
+    #[test]
+    fn test_validate_zero_batch_inbox() {
+        let boot = mock_boot_info(Address::ZERO);
+        assert!(matches!(boot.validate(), Err(OracleProviderError::ZeroBatchInboxAddress)));
+    }
+
+    #[test]
+    fn test_validate_batch_inbox() {
+        let boot = mock_boot_info(address!("ff00000000000000000000000000000000000010"));
+        assert!(boot.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_genesis_system_config() {
+        let mut boot = mock_boot_info(address!("ff00000000000000000000000000000000000010"));
+        let set_gas_limit = |boot: &mut BootInfo, gas_limit| {
+            boot.rollup_config.genesis.system_config.as_mut().unwrap().gas_limit = gas_limit;
+        };
+
+        set_gas_limit(&mut boot, 0);
+        assert!(matches!(
+            boot.validate(),
+            Err(OracleProviderError::InvalidGenesisSystemConfig(
+                GenesisSystemConfigError::ZeroGasLimit
+            ))
+        ));
+
+        // Gas limits outside of the protocol bounds are only warned about.
+        set_gas_limit(&mut boot, 1_000);
+        assert!(boot.validate().is_ok());
+        set_gas_limit(&mut boot, u64::MAX);
+        assert!(boot.validate().is_ok());
+
+        set_gas_limit(&mut boot, 60_000_000);
+        assert!(boot.validate().is_ok());
+
+        boot.rollup_config.genesis.system_config.as_mut().unwrap().batcher_address = Address::ZERO;
+        assert!(matches!(
+            boot.validate(),
+            Err(OracleProviderError::InvalidGenesisSystemConfig(
+                GenesisSystemConfigError::ZeroBatcherAddress
+            ))
+        ));
+
+        boot.rollup_config.genesis.system_config = None;
+        assert!(boot.validate().is_ok());
+    }
+
+    #[test]
+    fn test_decode_rollup_config() {
+        let config = RollupConfig {
+            l2_chain_id: 10,
+            batch_inbox_address: address!("ff00000000000000000000000000000000000010"),
+            regolith_time: Some(0),
+            canyon_time: Some(10),
+            ..Default::default()
+        };
+        let ser_cfg = serde_json::to_vec(&config).unwrap();
+        assert_eq!(decode_rollup_config(&ser_cfg).unwrap(), config);
+    }
+
+    #[test]
+    fn test_decode_legacy_rollup_config() {
+        let config = RollupConfig {
+            l2_chain_id: 10,
+            batch_inbox_address: address!("ff00000000000000000000000000000000000010"),
+            regolith_time: Some(0),
+            ..Default::default()
+        };
+        let mut legacy = serde_json::to_value(&config).unwrap();
+        let legacy_obj = legacy.as_object_mut().unwrap();
+
+        // Older configs predate the newer hardforks and blob fields entirely.
+        for key in ["isthmus_time", "interop_time", "blobs_enabled_l1_timestamp"] {
+            legacy_obj.remove(key);
+        }
+
+        // Older configs carried the L1 fee parameters at the top level.
+        legacy_obj.insert("l1_fee_overhead".to_string(), serde_json::json!("0xbc"));
+        legacy_obj.insert("l1_fee_scalar".to_string(), serde_json::json!("0xa6fe0"));
+
+        let decoded = decode_rollup_config(&serde_json::to_vec(&legacy).unwrap()).unwrap();
+        assert_eq!(decoded, config);
+        assert_eq!(decoded.isthmus_time, None);
+        assert_eq!(decoded.interop_time, None);
+        assert_eq!(decoded.blobs_enabled_l1_timestamp, None);
+    }
+
+    #[test]
+    fn test_decode_legacy_rollup_config_moves_fee_fields() {
+        let system_config = SystemConfig {
+            overhead: U256::from(0xbc),
+            scalar: U256::from(0xa6fe0),
+            ..Default::default()
+        };
+        let mut config = RollupConfig::default();
+        config.genesis.system_config = Some(system_config);
+
+        // Serialize the config with the L1 fee parameters at the top level, as older hosts did.
+        let mut legacy = serde_json::to_value(&config).unwrap();
+        let legacy_system_config = legacy["genesis"]
+            .as_object_mut()
+            .unwrap()
+            .iter_mut()
+            .find(|(k, _)| *k == "system_config" || *k == "systemConfig")
+            .unwrap()
+            .1
+            .as_object_mut()
+            .unwrap();
+        let overhead = legacy_system_config.remove("overhead").unwrap();
+        let scalar = legacy_system_config.remove("scalar").unwrap();
+        legacy["l1_fee_overhead"] = overhead;
+        legacy["l1_fee_scalar"] = scalar;
+
+        let decoded = decode_rollup_config(&serde_json::to_vec(&legacy).unwrap()).unwrap();
+        assert_eq!(decoded, config);
+    }
 }
@@ -4,7 +4,7 @@
 //! [OracleReader]: kona_preimage::OracleReader
 //! [HintWriter]: kona_preimage::HintWriter
 
-use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use alloc::{boxed::Box, collections::BTreeMap, sync::Arc, vec::Vec};
 use async_trait::async_trait;
 use core::num::NonZeroUsize;
 use kona_preimage::{
@@ -27,6 +27,9 @@ where
 {
     /// The spin-locked cache that stores the responses from the oracle.
     cache: Arc<Mutex<LruCache<PreimageKey, Vec<u8>>>>,
+    /// The spin-locked store of pinned responses, which are never evicted. A pinned key maps to
+    /// `None` until its preimage has been fetched.
+    pinned: Arc<Mutex<BTreeMap<PreimageKey, Option<Vec<u8>>>>>,
     /// Oracle reader type.
     oracle_reader: OR,
     /// Hint writer type.
@@ -47,10 +50,23 @@ where
             cache: Arc::new(Mutex::new(LruCache::new(
                 NonZeroUsize::new(cache_size).expect("N must be greater than 0"),
             ))),
+            pinned: Default::default(),
             oracle_reader,
             hint_writer,
         }
     }
+
+    /// Pins the preimage of `key`, so that it is never evicted from the cache once fetched.
+    /// Pinned preimages do not count towards the cache size, and are retained when the cache is
+    /// flushed.
+    pub fn pin(&self, key: PreimageKey) {
+        let mut pinned_lock = self.pinned.lock();
+        if pinned_lock.contains_key(&key) {
+            return;
+        }
+        let value = self.cache.lock().pop(&key);
+        pinned_lock.insert(key, value);
+    }
 }
 
 /// A trait that provides a method to flush a cache.
@@ -64,7 +80,7 @@ where
     OR: PreimageOracleClient,
     HW: HintWriterClient,
 {
-    /// Flushes the cache, removing all entries.
+    /// Flushes the cache, removing all entries that are not pinned.
     fn flush(&self) {
         self.cache.lock().clear();
     }
@@ -77,6 +93,17 @@ where
     HW: HintWriterClient + Sync,
 {
     async fn get(&self, key: PreimageKey) -> PreimageOracleResult<Vec<u8>> {
+        let mut pinned_lock = self.pinned.lock();
+        if let Some(pinned) = pinned_lock.get_mut(&key) {
+            if let Some(value) = pinned {
+                return Ok(value.clone());
+            }
+            let value = self.oracle_reader.get(key).await?;
+            *pinned = Some(value.clone());
+            return Ok(value);
+        }
+        drop(pinned_lock);
+
         let mut cache_lock = self.cache.lock();
         if let Some(value) = cache_lock.get(&key) {
             Ok(value.clone())
@@ -88,6 +115,20 @@ where
     }
 
     async fn get_exact(&self, key: PreimageKey, buf: &mut [u8]) -> PreimageOracleResult<()> {
+        let mut pinned_lock = self.pinned.lock();
+        if let Some(pinned) = pinned_lock.get_mut(&key) {
+            if let Some(value) = pinned {
+                // SAFETY: The value never enters the cache unless the preimage length matches the
+                // buffer length, due to the checks in the OracleReader.
+                buf.copy_from_slice(value.as_slice());
+            } else {
+                self.oracle_reader.get_exact(key, buf).await?;
+                *pinned = Some(buf.to_vec());
+            }
+            return Ok(());
+        }
+        drop(pinned_lock);
+
         let mut cache_lock = self.cache.lock();
         if let Some(value) = cache_lock.get(&key) {
             // SAFETY: The value never enters the cache unless the preimage length matches the
@@ -112,3 +153,65 @@ where
         self.hint_writer.write(hint).await
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{block_on, test_utils::TestOracle};
+
+    /// Constructs a [TestOracle] serving each of the first 256 local keys' value as its preimage.
+    fn mock_oracle() -> TestOracle {
+        TestOracle::new((0..256).map(|i| {
+            let key = PreimageKey::new_local(i);
+            (key, key.key_value().to_be_bytes::<32>().to_vec())
+        }))
+    }
+
+    #[test]
+    fn test_pinned_key_not_evicted() {
+        const CACHE_SIZE: usize = 4;
+
+        let mock = mock_oracle();
+        let oracle = CachingOracle::new(CACHE_SIZE, mock.clone(), mock.clone());
+
+        let pinned_key = PreimageKey::new_local(1);
+        let unpinned_key = PreimageKey::new_local(2);
+        oracle.pin(pinned_key);
+        block_on(async {
+            oracle.get(pinned_key).await.unwrap();
+            oracle.get(unpinned_key).await.unwrap();
+
+            // Flood the cache past its size.
+            for i in 0..(CACHE_SIZE as u64 * 2) {
+                oracle.get(PreimageKey::new_local(100 + i)).await.unwrap();
+            }
+            oracle.flush();
+
+            let mut buf = [0u8; 32];
+            oracle.get_exact(pinned_key, &mut buf).await.unwrap();
+            assert_eq!(buf.as_slice(), pinned_key.key_value().to_be_bytes::<32>().as_slice());
+            oracle.get(unpinned_key).await.unwrap();
+        });
+
+        // The pinned key was only fetched once, while the unpinned key was evicted and re-fetched.
+        assert_eq!(mock.request_count(pinned_key), 1);
+        assert_eq!(mock.request_count(unpinned_key), 2);
+    }
+
+    #[test]
+    fn test_pin_cached_key() {
+        let mock = mock_oracle();
+        let oracle = CachingOracle::new(1, mock.clone(), mock.clone());
+
+        let key = PreimageKey::new_local(1);
+        block_on(async {
+            oracle.get(key).await.unwrap();
+            oracle.pin(key);
+            oracle.get(PreimageKey::new_local(2)).await.unwrap();
+            oracle.get(key).await.unwrap();
+        });
+
+        // The cached preimage was moved into the pinned store when the key was pinned.
+        assert_eq!(mock.request_count(key), 1);
+    }
+}
@@ -13,6 +13,12 @@ pub enum OracleProviderError {
     /// Requested block number is past the chain head.
     #[error("Block number ({0}) past chain head ({_1})")]
     BlockNumberPastHead(u64, u64),
+    /// The L1 walk back required to establish the pipeline cursor is too deep.
+    #[error("L1 walk back of {0} blocks exceeds the maximum of {1}")]
+    WalkBackTooDeep(u64, u64),
+    /// The L2 safe head is implausibly far behind the claimed L2 block.
+    #[error("Safe head ({0}) is more than {2} blocks behind the claimed block ({1})")]
+    StaleSafeHead(u64, u64, u64),
     /// Preimage oracle error.
     #[error("Preimage oracle error: {0}")]
     Preimage(#[from] PreimageOracleError),
@@ -37,6 +43,12 @@ pub enum OracleProviderError {
     /// Unknown Chain ID
     #[error("Unknown chain ID: {0}")]
     UnknownChainId(u64),
+    /// The rollup config's batch inbox address is zero.
+    #[error("Batch inbox address must be non-zero")]
+    ZeroBatchInboxAddress,
+    /// The rollup config's genesis system config is invalid.
+    #[error("Invalid genesis system config: {0}")]
+    InvalidGenesisSystemConfig(#[from] GenesisSystemConfigError),
 }
 
 impl From<OracleProviderError> for PipelineErrorKind {
@@ -48,6 +60,17 @@ impl From<OracleProviderError> for PipelineErrorKind {
     }
 }
 
+/// An invalid field of the genesis system config in a rollup config.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GenesisSystemConfigError {
+    /// The batcher address is zero.
+    #[error("Batcher address must be non-zero")]
+    ZeroBatcherAddress,
+    /// The gas limit is zero.
+    #[error("Gas limit must be non-zero")]
+    ZeroGasLimit,
+}
+
 /// Error parsing a hint.
 #[derive(Error, Debug)]
 #[error("Hint parsing error: {_0}")]
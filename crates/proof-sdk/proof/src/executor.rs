@@ -1,8 +1,8 @@
 //! An executor constructor.
 
-use alloc::boxed::Box;
+use alloc::{boxed::Box, vec::Vec};
 use alloy_consensus::{Header, Sealed};
-use alloy_primitives::B256;
+use alloy_primitives::{Address, B256};
 use async_trait::async_trait;
 use kona_driver::Executor;
 use kona_executor::{
@@ -27,6 +27,8 @@ where
     trie_hinter: H,
     /// The handle register for the executor.
     handle_register: Option<KonaHandleRegister<P, H>>,
+    /// The addresses whose account proofs are hinted before executing a payload, if overridden.
+    prewarm_addresses: Option<Vec<Address>>,
     /// The executor.
     inner: Option<StatelessL2BlockExecutor<'a, P, H>>,
 }
@@ -44,7 +46,21 @@ where
         handle_register: Option<KonaHandleRegister<P, H>>,
         inner: Option<StatelessL2BlockExecutor<'a, P, H>>,
     ) -> Self {
-        Self { rollup_config, trie_provider, trie_hinter, handle_register, inner }
+        Self {
+            rollup_config,
+            trie_provider,
+            trie_hinter,
+            handle_register,
+            prewarm_addresses: None,
+            inner,
+        }
+    }
+
+    /// Overrides the addresses whose account proofs are hinted before executing a payload. By
+    /// default, the executor prewarms the standard predeploys.
+    pub fn with_prewarm_addresses(mut self, prewarm_addresses: Vec<Address>) -> Self {
+        self.prewarm_addresses = Some(prewarm_addresses);
+        self
     }
 }
 
@@ -77,6 +93,9 @@ where
         if let Some(register) = self.handle_register {
             builder = builder.with_handle_register(register);
         }
+        if let Some(prewarm_addresses) = self.prewarm_addresses.clone() {
+            builder = builder.with_prewarm_addresses(prewarm_addresses);
+        }
         self.inner = Some(builder.build());
     }
 
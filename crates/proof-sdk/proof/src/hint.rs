@@ -111,6 +111,9 @@ pub enum HintType {
     /// A hint that specifies bulk storage of all the code, state and keys generated by an
     /// execution witness.
     L2PayloadWitness,
+    /// A hint that specifies the headers of the ancestors of a layer 2 block, back to a given
+    /// block number.
+    L2AncestorHeaders,
 }
 
 impl HintType {
@@ -144,6 +147,7 @@ impl FromStr for HintType {
             "l2-account-proof" => Ok(Self::L2AccountProof),
             "l2-account-storage-proof" => Ok(Self::L2AccountStorageProof),
             "l2-payload-witness" => Ok(Self::L2PayloadWitness),
+            "l2-ancestor-headers" => Ok(Self::L2AncestorHeaders),
             _ => Err(HintParsingError(value.to_string())),
         }
     }
@@ -165,6 +169,7 @@ impl From<HintType> for &str {
             HintType::L2AccountProof => "l2-account-proof",
             HintType::L2AccountStorageProof => "l2-account-storage-proof",
             HintType::L2PayloadWitness => "l2-payload-witness",
+            HintType::L2AncestorHeaders => "l2-ancestor-headers",
         }
     }
 }
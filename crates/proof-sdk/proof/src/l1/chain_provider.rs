@@ -11,6 +11,10 @@ use kona_derive::traits::ChainProvider;
 use kona_mpt::{OrderedListWalker, TrieNode, TrieProvider};
 use kona_preimage::{CommsClient, PreimageKey, PreimageKeyType};
 use maili_protocol::BlockInfo;
+use spin::Mutex;
+
+/// The receipts of an L1 block, keyed by its hash.
+type CachedReceipts = Option<(B256, Vec<Receipt>)>;
 
 /// The oracle-backed L1 chain provider for the client program.
 #[derive(Debug, Clone)]
@@ -19,12 +23,16 @@ pub struct OracleL1ChainProvider<T: CommsClient> {
     pub l1_head: B256,
     /// The preimage oracle client.
     pub oracle: Arc<T>,
+    /// The receipts of the most recently fetched L1 block, keyed by its hash. Shared between
+    /// clones of the provider, so that the stages of the pipeline entering the same L1 origin only
+    /// fetch its receipts once.
+    receipts_cache: Arc<Mutex<CachedReceipts>>,
 }
 
 impl<T: CommsClient> OracleL1ChainProvider<T> {
     /// Creates a new [OracleL1ChainProvider] with the given boot information and oracle client.
-    pub const fn new(l1_head: B256, oracle: Arc<T>) -> Self {
-        Self { l1_head, oracle }
+    pub fn new(l1_head: B256, oracle: Arc<T>) -> Self {
+        Self { l1_head, oracle, receipts_cache: Default::default() }
     }
 }
 
@@ -64,6 +72,14 @@ impl<T: CommsClient + Sync + Send> ChainProvider for OracleL1ChainProvider<T> {
     }
 
     async fn receipts_by_hash(&mut self, hash: B256) -> Result<Vec<Receipt>, Self::Error> {
+        // The receipts of an L1 origin are requested by multiple stages when it is entered. Serve
+        // them from the cache if they were already fetched.
+        if let Some((cached_hash, receipts)) = self.receipts_cache.lock().as_ref() {
+            if *cached_hash == hash {
+                return Ok(receipts.clone());
+            }
+        }
+
         // Fetch the block header to find the receipts root.
         let header = self.header_by_hash(hash).await?;
 
@@ -83,6 +99,7 @@ impl<T: CommsClient + Sync + Send> ChainProvider for OracleL1ChainProvider<T> {
             .collect::<Result<Vec<_>, _>>()
             .map_err(OracleProviderError::Rlp)?;
 
+        *self.receipts_cache.lock() = Some((hash, receipts.clone()));
         Ok(receipts)
     }
 
@@ -139,3 +156,71 @@ impl<T: CommsClient> TrieProvider for OracleL1ChainProvider<T> {
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{block_on, test_utils::TestOracle};
+    use alloc::{collections::BTreeMap, string::ToString, vec};
+    use alloy_eips::eip2718::Encodable2718;
+    use alloy_primitives::keccak256;
+    use alloy_rlp::Encodable;
+
+    /// Constructs a [TestOracle] serving an L1 block with the given receipts, returning the oracle
+    /// and the block's hash.
+    fn mock_receipts_oracle(receipts: &[ReceiptEnvelope]) -> (TestOracle, B256) {
+        let mut preimages = BTreeMap::new();
+
+        let encoded = receipts.iter().map(|r| r.encoded_2718()).collect::<Vec<_>>();
+        let mut hb = kona_mpt::ordered_trie_with_encoder(encoded.as_slice(), |r, buf| {
+            buf.put_slice(r.as_ref());
+        });
+        let receipts_root = hb.root();
+        for (_, node) in hb.take_proof_nodes().into_inner() {
+            preimages.insert(PreimageKey::new_keccak256(*keccak256(node.as_ref())), node.into());
+        }
+
+        let header = Header { receipts_root, ..Default::default() };
+        let mut header_rlp = Vec::new();
+        header.encode(&mut header_rlp);
+        let hash = keccak256(&header_rlp);
+        preimages.insert(PreimageKey::new_keccak256(*hash), header_rlp);
+
+        (TestOracle::new(preimages), hash)
+    }
+
+    #[test]
+    fn test_receipts_fetched_once_per_origin() {
+        let receipts = [
+            ReceiptEnvelope::Eip1559(
+                Receipt { status: true.into(), cumulative_gas_used: 21_000, logs: vec![] }
+                    .with_bloom(),
+            ),
+            ReceiptEnvelope::Legacy(
+                Receipt { status: false.into(), cumulative_gas_used: 42_000, logs: vec![] }
+                    .with_bloom(),
+            ),
+        ];
+        let (oracle, hash) = mock_receipts_oracle(&receipts);
+        let mut provider = OracleL1ChainProvider::new(hash, Arc::new(oracle.clone()));
+        let mut cloned = provider.clone();
+
+        let fetched = block_on(provider.receipts_by_hash(hash)).unwrap();
+        assert_eq!(fetched.len(), 2);
+        assert_eq!(fetched[1].cumulative_gas_used, 42_000);
+        let requested = oracle.requested().len();
+
+        // Subsequent requests for the same origin, including from clones of the provider, are
+        // served from the cache without consulting the oracle.
+        assert_eq!(block_on(provider.receipts_by_hash(hash)).unwrap(), fetched);
+        assert_eq!(block_on(cloned.receipts_by_hash(hash)).unwrap(), fetched);
+        assert_eq!(oracle.requested().len(), requested);
+
+        let receipts_hints = oracle
+            .hints()
+            .iter()
+            .filter(|hint| hint.starts_with(&HintType::L1Receipts.to_string()))
+            .count();
+        assert_eq!(receipts_hints, 1);
+    }
+}
@@ -242,4 +242,14 @@ impl<T: CommsClient> TrieHinter for OracleL2ChainProvider<T> {
                 .await
         })
     }
+
+    fn hint_ancestor_headers(&self, head_hash: B256, block_number: u64) -> Result<(), Self::Error> {
+        crate::block_on(async move {
+            HintType::L2AncestorHeaders
+                .with_data(&[head_hash.as_slice(), block_number.to_be_bytes().as_ref()])
+                .with_data(self.chain_id.map_or_else(Vec::new, |id| id.to_be_bytes().to_vec()))
+                .send(self.oracle.as_ref())
+                .await
+        })
+    }
 }
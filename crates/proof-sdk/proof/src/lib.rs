@@ -31,3 +31,6 @@ pub use caching_oracle::{CachingOracle, FlushableCache};
 
 mod blocking_runtime;
 pub use blocking_runtime::block_on;
+
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
@@ -6,36 +6,112 @@ use crate::{
 };
 use alloc::sync::Arc;
 use alloy_consensus::{Header, Sealed};
+use alloy_eips::BlockNumHash;
 use alloy_primitives::B256;
 use core::fmt::Debug;
 use kona_derive::traits::ChainProvider;
 use kona_driver::{PipelineCursor, TipCursor};
+use kona_executor::TrieDBProvider;
 use kona_preimage::CommsClient;
-use maili_protocol::BatchValidationProvider;
+use maili_protocol::{BatchValidationProvider, BlockInfo, L2BlockInfo};
 use maili_registry::RollupConfig;
 use spin::RwLock;
 
+/// The L2 safe head that the derivation pipeline starts from, along with its [L2BlockInfo].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SafeHeadInfo {
+    /// The sealed header of the safe head.
+    pub header: Sealed<Header>,
+    /// The [L2BlockInfo] of the safe head.
+    pub block_info: L2BlockInfo,
+}
+
+impl SafeHeadInfo {
+    /// Returns the L1 origin of the safe head.
+    pub const fn l1_origin(&self) -> BlockNumHash {
+        self.block_info.l1_origin
+    }
+}
+
+/// A suggested maximum age of the safe head relative to the claimed L2 block, in seconds, equal to
+/// one week. Output roots are proposed far more frequently than this, so an older safe head
+/// usually indicates a stale or mismatched one.
+pub const MAX_SAFE_HEAD_AGE: u64 = 604_800;
+
+/// Returns the maximum number of L2 blocks that the safe head may trail the claimed L2 block by,
+/// for a safe head at most `max_age` seconds older than the claimed block.
+///
+/// If the block time of the chain is zero, the distance is unbounded.
+pub const fn max_safe_head_distance(rollup_config: &RollupConfig, max_age: u64) -> u64 {
+    match max_age.checked_div(rollup_config.block_time) {
+        Some(distance) => distance,
+        None => u64::MAX,
+    }
+}
+
+/// Fetches the header and [L2BlockInfo] of the L2 safe head with the given hash.
+pub async fn fetch_safe_head<O>(
+    l2_chain_provider: &mut OracleL2ChainProvider<O>,
+    safe_head_hash: B256,
+) -> Result<SafeHeadInfo, OracleProviderError>
+where
+    O: CommsClient + Send + Sync,
+{
+    let header = l2_chain_provider
+        .header_by_hash(safe_head_hash)
+        .map(|header| Sealed::new_unchecked(header, safe_head_hash))?;
+    let block_info = l2_chain_provider.l2_block_info_by_number(header.number).await?;
+
+    Ok(SafeHeadInfo { header, block_info })
+}
+
+/// Checks that the safe head trails the claimed L2 block by at most `max_distance` blocks.
+///
+/// A safe head past the claimed L2 block is not rejected here, as it indicates an invalid claim
+/// rather than a stale safe head.
+pub fn check_safe_head_distance(
+    safe_head: &SafeHeadInfo,
+    claimed_l2_block_number: u64,
+    max_distance: u64,
+) -> Result<(), OracleProviderError> {
+    let safe_head_number = safe_head.header.number;
+    if claimed_l2_block_number.saturating_sub(safe_head_number) > max_distance {
+        return Err(OracleProviderError::StaleSafeHead(
+            safe_head_number,
+            claimed_l2_block_number,
+            max_distance,
+        ));
+    }
+    Ok(())
+}
+
 /// Constructs a [`PipelineCursor`] from the caching oracle, boot info, and providers.
+///
+/// If `max_l1_walk_back` is set, the number of L1 blocks walked back from the L1 head to reach the
+/// pipeline's starting origin is bounded, and an [OracleProviderError::WalkBackTooDeep] error is
+/// returned rather than fetching each block in a deep gap.
 pub async fn new_pipeline_cursor<O>(
     rollup_config: &RollupConfig,
-    safe_header: Sealed<Header>,
+    safe_head: SafeHeadInfo,
     chain_provider: &mut OracleL1ChainProvider<O>,
-    l2_chain_provider: &mut OracleL2ChainProvider<O>,
+    max_l1_walk_back: Option<u64>,
 ) -> Result<Arc<RwLock<PipelineCursor>>, OracleProviderError>
 where
     O: CommsClient + FlushableCache + FlushableCache + Send + Sync + Debug,
 {
-    let safe_head_info = l2_chain_provider.l2_block_info_by_number(safe_header.number).await?;
-    let l1_origin = chain_provider.block_info_by_number(safe_head_info.l1_origin.number).await?;
+    let SafeHeadInfo { header: safe_header, block_info: safe_head_info } = safe_head;
 
     // Walk back the starting L1 block by `channel_timeout` to ensure that the full channel is
     // captured.
     let channel_timeout = rollup_config.channel_timeout(safe_head_info.block_info.timestamp);
-    let mut l1_origin_number = l1_origin.number.saturating_sub(channel_timeout);
-    if l1_origin_number < rollup_config.genesis.l1.number {
-        l1_origin_number = rollup_config.genesis.l1.number;
-    }
-    let origin = chain_provider.block_info_by_number(l1_origin_number).await?;
+    let origin = walk_back_origin(
+        rollup_config,
+        chain_provider,
+        safe_head_info.l1_origin.number,
+        channel_timeout,
+        max_l1_walk_back,
+    )
+    .await?;
 
     // Construct the cursor.
     let mut cursor = PipelineCursor::new(channel_timeout, origin);
@@ -45,3 +121,150 @@ where
     // Wrap the cursor in a shared read-write lock
     Ok(Arc::new(RwLock::new(cursor)))
 }
+
+/// Finds the starting L1 origin of the derivation pipeline, `channel_timeout` blocks before the
+/// safe head's L1 origin, bounded below by the L1 genesis block.
+///
+/// The depth of the walk from the L1 head is checked against `max_l1_walk_back` before any of the
+/// intermediate blocks are fetched.
+async fn walk_back_origin<O>(
+    rollup_config: &RollupConfig,
+    chain_provider: &mut OracleL1ChainProvider<O>,
+    l1_origin_number: u64,
+    channel_timeout: u64,
+    max_l1_walk_back: Option<u64>,
+) -> Result<BlockInfo, OracleProviderError>
+where
+    O: CommsClient + Send + Sync,
+{
+    let l1_head = chain_provider.header_by_hash(chain_provider.l1_head).await?;
+    if l1_origin_number > l1_head.number {
+        return Err(OracleProviderError::BlockNumberPastHead(l1_origin_number, l1_head.number));
+    }
+
+    let origin_number =
+        l1_origin_number.saturating_sub(channel_timeout).max(rollup_config.genesis.l1.number);
+
+    let depth = l1_head.number.saturating_sub(origin_number);
+    if let Some(max_l1_walk_back) = max_l1_walk_back {
+        if depth > max_l1_walk_back {
+            return Err(OracleProviderError::WalkBackTooDeep(depth, max_l1_walk_back));
+        }
+    }
+
+    chain_provider.block_info_by_number(origin_number).await
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{block_on, test_utils::TestOracle};
+    use alloc::{collections::BTreeMap, vec::Vec};
+    use alloy_consensus::EMPTY_ROOT_HASH;
+    use alloy_primitives::keccak256;
+    use alloy_rlp::Encodable;
+    use kona_preimage::PreimageKey;
+
+    /// Constructs an [OracleL1ChainProvider] over a mock L1 chain of `length` blocks.
+    fn mock_l1_chain(length: u64) -> (OracleL1ChainProvider<TestOracle>, TestOracle) {
+        let mut preimages = BTreeMap::new();
+        let mut parent_hash = B256::ZERO;
+        for number in 0..length {
+            let header = Header { number, parent_hash, ..Default::default() };
+            let mut rlp = Vec::new();
+            header.encode(&mut rlp);
+            parent_hash = keccak256(&rlp);
+            preimages.insert(PreimageKey::new_keccak256(*parent_hash), rlp);
+        }
+
+        let oracle = TestOracle::new(preimages);
+        (OracleL1ChainProvider::new(parent_hash, Arc::new(oracle.clone())), oracle)
+    }
+
+    #[test]
+    fn test_walk_back_origin() {
+        let rollup_config = RollupConfig::default();
+        let (mut chain_provider, _) = mock_l1_chain(10);
+
+        let origin =
+            block_on(walk_back_origin(&rollup_config, &mut chain_provider, 8, 5, Some(6))).unwrap();
+        assert_eq!(origin.number, 3);
+    }
+
+    #[test]
+    fn test_walk_back_origin_clamped_to_genesis() {
+        let rollup_config = RollupConfig::default();
+        let (mut chain_provider, _) = mock_l1_chain(10);
+
+        let origin =
+            block_on(walk_back_origin(&rollup_config, &mut chain_provider, 2, 5, None)).unwrap();
+        assert_eq!(origin.number, 0);
+    }
+
+    #[test]
+    fn test_walk_back_origin_exceeds_max() {
+        let rollup_config = RollupConfig::default();
+        let (mut chain_provider, oracle) = mock_l1_chain(10);
+
+        let err = block_on(walk_back_origin(&rollup_config, &mut chain_provider, 8, 5, Some(5)))
+            .unwrap_err();
+        assert!(matches!(err, OracleProviderError::WalkBackTooDeep(6, 5)));
+
+        // Only the L1 head was fetched before the walk was rejected.
+        assert_eq!(oracle.requested().len(), 1);
+    }
+
+    #[test]
+    fn test_fetch_safe_head() {
+        let header = Header { number: 0, transactions_root: EMPTY_ROOT_HASH, ..Default::default() };
+        let mut header_rlp = Vec::new();
+        header.encode(&mut header_rlp);
+        let safe_head_hash = keccak256(&header_rlp);
+
+        let l1_genesis = BlockNumHash { number: 10, hash: B256::repeat_byte(0x01) };
+        let mut rollup_config = RollupConfig::default();
+        rollup_config.genesis.l1 = l1_genesis;
+        rollup_config.genesis.l2 = BlockNumHash { number: 0, hash: safe_head_hash };
+
+        let mut preimages = BTreeMap::new();
+        preimages.insert(PreimageKey::new_keccak256(*safe_head_hash), header_rlp);
+        preimages.insert(PreimageKey::new_keccak256(*EMPTY_ROOT_HASH), alloc::vec![0x80]);
+        let oracle = TestOracle::new(preimages);
+        let mut l2_chain_provider =
+            OracleL2ChainProvider::new(safe_head_hash, Arc::new(rollup_config), Arc::new(oracle));
+
+        let safe_head = block_on(fetch_safe_head(&mut l2_chain_provider, safe_head_hash)).unwrap();
+        assert_eq!(safe_head.header.seal(), safe_head_hash);
+        assert_eq!(safe_head.block_info.block_info.hash, safe_head_hash);
+        assert_eq!(safe_head.block_info.block_info.number, safe_head.header.number);
+        assert_eq!(safe_head.l1_origin(), l1_genesis);
+    }
+
+    #[test]
+    fn test_max_safe_head_distance() {
+        let rollup_config = RollupConfig { block_time: 2, ..Default::default() };
+        assert_eq!(max_safe_head_distance(&rollup_config, MAX_SAFE_HEAD_AGE), 302_400);
+
+        let rollup_config = RollupConfig { block_time: 1, ..Default::default() };
+        assert_eq!(max_safe_head_distance(&rollup_config, MAX_SAFE_HEAD_AGE), 604_800);
+
+        let rollup_config = RollupConfig { block_time: 0, ..Default::default() };
+        assert_eq!(max_safe_head_distance(&rollup_config, MAX_SAFE_HEAD_AGE), u64::MAX);
+    }
+
+    #[test]
+    fn test_check_safe_head_distance() {
+        const MAX_DISTANCE: u64 = 302_400;
+
+        let safe_head = |number| SafeHeadInfo {
+            header: Sealed::new_unchecked(Header { number, ..Default::default() }, B256::ZERO),
+            block_info: L2BlockInfo::default(),
+        };
+
+        check_safe_head_distance(&safe_head(100), 100 + MAX_DISTANCE, MAX_DISTANCE).unwrap();
+        check_safe_head_distance(&safe_head(200), 100, MAX_DISTANCE).unwrap();
+
+        let err = check_safe_head_distance(&safe_head(1), 10_000_000, MAX_DISTANCE).unwrap_err();
+        assert!(matches!(err, OracleProviderError::StaleSafeHead(1, 10_000_000, MAX_DISTANCE)));
+    }
+}
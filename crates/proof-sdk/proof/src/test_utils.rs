@@ -0,0 +1,76 @@
+//! Test utilities for `kona-proof`.
+
+use alloc::{
+    boxed::Box,
+    collections::BTreeMap,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use async_trait::async_trait;
+use kona_preimage::{
+    errors::{PreimageOracleError, PreimageOracleResult},
+    HintWriterClient, PreimageKey, PreimageOracleClient,
+};
+use spin::Mutex;
+
+/// A mock preimage oracle for testing, which serves preimages from memory and records every
+/// preimage key requested from it, and every hint sent to it.
+///
+/// Clones share the same preimages and records.
+#[derive(Debug, Clone, Default)]
+pub struct TestOracle {
+    /// The preimages served by the oracle.
+    preimages: Arc<BTreeMap<PreimageKey, Vec<u8>>>,
+    /// The preimage keys requested from the oracle, in order.
+    requested: Arc<Mutex<Vec<PreimageKey>>>,
+    /// The hints sent to the oracle, in order.
+    hints: Arc<Mutex<Vec<String>>>,
+}
+
+impl TestOracle {
+    /// Creates a new [TestOracle] serving the given preimages.
+    pub fn new(preimages: impl IntoIterator<Item = (PreimageKey, Vec<u8>)>) -> Self {
+        Self { preimages: Arc::new(preimages.into_iter().collect()), ..Default::default() }
+    }
+
+    /// Returns the preimage keys requested from the oracle, in order.
+    pub fn requested(&self) -> Vec<PreimageKey> {
+        self.requested.lock().clone()
+    }
+
+    /// Returns the number of times the given preimage key was requested from the oracle.
+    pub fn request_count(&self, key: PreimageKey) -> usize {
+        self.requested.lock().iter().filter(|k| **k == key).count()
+    }
+
+    /// Returns the hints sent to the oracle, in order.
+    pub fn hints(&self) -> Vec<String> {
+        self.hints.lock().clone()
+    }
+}
+
+#[async_trait]
+impl PreimageOracleClient for TestOracle {
+    async fn get(&self, key: PreimageKey) -> PreimageOracleResult<Vec<u8>> {
+        self.requested.lock().push(key);
+        self.preimages.get(&key).cloned().ok_or(PreimageOracleError::KeyNotFound)
+    }
+
+    async fn get_exact(&self, key: PreimageKey, buf: &mut [u8]) -> PreimageOracleResult<()> {
+        let preimage = self.get(key).await?;
+        if preimage.len() != buf.len() {
+            return Err(PreimageOracleError::Other("Invalid preimage length".to_string()));
+        }
+        buf.copy_from_slice(&preimage);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl HintWriterClient for TestOracle {
+    async fn write(&self, hint: &str) -> PreimageOracleResult<()> {
+        self.hints.lock().push(hint.to_string());
+        Ok(())
+    }
+}
@@ -106,6 +106,12 @@ impl OnlineBeaconClient {
         }
         Self { base, inner: Client::new() }
     }
+
+    /// Performs a cheap liveness probe against the beacon API by fetching the beacon genesis.
+    pub async fn health_check(&self) -> Result<(), reqwest::Error> {
+        let response = self.inner.get(format!("{}/{}", self.base, GENESIS_METHOD)).send().await?;
+        response.error_for_status()?.json::<APIGenesisResponse>().await.map(|_| ())
+    }
 }
 
 #[async_trait]
@@ -147,3 +153,65 @@ impl BeaconClient for OnlineBeaconClient {
         Ok(sidecars)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+        task::JoinHandle,
+    };
+
+    /// Spawns a beacon API server that answers a single request with the given status line and
+    /// JSON body, returning its base URL.
+    async fn mock_beacon(status: &'static str, body: String) -> (String, JoinHandle<()>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (mut conn, _) = listener.accept().await.unwrap();
+
+            // Read the request head before responding.
+            let mut request = Vec::new();
+            let mut buf = [0u8; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                let n = conn.read(&mut buf).await.unwrap();
+                assert_ne!(n, 0, "Connection closed before the request was received");
+                request.extend_from_slice(&buf[..n]);
+            }
+
+            let response = format!(
+                "HTTP/1.1 {status}\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\
+                 connection: close\r\n\r\n{body}",
+                body.len()
+            );
+            conn.write_all(response.as_bytes()).await.unwrap();
+        });
+        (format!("http://{addr}/"), server)
+    }
+
+    #[tokio::test]
+    async fn test_health_check() {
+        let body = serde_json::to_string(&APIGenesisResponse::new(1_606_824_023)).unwrap();
+        let (base, server) = mock_beacon("200 OK", body).await;
+
+        let client = OnlineBeaconClient::new_http(base);
+        client.health_check().await.unwrap();
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_health_check_error_status() {
+        let (base, server) = mock_beacon("503 Service Unavailable", "{}".to_string()).await;
+
+        let client = OnlineBeaconClient::new_http(base);
+        assert!(client.health_check().await.is_err());
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_health_check_unreachable() {
+        let client = OnlineBeaconClient::new_http("http://127.0.0.1:0/".to_string());
+        assert!(client.health_check().await.is_err());
+    }
+}
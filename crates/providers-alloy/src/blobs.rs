@@ -1,7 +1,10 @@
 //! Contains an online implementation of the `BlobProvider` trait.
 
 use crate::BeaconClient;
-use alloy_eips::eip4844::{Blob, BlobTransactionSidecarItem, IndexedBlobHash};
+use alloy_eips::eip4844::{
+    env_settings::EnvKzgSettings, Blob, BlobTransactionSidecar, BlobTransactionSidecarItem,
+    IndexedBlobHash,
+};
 use alloy_rpc_types_beacon::sidecar::BlobData;
 use async_trait::async_trait;
 use kona_derive::{errors::BlobProviderError, traits::BlobProvider};
@@ -17,6 +20,8 @@ pub struct OnlineBlobProvider<B: BeaconClient> {
     pub genesis_time: u64,
     /// Slot interval used for the time to slot conversion.
     pub slot_interval: u64,
+    /// The KZG settings used to verify blobs against their commitments and proofs.
+    pub kzg_settings: EnvKzgSettings,
 }
 
 impl<B: BeaconClient> OnlineBlobProvider<B> {
@@ -41,7 +46,35 @@ impl<B: BeaconClient> OnlineBlobProvider<B> {
             .map(|r| r.data.seconds_per_slot)
             .map_err(|e| BlobProviderError::Backend(e.to_string()))
             .expect("Failed to load slot interval from beacon client");
-        Self { beacon_client, genesis_time, slot_interval }
+        Self { beacon_client, genesis_time, slot_interval, kzg_settings: EnvKzgSettings::Default }
+    }
+
+    /// Sets the KZG settings used to verify blobs, in place of the Ethereum mainnet trusted setup.
+    pub fn with_kzg_settings(mut self, kzg_settings: EnvKzgSettings) -> Self {
+        self.kzg_settings = kzg_settings;
+        self
+    }
+
+    /// Verifies the blob of the [BlobTransactionSidecarItem] against the [IndexedBlobHash] with
+    /// the provider's KZG settings.
+    pub fn verify_blob(
+        &self,
+        sidecar: &BlobTransactionSidecarItem,
+        hash: &IndexedBlobHash,
+    ) -> Result<(), BlobProviderError> {
+        if sidecar.index != hash.index {
+            return Err(BlobProviderError::Backend(format!(
+                "Blob index mismatch: expected {}, got {}",
+                hash.index, sidecar.index
+            )));
+        }
+        BlobTransactionSidecar::new(
+            vec![*sidecar.blob],
+            vec![sidecar.kzg_commitment],
+            vec![sidecar.kzg_proof],
+        )
+        .validate(&[hash.hash], self.kzg_settings.get())
+        .map_err(|e| BlobProviderError::Backend(e.to_string()))
     }
 
     /// Fetches blob sidecars for the given slot and blob hashes.
@@ -91,6 +124,13 @@ impl<B: BeaconClient> OnlineBlobProvider<B> {
             .filter(|s| blob_hash_indicies.contains(&s.index))
             .collect::<Vec<_>>();
 
+        // A requested blob without a sidecar may have been reorged out along with its L1 block.
+        if let Some(missing) =
+            blob_hashes.iter().find(|b| !filtered.iter().any(|s| s.index == b.index))
+        {
+            return Err(BlobProviderError::BlobNotFound(missing.hash));
+        }
+
         // Validate the correct number of blob sidecars were retrieved.
         if blob_hashes.len() != filtered.len() {
             return Err(BlobProviderError::SidecarLengthMismatch(blob_hashes.len(), filtered.len()));
@@ -134,10 +174,7 @@ where
                 let hash = blob_hashes
                     .get(i)
                     .ok_or(BlobProviderError::Backend("Missing blob hash".to_string()))?;
-                sidecar
-                    .verify_blob(&IndexedBlobHash { hash: hash.hash, index: hash.index })
-                    .map(|_| sidecar.blob)
-                    .map_err(|e| BlobProviderError::Backend(e.to_string()))
+                self.verify_blob(&sidecar, hash).map(|_| sidecar.blob)
             })
             .collect::<Result<Vec<Box<Blob>>, BlobProviderError>>()
             .map_err(|e| BlobProviderError::Backend(e.to_string()))?;
@@ -174,3 +211,66 @@ impl<B: BeaconClient + Send + Sync> BlobSidecarProvider for B {
             .map_err(|e| BlobProviderError::Backend(e.to_string()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OnlineBeaconClient;
+    use alloy_eips::eip4844::kzg_to_versioned_hash;
+    use alloy_primitives::B256;
+
+    fn provider() -> OnlineBlobProvider<OnlineBeaconClient> {
+        OnlineBlobProvider {
+            beacon_client: OnlineBeaconClient::new_http("http://127.0.0.1:0/".to_string()),
+            genesis_time: 0,
+            slot_interval: 12,
+            kzg_settings: EnvKzgSettings::Default,
+        }
+    }
+
+    /// Builds a sidecar item at index 0 for a blob whose second byte is `byte`, along with its
+    /// versioned hash.
+    fn sidecar_item(byte: u8) -> (BlobTransactionSidecarItem, IndexedBlobHash) {
+        let mut blob = Blob::default();
+        blob[1] = byte;
+        let sidecar = BlobTransactionSidecar::try_from_blobs(vec![blob]).unwrap();
+        let item = BlobTransactionSidecarItem {
+            index: 0,
+            blob: Box::new(blob),
+            kzg_commitment: sidecar.commitments[0],
+            kzg_proof: sidecar.proofs[0],
+        };
+        let hash = kzg_to_versioned_hash(item.kzg_commitment.as_slice());
+        (item, IndexedBlobHash { index: 0, hash })
+    }
+
+    #[test]
+    fn test_verify_blob() {
+        let (item, hash) = sidecar_item(0xFF);
+        provider().verify_blob(&item, &hash).unwrap();
+    }
+
+    #[test]
+    fn test_verify_blob_hash_mismatch() {
+        let (item, hash) = sidecar_item(0xFF);
+        let hash = IndexedBlobHash { hash: B256::repeat_byte(0x01), ..hash };
+        assert!(provider().verify_blob(&item, &hash).is_err());
+    }
+
+    #[test]
+    fn test_verify_blob_commitment_mismatch() {
+        // The commitment and proof of another blob match the requested hash, but not the blob.
+        let (item, _) = sidecar_item(0xFF);
+        let (other, other_hash) = sidecar_item(0xEE);
+        let item = BlobTransactionSidecarItem { blob: item.blob, ..other };
+        assert!(provider().verify_blob(&item, &other_hash).is_err());
+    }
+
+    #[test]
+    fn test_verify_blob_index_mismatch() {
+        let (item, hash) = sidecar_item(0xFF);
+        let hash = IndexedBlobHash { index: 1, ..hash };
+        let err = provider().verify_blob(&item, &hash).unwrap_err();
+        assert!(err.to_string().contains("Blob index mismatch"));
+    }
+}
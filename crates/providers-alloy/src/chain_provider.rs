@@ -1,5 +1,6 @@
 //! Providers that use alloy provider types on the backend.
 
+use crate::L1OriginPrefetcher;
 use alloy_consensus::{Block, Header, Receipt, ReceiptWithBloom, TxEnvelope, TxType};
 use alloy_primitives::{Bytes, B256, U64};
 use alloy_provider::{Provider, RootProvider};
@@ -32,6 +33,8 @@ pub struct AlloyChainProvider {
     receipts_by_hash_cache: LruCache<B256, Vec<Receipt>>,
     /// `block_info_and_transactions_by_hash` LRU cache.
     block_info_and_transactions_by_hash_cache: LruCache<B256, (BlockInfo, Vec<TxEnvelope>)>,
+    /// The optional [L1OriginPrefetcher] warming the data of upcoming L1 origins.
+    prefetcher: Option<L1OriginPrefetcher>,
 }
 
 impl AlloyChainProvider {
@@ -44,9 +47,19 @@ impl AlloyChainProvider {
             block_info_and_transactions_by_hash_cache: LruCache::new(
                 NonZeroUsize::new(CACHE_SIZE).unwrap(),
             ),
+            prefetcher: None,
         }
     }
 
+    /// Enables prefetching of upcoming L1 origins through the given [L1OriginPrefetcher].
+    ///
+    /// Whenever an origin is fetched by number, the prefetcher's background task is asked to
+    /// warm the following origins, which are then served without a round trip to the RPC.
+    pub fn with_prefetcher(mut self, prefetcher: L1OriginPrefetcher) -> Self {
+        self.prefetcher = Some(prefetcher);
+        self
+    }
+
     /// Creates a new [AlloyChainProvider] from the provided [reqwest::Url].
     pub fn new_http(url: reqwest::Url) -> Self {
         let inner = RootProvider::new_http(url);
@@ -62,6 +75,11 @@ impl AlloyChainProvider {
     pub async fn chain_id(&mut self) -> Result<u64, RpcError<TransportErrorKind>> {
         self.inner.get_chain_id().await
     }
+
+    /// Performs a cheap liveness probe against the RPC by fetching the chain ID.
+    pub async fn health_check(&mut self) -> Result<(), RpcError<TransportErrorKind>> {
+        self.chain_id().await.map(|_| ())
+    }
 }
 
 /// An error for the [AlloyChainProvider].
@@ -74,6 +92,12 @@ pub enum AlloyChainProviderError {
     /// Failed to decode the raw header.
     #[error("Failed to decode raw header for hash {0}")]
     RawHeaderDecoding(B256),
+    /// Failed to fetch the raw block with the given number.
+    #[error("Failed to fetch raw block for number {0}")]
+    RawBlockFetch(u64),
+    /// Failed to decode the raw block with the given number.
+    #[error("Failed to decode raw block for number {0}")]
+    RawBlockDecoding(u64),
     /// Failed to fetch the raw receipts.
     #[error("Failed to fetch raw receipts for hash {0}")]
     RawReceiptsFetch(B256),
@@ -91,6 +115,12 @@ impl From<AlloyChainProviderError> for PipelineErrorKind {
             AlloyChainProviderError::RawHeaderDecoding(_) => PipelineErrorKind::Temporary(
                 PipelineError::Provider("Failed to decode raw header".to_string()),
             ),
+            AlloyChainProviderError::RawBlockFetch(_) => PipelineErrorKind::Temporary(
+                PipelineError::Provider("Failed to fetch raw block".to_string()),
+            ),
+            AlloyChainProviderError::RawBlockDecoding(_) => PipelineErrorKind::Temporary(
+                PipelineError::Provider("Failed to decode raw block".to_string()),
+            ),
             AlloyChainProviderError::RawReceiptsFetch(_) => PipelineErrorKind::Temporary(
                 PipelineError::Provider("Failed to fetch raw receipts".to_string()),
             ),
@@ -101,6 +131,28 @@ impl From<AlloyChainProviderError> for PipelineErrorKind {
     }
 }
 
+/// Decodes the raw EIP-2718 encoded receipts of the block with the given hash.
+pub(crate) fn decode_raw_receipts(
+    hash: B256,
+    raw_receipts: &[Bytes],
+) -> Result<Vec<Receipt>, AlloyChainProviderError> {
+    raw_receipts
+        .iter()
+        .map(|r| {
+            let r = &mut r.as_ref();
+
+            // Skip the transaction type byte if it exists
+            if !r.is_empty() && r[0] <= TxType::Eip7702 as u8 {
+                r.advance(1);
+            }
+
+            Ok(ReceiptWithBloom::decode(r)
+                .map_err(|_| AlloyChainProviderError::RawReceiptsDecoding(hash))?
+                .receipt)
+        })
+        .collect()
+}
+
 #[async_trait]
 impl ChainProvider for AlloyChainProvider {
     type Error = AlloyChainProviderError;
@@ -109,6 +161,10 @@ impl ChainProvider for AlloyChainProvider {
         if let Some(header) = self.header_by_hash_cache.get(&hash) {
             return Ok(header.clone());
         }
+        if let Some(origin) = self.prefetcher.as_ref().and_then(|p| p.get(hash)) {
+            self.header_by_hash_cache.put(hash, origin.header.clone());
+            return Ok(origin.header.clone());
+        }
 
         let raw_header: Bytes = self
             .inner
@@ -138,6 +194,10 @@ impl ChainProvider for AlloyChainProvider {
             parent_hash: header.parent_hash,
             timestamp: header.timestamp,
         };
+
+        if let Some(prefetcher) = &self.prefetcher {
+            prefetcher.request_warm(number);
+        }
         Ok(block_info)
     }
 
@@ -145,6 +205,10 @@ impl ChainProvider for AlloyChainProvider {
         if let Some(receipts) = self.receipts_by_hash_cache.get(&hash) {
             return Ok(receipts.clone());
         }
+        if let Some(origin) = self.prefetcher.as_ref().and_then(|p| p.get(hash)) {
+            self.receipts_by_hash_cache.put(hash, origin.receipts.clone());
+            return Ok(origin.receipts.clone());
+        }
 
         let raw_receipts: Vec<Bytes> = self
             .inner
@@ -152,21 +216,7 @@ impl ChainProvider for AlloyChainProvider {
             .await
             .map_err(|_| AlloyChainProviderError::RawReceiptsFetch(hash))?;
 
-        let receipts = raw_receipts
-            .iter()
-            .map(|r| {
-                let r = &mut r.as_ref();
-
-                // Skip the transaction type byte if it exists
-                if !r.is_empty() && r[0] <= TxType::Eip7702 as u8 {
-                    r.advance(1);
-                }
-
-                Ok(ReceiptWithBloom::decode(r)
-                    .map_err(|_| AlloyChainProviderError::RawReceiptsDecoding(hash))?
-                    .receipt)
-            })
-            .collect::<Result<Vec<_>, Self::Error>>()?;
+        let receipts = decode_raw_receipts(hash, &raw_receipts)?;
         self.receipts_by_hash_cache.put(hash, receipts.clone());
         Ok(receipts)
     }
@@ -179,6 +229,11 @@ impl ChainProvider for AlloyChainProvider {
         {
             return Ok(block_info_and_txs.clone());
         }
+        if let Some(origin) = self.prefetcher.as_ref().and_then(|p| p.get(hash)) {
+            let block_info_and_txs = (origin.block_info, origin.transactions.clone());
+            self.block_info_and_transactions_by_hash_cache.put(hash, block_info_and_txs.clone());
+            return Ok(block_info_and_txs);
+        }
 
         let raw_block: Bytes = self
             .inner
@@ -199,3 +254,21 @@ impl ChainProvider for AlloyChainProvider {
         Ok((block_info, block.body.transactions))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::MockTransport;
+
+    #[tokio::test]
+    async fn test_health_check() {
+        let asserter = MockTransport::new();
+        let mut provider = AlloyChainProvider::new(RootProvider::new(asserter.client()));
+
+        asserter.push_success(&U64::from(1));
+        assert!(provider.health_check().await.is_ok());
+
+        asserter.push_failure_msg("connection refused");
+        assert!(provider.health_check().await.is_err());
+    }
+}
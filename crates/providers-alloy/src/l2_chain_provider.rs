@@ -44,6 +44,11 @@ impl AlloyL2ChainProvider {
         self.inner.get_block_number().await
     }
 
+    /// Performs a cheap liveness probe against the RPC by fetching the chain ID.
+    pub async fn health_check(&mut self) -> Result<(), RpcError<TransportErrorKind>> {
+        self.chain_id().await.map(|_| ())
+    }
+
     /// Creates a new [AlloyL2ChainProvider] from the provided [reqwest::Url].
     pub fn new_http(url: reqwest::Url, rollup_config: Arc<RollupConfig>) -> Self {
         let inner = RootProvider::new_http(url);
@@ -128,3 +133,24 @@ impl L2ChainProvider for AlloyL2ChainProvider {
             .map_err(|_| AlloyL2ChainProviderError::SystemConfigConversion(number))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::MockTransport;
+
+    #[tokio::test]
+    async fn test_health_check() {
+        let asserter = MockTransport::new();
+        let mut provider = AlloyL2ChainProvider::new(
+            RootProvider::new(asserter.client()),
+            Arc::new(RollupConfig::default()),
+        );
+
+        asserter.push_success(&U64::from(10));
+        assert!(provider.health_check().await.is_ok());
+
+        asserter.push_failure_msg("connection refused");
+        assert!(provider.health_check().await.is_err());
+    }
+}
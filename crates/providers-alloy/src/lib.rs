@@ -15,5 +15,11 @@ pub use blobs::{BlobSidecarProvider, OnlineBlobProvider};
 mod chain_provider;
 pub use chain_provider::AlloyChainProvider;
 
+mod prefetch;
+pub use prefetch::L1OriginPrefetcher;
+
 mod l2_chain_provider;
 pub use l2_chain_provider::AlloyL2ChainProvider;
+
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
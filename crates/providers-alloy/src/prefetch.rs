@@ -0,0 +1,331 @@
+//! Contains the [L1OriginPrefetcher], which warms the data of upcoming L1 origins ahead of the
+//! derivation pipeline.
+
+use crate::chain_provider::{decode_raw_receipts, AlloyChainProviderError};
+use alloy_consensus::{Block, Header, Receipt, TxEnvelope};
+use alloy_primitives::{Bytes, B256, U64};
+use alloy_provider::{Provider, RootProvider};
+use alloy_rlp::Decodable;
+use futures::stream::{self, StreamExt};
+use lru::LruCache;
+use maili_protocol::BlockInfo;
+use std::{
+    collections::HashSet,
+    num::NonZeroUsize,
+    sync::{Arc, Mutex, Once},
+    vec::Vec,
+};
+use tokio::sync::{watch, Semaphore};
+
+/// The data of a prefetched L1 origin.
+#[derive(Debug, Clone)]
+pub(crate) struct PrefetchedOrigin {
+    /// The header of the origin.
+    pub(crate) header: Header,
+    /// The [BlockInfo] of the origin.
+    pub(crate) block_info: BlockInfo,
+    /// The transactions of the origin.
+    pub(crate) transactions: Vec<TxEnvelope>,
+    /// The receipts of the origin.
+    pub(crate) receipts: Vec<Receipt>,
+}
+
+/// The shared store of prefetched L1 origins.
+#[derive(Debug)]
+struct PrefetchStore {
+    /// The prefetched origins, keyed by block hash.
+    origins: LruCache<B256, Arc<PrefetchedOrigin>>,
+    /// The block hashes of the prefetched origins, keyed by block number.
+    numbers: LruCache<u64, B256>,
+    /// The numbers of the origins currently being fetched.
+    in_flight: HashSet<u64>,
+}
+
+/// The [L1OriginPrefetcher] is an opt-in background warmer for the
+/// [AlloyChainProvider](crate::AlloyChainProvider).
+///
+/// Given the current L1 origin, it concurrently fetches the blocks and receipts of the next
+/// `lookahead` origins, overlapping the RPC round trips with derivation. At most `concurrency`
+/// origins are fetched at once, across all warm-ups. Prefetching is best-effort: origins that fail
+/// to be fetched are skipped, and are later fetched on demand by the provider.
+///
+/// Clones of the [L1OriginPrefetcher] share the same store of prefetched origins, the same
+/// concurrency limit, and the same background task.
+#[derive(Debug, Clone)]
+pub struct L1OriginPrefetcher {
+    /// The state shared with the background task.
+    shared: Arc<PrefetchShared>,
+    /// The latest origin requested to be warmed by the background task.
+    requests: Arc<watch::Sender<Option<u64>>>,
+    /// Guards the one-time spawn of the background task.
+    task: Arc<Once>,
+}
+
+/// The state of an [L1OriginPrefetcher] that is shared with its background task.
+#[derive(Debug)]
+struct PrefetchShared {
+    /// The inner Ethereum JSON-RPC provider.
+    inner: RootProvider,
+    /// The number of origins to prefetch past the current origin.
+    lookahead: u64,
+    /// The maximum number of origins fetched concurrently.
+    concurrency: NonZeroUsize,
+    /// The permits bounding the number of origins fetched concurrently.
+    permits: Semaphore,
+    /// The store of prefetched origins.
+    store: Mutex<PrefetchStore>,
+}
+
+impl L1OriginPrefetcher {
+    /// Creates a new [L1OriginPrefetcher] that prefetches `lookahead` origins past the current
+    /// origin, fetching at most `concurrency` origins at once.
+    pub fn new(inner: RootProvider, lookahead: u64, concurrency: NonZeroUsize) -> Self {
+        // Retain the window being prefetched as well as the one before it, so that origins are not
+        // evicted before the pipeline reaches them.
+        let capacity =
+            NonZeroUsize::new((lookahead as usize).saturating_mul(2)).unwrap_or(NonZeroUsize::MIN);
+        let store = PrefetchStore {
+            origins: LruCache::new(capacity),
+            numbers: LruCache::new(capacity),
+            in_flight: HashSet::new(),
+        };
+        let shared = PrefetchShared {
+            inner,
+            lookahead,
+            concurrency,
+            permits: Semaphore::new(concurrency.get()),
+            store: Mutex::new(store),
+        };
+        Self {
+            shared: Arc::new(shared),
+            requests: Arc::new(watch::channel(None).0),
+            task: Arc::new(Once::new()),
+        }
+    }
+
+    /// Prefetches the origins in the look-ahead window past the origin with the given number,
+    /// skipping the origins that have already been prefetched or are currently being fetched.
+    ///
+    /// Returns the number of origins that were fetched. If any origin failed to be fetched, the
+    /// first error is returned once the rest of the window has been fetched.
+    pub async fn warm(&self, origin: u64) -> Result<usize, AlloyChainProviderError> {
+        self.shared.warm(origin).await
+    }
+
+    /// Requests the background task to prefetch the look-ahead window past the origin with the
+    /// given number. See [L1OriginPrefetcher::warm].
+    ///
+    /// The background task is spawned on the first request. Requests made while the task is busy
+    /// are coalesced, so that only the latest origin is warmed next.
+    ///
+    /// ## Panics
+    /// Panics if the background task is spawned outside of a tokio runtime.
+    pub fn request_warm(&self, origin: u64) {
+        self.requests.send_replace(Some(origin));
+        self.task.call_once(|| {
+            tokio::spawn(Self::run(self.shared.clone(), self.requests.subscribe()));
+        });
+    }
+
+    /// Returns the prefetched origin with the given block hash, if it is in the store.
+    pub(crate) fn get(&self, hash: B256) -> Option<Arc<PrefetchedOrigin>> {
+        self.shared.store.lock().expect("prefetch store poisoned").origins.get(&hash).cloned()
+    }
+
+    /// The background task, warming the latest requested origin until all handles to the
+    /// prefetcher are dropped.
+    async fn run(shared: Arc<PrefetchShared>, mut requests: watch::Receiver<Option<u64>>) {
+        requests.mark_changed();
+        while requests.changed().await.is_ok() {
+            let origin = *requests.borrow_and_update();
+            if let Some(origin) = origin {
+                // Failed origins are fetched on demand by the provider, which surfaces the error.
+                let _ = shared.warm(origin).await;
+            }
+        }
+    }
+}
+
+impl PrefetchShared {
+    /// See [L1OriginPrefetcher::warm].
+    async fn warm(&self, origin: u64) -> Result<usize, AlloyChainProviderError> {
+        let pending = {
+            let mut store = self.store.lock().expect("prefetch store poisoned");
+            let pending = (origin.saturating_add(1)..=origin.saturating_add(self.lookahead))
+                .filter(|n| !store.numbers.contains(n) && !store.in_flight.contains(n))
+                .collect::<Vec<_>>();
+            store.in_flight.extend(pending.iter().copied());
+            pending
+        };
+        let _guard = InFlightGuard { store: &self.store, numbers: &pending };
+
+        let mut fetched = 0;
+        let mut error = None;
+        let mut results = stream::iter(pending.iter().copied())
+            .map(|number| self.fetch_origin(number))
+            .buffer_unordered(self.concurrency.get());
+        while let Some(result) = results.next().await {
+            match result {
+                Ok(origin) => {
+                    let mut store = self.store.lock().expect("prefetch store poisoned");
+                    store.numbers.put(origin.block_info.number, origin.block_info.hash);
+                    store.origins.put(origin.block_info.hash, Arc::new(origin));
+                    fetched += 1;
+                }
+                Err(e) => {
+                    error.get_or_insert(e);
+                }
+            }
+        }
+        error.map_or(Ok(fetched), Err)
+    }
+
+    /// Fetches the block and receipts of the origin with the given number.
+    async fn fetch_origin(&self, number: u64) -> Result<PrefetchedOrigin, AlloyChainProviderError> {
+        let _permit = self.permits.acquire().await.expect("prefetch semaphore closed");
+
+        let raw_block: Bytes = self
+            .inner
+            .raw_request("debug_getRawBlock".into(), [U64::from(number)])
+            .await
+            .map_err(|_| AlloyChainProviderError::RawBlockFetch(number))?;
+        let block: Block<TxEnvelope> = Block::decode(&mut raw_block.as_ref())
+            .map_err(|_| AlloyChainProviderError::RawBlockDecoding(number))?;
+
+        let hash = block.header.hash_slow();
+        let raw_receipts: Vec<Bytes> = self
+            .inner
+            .raw_request("debug_getRawReceipts".into(), [hash])
+            .await
+            .map_err(|_| AlloyChainProviderError::RawReceiptsFetch(hash))?;
+        let receipts = decode_raw_receipts(hash, &raw_receipts)?;
+
+        let block_info = BlockInfo {
+            hash,
+            number: block.header.number,
+            parent_hash: block.header.parent_hash,
+            timestamp: block.header.timestamp,
+        };
+        Ok(PrefetchedOrigin {
+            header: block.header,
+            block_info,
+            transactions: block.body.transactions,
+            receipts,
+        })
+    }
+}
+
+/// Releases the in-flight origins of a warm-up once it completes or is cancelled.
+struct InFlightGuard<'a> {
+    /// The store holding the in-flight origins.
+    store: &'a Mutex<PrefetchStore>,
+    /// The numbers of the origins fetched by the warm-up.
+    numbers: &'a [u64],
+}
+
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        if let Ok(mut store) = self.store.lock() {
+            self.numbers.iter().for_each(|n| {
+                store.in_flight.remove(n);
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::MockTransport, AlloyChainProvider};
+    use alloy_consensus::BlockBody;
+    use kona_derive::traits::ChainProvider;
+
+    fn mock_block(number: u64) -> Block<TxEnvelope> {
+        Block {
+            header: Header { number, timestamp: number * 12, ..Default::default() },
+            body: BlockBody { transactions: Vec::new(), ommers: Vec::new(), withdrawals: None },
+        }
+    }
+
+    fn push_block(asserter: &MockTransport, block: &Block<TxEnvelope>) {
+        asserter.push_success(&Bytes::from(alloy_rlp::encode(block)));
+        asserter.push_success(&Vec::<Bytes>::new());
+    }
+
+    #[tokio::test]
+    async fn test_warm_lookahead_window() {
+        let asserter = MockTransport::new();
+        let inner = RootProvider::new(asserter.client());
+        let prefetcher = L1OriginPrefetcher::new(inner.clone(), 3, NonZeroUsize::MIN);
+
+        let blocks = (11..=13).map(mock_block).collect::<Vec<_>>();
+        blocks.iter().for_each(|block| push_block(&asserter, block));
+        assert_eq!(prefetcher.warm(10).await.unwrap(), 3);
+
+        // The look-ahead window is already warm, so no further requests are made.
+        assert_eq!(prefetcher.warm(10).await.unwrap(), 0);
+        assert_eq!(asserter.methods().len(), 6);
+
+        // Every origin in the window is served by the provider without a round trip to the RPC.
+        let mut provider = AlloyChainProvider::new(inner).with_prefetcher(prefetcher.clone());
+        for block in &blocks {
+            let hash = block.header.hash_slow();
+            assert_eq!(provider.header_by_hash(hash).await.unwrap(), block.header);
+            assert!(provider.receipts_by_hash(hash).await.unwrap().is_empty());
+
+            let (block_info, txs) =
+                provider.block_info_and_transactions_by_hash(hash).await.unwrap();
+            assert_eq!(block_info.number, block.header.number);
+            assert!(txs.is_empty());
+        }
+        assert_eq!(asserter.methods().len(), 6);
+        assert!(prefetcher.get(mock_block(14).header.hash_slow()).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_warm_skips_in_flight_origins() {
+        let asserter = MockTransport::new();
+        let prefetcher =
+            L1OriginPrefetcher::new(RootProvider::new(asserter.client()), 2, NonZeroUsize::MIN);
+
+        (1..=2).for_each(|number| push_block(&asserter, &mock_block(number)));
+        let (first, second) = tokio::join!(prefetcher.warm(0), prefetcher.warm(0));
+        assert_eq!(first.unwrap() + second.unwrap(), 2);
+        assert_eq!(asserter.methods().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_warm_reports_failed_origins() {
+        let asserter = MockTransport::new();
+        let prefetcher =
+            L1OriginPrefetcher::new(RootProvider::new(asserter.client()), 2, NonZeroUsize::MIN);
+
+        let block = mock_block(1);
+        push_block(&asserter, &block);
+        asserter.push_failure_msg("block not found");
+        assert!(matches!(prefetcher.warm(0).await, Err(AlloyChainProviderError::RawBlockFetch(2))));
+        assert!(prefetcher.get(block.header.hash_slow()).is_some());
+
+        // The failed origin is no longer in flight, and is fetched by the next warm-up.
+        push_block(&asserter, &mock_block(2));
+        assert_eq!(prefetcher.warm(0).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_request_warm_background_task() {
+        let asserter = MockTransport::new();
+        let prefetcher =
+            L1OriginPrefetcher::new(RootProvider::new(asserter.client()), 1, NonZeroUsize::MIN);
+
+        let block = mock_block(6);
+        push_block(&asserter, &block);
+        prefetcher.request_warm(5);
+        for _ in 0..100 {
+            if prefetcher.get(block.header.hash_slow()).is_some() {
+                return;
+            }
+            tokio::task::yield_now().await;
+        }
+        panic!("origin was not prefetched by the background task");
+    }
+}
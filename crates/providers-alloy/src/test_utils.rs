@@ -0,0 +1,95 @@
+//! Test utilities for the alloy-backed providers.
+
+use alloy_json_rpc::{
+    ErrorPayload, RequestPacket, Response, ResponsePacket, ResponsePayload, SerializedRequest,
+};
+use alloy_rpc_client::RpcClient;
+use alloy_transport::{TransportError, TransportErrorKind, TransportFut};
+use serde::Serialize;
+use std::{
+    borrow::Cow,
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+use tower::Service;
+
+/// A mock [alloy_transport::Transport] that answers requests, in order, with queued responses.
+///
+/// Requests received while the queue is empty fail with a transport error.
+#[derive(Debug, Clone, Default)]
+pub struct MockTransport {
+    /// The queued responses, and the methods of the requests received so far.
+    inner: Arc<Mutex<MockTransportInner>>,
+}
+
+/// The shared state of a [MockTransport].
+#[derive(Debug, Default)]
+struct MockTransportInner {
+    /// The queued response payloads.
+    responses: VecDeque<ResponsePayload>,
+    /// The methods of the requests received so far.
+    methods: Vec<String>,
+}
+
+impl MockTransport {
+    /// Creates a new [MockTransport] with an empty response queue.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns an [RpcClient] backed by the [MockTransport].
+    pub fn client(&self) -> RpcClient {
+        RpcClient::new(self.clone(), true)
+    }
+
+    /// Queues a successful response.
+    pub fn push_success<R: Serialize>(&self, response: &R) {
+        let raw = serde_json::value::to_raw_value(response).expect("Failed to serialize response");
+        self.inner.lock().unwrap().responses.push_back(ResponsePayload::Success(raw));
+    }
+
+    /// Queues an error response with the given message.
+    pub fn push_failure_msg(&self, message: impl Into<Cow<'static, str>>) {
+        let error = ErrorPayload { code: -32000, message: message.into(), data: None };
+        self.inner.lock().unwrap().responses.push_back(ResponsePayload::Failure(error));
+    }
+
+    /// Returns the methods of the requests received so far, in order.
+    pub fn methods(&self) -> Vec<String> {
+        self.inner.lock().unwrap().methods.clone()
+    }
+
+    /// Answers a single request with the next queued response.
+    fn respond(&self, request: &SerializedRequest) -> Result<Response, TransportError> {
+        let mut inner = self.inner.lock().unwrap();
+        inner.methods.push(request.method().to_string());
+        let payload = inner
+            .responses
+            .pop_front()
+            .ok_or_else(|| TransportErrorKind::custom_str("Empty mock response queue"))?;
+        Ok(Response { id: request.id().clone(), payload })
+    }
+}
+
+impl Service<RequestPacket> for MockTransport {
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: RequestPacket) -> Self::Future {
+        let response = match request {
+            RequestPacket::Single(request) => self.respond(&request).map(ResponsePacket::Single),
+            RequestPacket::Batch(requests) => requests
+                .iter()
+                .map(|request| self.respond(request))
+                .collect::<Result<Vec<_>, _>>()
+                .map(ResponsePacket::Batch),
+        };
+        Box::pin(async move { response })
+    }
+}